@@ -0,0 +1,263 @@
+use crate::{Database, Project, TaskId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+	collections::HashSet,
+	io,
+	path::{Path, PathBuf},
+};
+
+/// a file attached to a task, its content is stored once per hash in the attachments directory
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Attachment {
+	/// the file name the attachment was added with
+	pub name: String,
+	/// sha256 of the content as lowercase hex
+	pub hash: String,
+	pub size_bytes: u64,
+	pub added: DateTime<Utc>,
+}
+
+impl Attachment {
+	pub fn new(name: String, content: &[u8]) -> Self {
+		Self {
+			name,
+			hash: attachment_hash(content),
+			size_bytes: content.len() as u64,
+			added: Utc::now(),
+		}
+	}
+
+	/// the hash with the lowercase extension of the name,
+	/// the extension is kept so that other programs can open the blob
+	pub fn blob_file_name(&self) -> String {
+		match Path::new(&self.name)
+			.extension()
+			.map(|extension| extension.to_string_lossy().to_lowercase())
+		{
+			Some(extension) if is_valid_blob_extension(&extension) => {
+				format!("{}.{extension}", self.hash)
+			}
+			_ => self.hash.clone(),
+		}
+	}
+
+	pub fn is_image(&self) -> bool {
+		matches!(
+			Path::new(&self.name)
+				.extension()
+				.map(|extension| extension.to_string_lossy().to_lowercase())
+				.as_deref(),
+			Some("png" | "jpg" | "jpeg" | "gif" | "webp" | "bmp")
+		)
+	}
+}
+
+pub fn attachment_hash(content: &[u8]) -> String {
+	Sha256::digest(content)
+		.iter()
+		.map(|byte| format!("{byte:02x}"))
+		.collect()
+}
+
+/// checks that the blob file name only consists of a hash and an extension,
+/// so that it can't point outside of the attachments directory
+pub fn is_valid_blob_file_name(blob_file_name: &str) -> bool {
+	let (hash, extension) = blob_file_name
+		.split_once('.')
+		.unwrap_or((blob_file_name, ""));
+	hash.len() == 64
+		&& hash.chars().all(|c| c.is_ascii_hexdigit())
+		&& (extension.is_empty() || is_valid_blob_extension(extension))
+}
+
+fn is_valid_blob_extension(extension: &str) -> bool {
+	!extension.is_empty()
+		&& extension.len() <= 16
+		&& extension.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// the 'attachments' directory next to the database file
+pub fn attachments_directory(database_filepath: &Path) -> PathBuf {
+	database_filepath
+		.parent()
+		.unwrap_or(Path::new("."))
+		.join("attachments")
+}
+
+/// writes the content into the attachments directory, if it isn't stored there already
+pub async fn store_attachment_blob(
+	attachments_directory: &Path,
+	blob_file_name: &str,
+	content: &[u8],
+) -> io::Result<()> {
+	let blob_filepath = attachments_directory.join(blob_file_name);
+	if tokio::fs::try_exists(&blob_filepath).await? {
+		return Ok(());
+	}
+	tokio::fs::create_dir_all(attachments_directory).await?;
+	tokio::fs::write(blob_filepath, content).await
+}
+
+pub async fn load_attachment_blob(
+	attachments_directory: &Path,
+	blob_file_name: &str,
+) -> io::Result<Vec<u8>> {
+	if !is_valid_blob_file_name(blob_file_name) {
+		return Err(io::Error::new(
+			io::ErrorKind::InvalidInput,
+			format!("'{blob_file_name}' is not a valid attachment blob file name"),
+		));
+	}
+	tokio::fs::read(attachments_directory.join(blob_file_name)).await
+}
+
+/// the blobs that aren't stored in the attachments directory, like the ones added by other clients
+pub async fn missing_attachment_blobs(
+	attachments_directory: &Path,
+	blob_file_names: impl IntoIterator<Item = String>,
+) -> Vec<String> {
+	let mut missing_blob_file_names = Vec::new();
+	for blob_file_name in blob_file_names {
+		let stored = tokio::fs::try_exists(attachments_directory.join(&blob_file_name))
+			.await
+			.unwrap_or(false);
+		if !stored && is_valid_blob_file_name(&blob_file_name) {
+			missing_blob_file_names.push(blob_file_name);
+		}
+	}
+	missing_blob_file_names
+}
+
+/// removes the blobs that are no longer referenced by any task, returns how many got removed
+pub async fn remove_attachment_blobs(
+	attachments_directory: &Path,
+	blob_file_names: impl IntoIterator<Item = String>,
+) -> io::Result<usize> {
+	let mut removed_blobs = 0;
+	for blob_file_name in blob_file_names {
+		if !is_valid_blob_file_name(&blob_file_name) {
+			continue;
+		}
+		match tokio::fs::remove_file(attachments_directory.join(blob_file_name)).await {
+			Ok(()) => removed_blobs += 1,
+			Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+			Err(e) => return Err(e),
+		}
+	}
+	Ok(removed_blobs)
+}
+
+impl Project {
+	pub fn add_task_attachment(&mut self, task_id: TaskId, attachment: Attachment) {
+		if let Some(task) = self.get_task_mut(&task_id) {
+			let already_attached = task.attachments.iter().any(|task_attachment| {
+				task_attachment.hash == attachment.hash && task_attachment.name == attachment.name
+			});
+			if !already_attached {
+				task.attachments.push(attachment);
+			}
+		}
+	}
+
+	pub fn remove_task_attachment(&mut self, task_id: TaskId, attachment: &Attachment) {
+		if let Some(task) = self.get_task_mut(&task_id) {
+			task.attachments
+				.retain(|task_attachment| task_attachment != attachment);
+		}
+	}
+}
+
+impl Database {
+	pub fn attachment_blob_file_names(&self) -> HashSet<String> {
+		self.projects()
+			.iter()
+			.flat_map(|(_project_id, project)| project.iter())
+			.flat_map(|(_task_id, task, _task_type)| &task.attachments)
+			.map(Attachment::blob_file_name)
+			.collect()
+	}
+
+	/// the blobs that were referenced before the update but aren't anymore,
+	/// like the attachments of deleted tasks
+	pub fn orphaned_attachment_blob_file_names(
+		&self,
+		blob_file_names_before_update: HashSet<String>,
+	) -> Vec<String> {
+		let blob_file_names = self.attachment_blob_file_names();
+		blob_file_names_before_update
+			.into_iter()
+			.filter(|blob_file_name| !blob_file_names.contains(blob_file_name))
+			.collect()
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use crate::{
+		database::tests::{create_test_project_database, create_test_task},
+		is_valid_blob_file_name, Attachment, DatabaseMessage, TaskId,
+	};
+
+	#[test]
+	fn test_blob_file_name() {
+		let attachment = Attachment::new("Screenshot.PNG".to_string(), b"content");
+		assert_eq!(
+			attachment.blob_file_name(),
+			"ed7002b439e9ac845f22357d822bac1444730fbdb6016d3ec9432297b9ec9f73.png"
+		);
+		assert!(attachment.is_image());
+		assert!(is_valid_blob_file_name(&attachment.blob_file_name()));
+
+		let attachment = Attachment::new("notes.t@r".to_string(), b"content");
+		assert_eq!(attachment.blob_file_name(), attachment.hash);
+		assert!(!is_valid_blob_file_name("../database.project_tracker"));
+	}
+
+	#[test]
+	fn test_orphaned_attachment_blobs() {
+		let kept_task_id = TaskId::generate();
+		let deleted_task_id = TaskId::generate();
+		let shared_attachment = Attachment::new("shared.txt".to_string(), b"shared");
+		let deleted_attachment = Attachment::new("deleted.pdf".to_string(), b"deleted");
+
+		let (mut database, project_id) = create_test_project_database([
+			(kept_task_id, create_test_task()),
+			(deleted_task_id, create_test_task()),
+		]);
+		let attachments = [
+			(kept_task_id, &shared_attachment),
+			(deleted_task_id, &shared_attachment),
+			(deleted_task_id, &deleted_attachment),
+			(deleted_task_id, &deleted_attachment),
+		];
+		for (task_id, attachment) in attachments {
+			database.update(DatabaseMessage::AddTaskAttachment {
+				project_id,
+				task_id,
+				attachment: attachment.clone(),
+			});
+		}
+		assert_eq!(
+			database
+				.get_task(&project_id, &deleted_task_id)
+				.unwrap()
+				.attachments
+				.len(),
+			2
+		);
+
+		let blob_file_names_before_update = database.attachment_blob_file_names();
+		database.update(DatabaseMessage::DeleteTask {
+			project_id,
+			task_id: deleted_task_id,
+		});
+
+		assert_eq!(
+			database.orphaned_attachment_blob_file_names(blob_file_names_before_update),
+			vec![deleted_attachment.blob_file_name()]
+		);
+	}
+}
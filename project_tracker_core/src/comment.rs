@@ -0,0 +1,173 @@
+use crate::{toggle_task_description_markdown_task, Project, TaskId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TaskCommentId(pub Uuid);
+
+impl TaskCommentId {
+	pub fn generate() -> Self {
+		Self(Uuid::new_v4())
+	}
+}
+
+/// a markdown comment in the activity thread of a task
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TaskComment {
+	pub id: TaskCommentId,
+	pub author: String,
+	pub created: DateTime<Utc>,
+	#[serde(default)]
+	pub edited: Option<DateTime<Utc>>,
+	pub body: String,
+}
+
+impl TaskComment {
+	pub fn new(author: String, body: String) -> Self {
+		Self {
+			id: TaskCommentId::generate(),
+			author,
+			created: Utc::now(),
+			edited: None,
+			body,
+		}
+	}
+}
+
+impl Project {
+	/// keeps the comments sorted by their creation time, oldest first
+	pub fn add_task_comment(&mut self, task_id: TaskId, comment: TaskComment) {
+		if let Some(task) = self.get_task_mut(&task_id) {
+			if task
+				.comments
+				.iter()
+				.all(|task_comment| task_comment.id != comment.id)
+			{
+				let index = task
+					.comments
+					.partition_point(|task_comment| task_comment.created <= comment.created);
+				task.comments.insert(index, comment);
+			}
+		}
+	}
+
+	pub fn edit_task_comment(
+		&mut self,
+		task_id: TaskId,
+		comment_id: TaskCommentId,
+		new_body: String,
+		edited: DateTime<Utc>,
+	) {
+		if let Some(comment) = self.get_task_comment_mut(task_id, comment_id) {
+			if comment.body != new_body {
+				comment.body = new_body;
+				comment.edited = Some(edited);
+			}
+		}
+	}
+
+	pub fn toggle_task_comment_markdown_task(
+		&mut self,
+		task_id: TaskId,
+		comment_id: TaskCommentId,
+		checked: bool,
+		range: Range<usize>,
+	) {
+		if let Some(comment) = self.get_task_comment_mut(task_id, comment_id) {
+			toggle_task_description_markdown_task(&mut comment.body, checked, range);
+		}
+	}
+
+	pub fn delete_task_comment(&mut self, task_id: TaskId, comment_id: TaskCommentId) {
+		if let Some(task) = self.get_task_mut(&task_id) {
+			task.comments.retain(|comment| comment.id != comment_id);
+		}
+	}
+
+	fn get_task_comment_mut(
+		&mut self,
+		task_id: TaskId,
+		comment_id: TaskCommentId,
+	) -> Option<&mut TaskComment> {
+		self.get_task_mut(&task_id)?
+			.comments
+			.iter_mut()
+			.find(|comment| comment.id == comment_id)
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use crate::{
+		database::tests::{create_test_project_database, create_test_task},
+		Database, DatabaseMessage, TaskComment, TaskId,
+	};
+	use chrono::{DateTime, Duration};
+
+	#[test]
+	fn test_task_comments() {
+		let task_id = TaskId::generate();
+		let (mut database, project_id) =
+			create_test_project_database([(task_id, create_test_task())]);
+
+		let mut first_comment =
+			TaskComment::new("Jane Doe".to_string(), "- [ ] ask John".to_string());
+		first_comment.created = DateTime::from_timestamp(1700000000, 0).unwrap();
+		let mut second_comment = TaskComment::new("John Doe".to_string(), "done".to_string());
+		second_comment.created = first_comment.created + Duration::minutes(5);
+
+		// comments of other clients can arrive out of order
+		for comment in [
+			second_comment.clone(),
+			first_comment.clone(),
+			first_comment.clone(),
+		] {
+			database.update(DatabaseMessage::AddTaskComment {
+				project_id,
+				task_id,
+				comment,
+			});
+		}
+		let comments = |database: &Database| {
+			database
+				.get_task(&project_id, &task_id)
+				.unwrap()
+				.comments
+				.clone()
+		};
+		assert_eq!(
+			comments(&database),
+			vec![first_comment.clone(), second_comment.clone()]
+		);
+
+		let edited = second_comment.created + Duration::minutes(1);
+		database.update(DatabaseMessage::EditTaskComment {
+			project_id,
+			task_id,
+			comment_id: second_comment.id,
+			new_body: "done, see pr".to_string(),
+			edited,
+		});
+		database.update(DatabaseMessage::ToggleTaskCommentMarkdownTask {
+			project_id,
+			task_id,
+			comment_id: first_comment.id,
+			checked: true,
+			range: 2..5,
+		});
+		let edited_comments = comments(&database);
+		assert_eq!(edited_comments[0].body, "- [X] ask John");
+		assert_eq!(edited_comments[1].body, "done, see pr");
+		assert_eq!(edited_comments[1].edited, Some(edited));
+
+		database.update(DatabaseMessage::DeleteTaskComment {
+			project_id,
+			task_id,
+			comment_id: first_comment.id,
+		});
+		assert_eq!(comments(&database).len(), 1);
+	}
+}
@@ -1,8 +1,14 @@
 use crate::{
-	duration_str, round_duration_to_minutes, OrderedHashMap, Project, ProjectId, SerializableColor,
-	SerializableDate, SortMode, Task, TaskId, TaskTag, TaskTagId, TaskType, TimeSpend,
+	database_binary::{decode_database_binary, encode_database_binary},
+	day_plan::{carry_over_day_plan, plan_task, reorder_day_plan},
+	duration_str,
+	import::apply_import,
+	round_duration_to_minutes, Attachment, ImportPlan, OrderedHashMap, Project, ProjectId,
+	ScannedGitCommit, SerializableColor, SerializableDate, SerializableTime, SortMode,
+	SourceCodeTodoSettings, Task, TaskComment, TaskCommentId, TaskId, TaskOrigin, TaskPriority,
+	TaskReminder, TaskTag, TaskTagId, TaskType, TimeBlock, TimeSpend, TrackedSession,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeSet, HashMap};
 use std::fmt::Write;
@@ -25,13 +31,27 @@ pub struct Database {
 pub enum DatabaseMessage {
 	Clear,
 
-	ImportProjects(Vec<Project>),
+	ImportProjects(ImportPlan),
 
 	ImportSourceCodeTodos {
 		project_id: ProjectId,
 		source_code_directory: PathBuf,
 		source_code_todo_tasks: OrderedHashMap<TaskId, Task>,
 	},
+	ChangeSourceCodeTodoSettings {
+		project_id: ProjectId,
+		new_settings: SourceCodeTodoSettings,
+	},
+	PromoteSourceCodeTodo {
+		project_id: ProjectId,
+		source_code_todo_id: TaskId,
+		task_id: TaskId,
+		origin: TaskOrigin,
+	},
+	LinkGitCommits {
+		project_id: ProjectId,
+		commits: Vec<ScannedGitCommit>,
+	},
 
 	CreateProject {
 		project_id: ProjectId,
@@ -122,12 +142,77 @@ pub enum DatabaseMessage {
 	StopTaskTimeSpend {
 		project_id: ProjectId,
 		task_id: TaskId,
+		/// measured by the client, so that every replica records the same session
+		tracked_session: Option<TrackedSession>,
+	},
+	AddTaskPomodoro {
+		project_id: ProjectId,
+		task_id: TaskId,
 	},
 	ChangeTaskDueDate {
 		project_id: ProjectId,
 		task_id: TaskId,
 		new_due_date: Option<SerializableDate>,
 	},
+	ChangeTaskDueTime {
+		project_id: ProjectId,
+		task_id: TaskId,
+		new_due_time: Option<SerializableTime>,
+	},
+	ChangeTaskTimeBlock {
+		project_id: ProjectId,
+		task_id: TaskId,
+		new_time_block: Option<TimeBlock>,
+	},
+	AddTaskReminder {
+		project_id: ProjectId,
+		task_id: TaskId,
+		reminder: TaskReminder,
+	},
+	RemoveTaskReminder {
+		project_id: ProjectId,
+		task_id: TaskId,
+		reminder: TaskReminder,
+	},
+	AddTaskAttachment {
+		project_id: ProjectId,
+		task_id: TaskId,
+		attachment: Attachment,
+	},
+	RemoveTaskAttachment {
+		project_id: ProjectId,
+		task_id: TaskId,
+		attachment: Attachment,
+	},
+	AddTaskComment {
+		project_id: ProjectId,
+		task_id: TaskId,
+		comment: TaskComment,
+	},
+	EditTaskComment {
+		project_id: ProjectId,
+		task_id: TaskId,
+		comment_id: TaskCommentId,
+		new_body: String,
+		edited: DateTime<Utc>,
+	},
+	ToggleTaskCommentMarkdownTask {
+		project_id: ProjectId,
+		task_id: TaskId,
+		comment_id: TaskCommentId,
+		range: Range<usize>,
+		checked: bool,
+	},
+	DeleteTaskComment {
+		project_id: ProjectId,
+		task_id: TaskId,
+		comment_id: TaskCommentId,
+	},
+	ChangeTaskPriority {
+		project_id: ProjectId,
+		task_id: TaskId,
+		new_priority: TaskPriority,
+	},
 	ToggleTaskTag {
 		project_id: ProjectId,
 		task_id: TaskId,
@@ -143,6 +228,24 @@ pub enum DatabaseMessage {
 		task_id: TaskId,
 	},
 
+	PlanTask {
+		project_id: ProjectId,
+		task_id: TaskId,
+		date: SerializableDate,
+	},
+	UnplanTask {
+		project_id: ProjectId,
+		task_id: TaskId,
+	},
+	ReorderDayPlan {
+		date: SerializableDate,
+		tasks: Vec<(ProjectId, TaskId)>,
+	},
+	CarryOverDayPlan {
+		from: SerializableDate,
+		to: SerializableDate,
+	},
+
 	CreateTaskTag {
 		project_id: ProjectId,
 		task_tag_id: TaskTagId,
@@ -252,6 +355,31 @@ impl Database {
 		})
 	}
 
+	/// reminders of unfinished tasks that fire in '(after, until]'
+	pub fn task_reminders_between<Tz: TimeZone>(
+		&self,
+		after: DateTime<Utc>,
+		until: DateTime<Utc>,
+		timezone: &Tz,
+	) -> Vec<(ProjectId, TaskId, TaskReminder)> {
+		let mut reminders = Vec::new();
+		for (project_id, project) in self.projects.iter() {
+			for (task_id, task, task_type) in project.iter() {
+				if task_type.is_done() {
+					continue;
+				}
+				for reminder in task.reminders.iter() {
+					if let Some(fire_time) = reminder.fire_time(task, timezone) {
+						if after < fire_time && fire_time <= until {
+							reminders.push((project_id, task_id, *reminder));
+						}
+					}
+				}
+			}
+		}
+		reminders
+	}
+
 	pub fn last_changed_time(&self) -> &DateTime<Utc> {
 		&self.last_changed_time
 	}
@@ -286,11 +414,8 @@ impl Database {
 				self.modified();
 			}
 
-			DatabaseMessage::ImportProjects(new_projects) => self.modify(|projects| {
-				projects.reserve(new_projects.len());
-				for new_project in new_projects {
-					projects.insert(ProjectId::generate(), new_project);
-				}
+			DatabaseMessage::ImportProjects(import) => self.modify(|projects| {
+				apply_import(projects, import);
 			}),
 
 			DatabaseMessage::ImportSourceCodeTodos {
@@ -304,6 +429,34 @@ impl Database {
 				}
 			}),
 
+			DatabaseMessage::ChangeSourceCodeTodoSettings {
+				project_id,
+				new_settings,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.source_code_todo_settings = new_settings;
+				}
+			}),
+			DatabaseMessage::PromoteSourceCodeTodo {
+				project_id,
+				source_code_todo_id,
+				task_id,
+				origin,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.promote_source_code_todo(source_code_todo_id, task_id, origin);
+				}
+			}),
+			DatabaseMessage::LinkGitCommits {
+				project_id,
+				commits,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					let mark_done = project.source_code_todo_settings.mark_done_by_commits;
+					project.link_git_commits(&commits, mark_done);
+				}
+			}),
+
 			DatabaseMessage::CreateProject {
 				project_id,
 				name,
@@ -531,9 +684,18 @@ impl Database {
 			DatabaseMessage::StopTaskTimeSpend {
 				project_id,
 				task_id,
+				tracked_session,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.stop_task_time_spend(task_id, tracked_session);
+				}
+			}),
+			DatabaseMessage::AddTaskPomodoro {
+				project_id,
+				task_id,
 			} => self.modify(|projects| {
 				if let Some(project) = projects.get_mut(&project_id) {
-					project.stop_task_time_spend(task_id);
+					project.add_task_pomodoro(task_id);
 				}
 			}),
 			DatabaseMessage::ChangeTaskDueDate {
@@ -545,6 +707,109 @@ impl Database {
 					project.set_task_due_date(task_id, new_due_date);
 				}
 			}),
+			DatabaseMessage::ChangeTaskDueTime {
+				project_id,
+				task_id,
+				new_due_time,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.set_task_due_time(task_id, new_due_time);
+				}
+			}),
+			DatabaseMessage::ChangeTaskTimeBlock {
+				project_id,
+				task_id,
+				new_time_block,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.set_task_time_block(task_id, new_time_block);
+				}
+			}),
+			DatabaseMessage::AddTaskReminder {
+				project_id,
+				task_id,
+				reminder,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.add_task_reminder(task_id, reminder);
+				}
+			}),
+			DatabaseMessage::AddTaskAttachment {
+				project_id,
+				task_id,
+				attachment,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.add_task_attachment(task_id, attachment);
+				}
+			}),
+			DatabaseMessage::RemoveTaskAttachment {
+				project_id,
+				task_id,
+				attachment,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.remove_task_attachment(task_id, &attachment);
+				}
+			}),
+			DatabaseMessage::AddTaskComment {
+				project_id,
+				task_id,
+				comment,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.add_task_comment(task_id, comment);
+				}
+			}),
+			DatabaseMessage::EditTaskComment {
+				project_id,
+				task_id,
+				comment_id,
+				new_body,
+				edited,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.edit_task_comment(task_id, comment_id, new_body, edited);
+				}
+			}),
+			DatabaseMessage::ToggleTaskCommentMarkdownTask {
+				project_id,
+				task_id,
+				comment_id,
+				range,
+				checked,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.toggle_task_comment_markdown_task(task_id, comment_id, checked, range);
+				}
+			}),
+			DatabaseMessage::DeleteTaskComment {
+				project_id,
+				task_id,
+				comment_id,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.delete_task_comment(task_id, comment_id);
+				}
+			}),
+			DatabaseMessage::RemoveTaskReminder {
+				project_id,
+				task_id,
+				reminder,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.remove_task_reminder(task_id, reminder);
+				}
+			}),
+			DatabaseMessage::ChangeTaskPriority {
+				project_id,
+				task_id,
+				new_priority,
+			} => self.modify(|projects| {
+				if let Some(project) = projects.get_mut(&project_id) {
+					project.set_task_priority(task_id, new_priority);
+				}
+			}),
 			DatabaseMessage::ToggleTaskTag {
 				project_id,
 				task_id,
@@ -563,6 +828,29 @@ impl Database {
 				}
 			}),
 
+			DatabaseMessage::PlanTask {
+				project_id,
+				task_id,
+				date,
+			} => self.modify(|projects| plan_task(projects, project_id, task_id, date)),
+			DatabaseMessage::UnplanTask {
+				project_id,
+				task_id,
+			} => self.modify(|projects| {
+				if let Some(task) = projects
+					.get_mut(&project_id)
+					.and_then(|project| project.get_task_mut(&task_id))
+				{
+					task.plan = None;
+				}
+			}),
+			DatabaseMessage::ReorderDayPlan { date, tasks } => {
+				self.modify(|projects| reorder_day_plan(projects, date, tasks))
+			}
+			DatabaseMessage::CarryOverDayPlan { from, to } => {
+				self.modify(|projects| carry_over_day_plan(projects, from, to))
+			}
+
 			DatabaseMessage::CreateTaskTag {
 				project_id,
 				task_tag_id,
@@ -602,6 +890,9 @@ impl Database {
 			} => self.modify(|projects| {
 				if let Some(project) = projects.get_mut(&project_id) {
 					project.task_tags.remove(&task_tag_id);
+					project
+						.source_code_todo_settings
+						.remove_task_tag(&task_tag_id);
 					for task in project.values_mut() {
 						task.tags.remove(&task_tag_id);
 					}
@@ -803,20 +1094,19 @@ impl Database {
 		Self::new(serialized, last_changed_time)
 	}
 
+	/// also loads binaries of older database versions
 	pub fn from_binary(
 		binary: &[u8],
 		last_changed_time: DateTime<Utc>,
 	) -> Result<Self, bincode::error::DecodeError> {
-		let (serialized_database, _) =
-			bincode::serde::decode_from_slice(binary, bincode::config::legacy())?;
 		Ok(Self::from_serialized(
-			serialized_database,
+			decode_database_binary(binary)?,
 			last_changed_time,
 		))
 	}
 
 	pub fn to_binary(&self) -> Option<Vec<u8>> {
-		bincode::serde::encode_to_vec(self.serialized(), bincode::config::legacy()).ok()
+		encode_database_binary(self.serialized()).ok()
 	}
 
 	// returns begin time of saving
@@ -881,12 +1171,12 @@ pub fn get_last_modification_date_time(metadata: &std::fs::Metadata) -> Option<D
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::panic, clippy::expect_used)]
-mod tests {
+pub(crate) mod tests {
 	use chrono::Utc;
 
 	use crate::{
 		Database, LoadDatabaseError, OrderedHashMap, Project, ProjectId, SerializableColor,
-		SerializableDate, SortMode, TaskId, TimeSpend,
+		SerializableDate, SortMode, Task, TaskId, TimeSpend,
 	};
 	use std::{collections::BTreeSet, path::PathBuf};
 
@@ -927,6 +1217,36 @@ mod tests {
 		database
 	}
 
+	pub(crate) fn create_test_task() -> Task {
+		Task::new(
+			String::new(),
+			String::new(),
+			None,
+			None,
+			None,
+			BTreeSet::new(),
+		)
+	}
+
+	/// database with a single project that contains the given todo tasks
+	pub(crate) fn create_test_project_database(
+		todo_tasks: impl IntoIterator<Item = (TaskId, Task)>,
+	) -> (Database, ProjectId) {
+		let project_id = ProjectId::generate();
+		let mut project = Project::new(
+			"project".to_string(),
+			SerializableColor::default(),
+			OrderedHashMap::new(),
+			SortMode::default(),
+		);
+		for (task_id, task) in todo_tasks {
+			project.todo_tasks.insert(task_id, task);
+		}
+		let mut database = Database::default();
+		database.modify(|projects| projects.insert(project_id, project));
+		(database, project_id)
+	}
+
 	#[tokio::test]
 	async fn test_database_serialization() {
 		let output_filepath: PathBuf =
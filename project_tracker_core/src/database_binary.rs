@@ -0,0 +1,462 @@
+use crate::{
+	Attachment, GitCommit, ImportSource, OrderedHashMap, Project, ProjectId, SerializableColor,
+	SerializableDate, SerializableTime, SerializedDatabase, SortMode, SourceCodeTodoBlame,
+	SourceCodeTodoKeyword, SourceCodeTodoSettings, Task, TaskId, TaskOrigin, TaskPlan,
+	TaskPriority, TaskReminder, TaskTag, TaskTagId, TimeBlock, TimeSpend, TrackedSession,
+};
+use bincode::error::{DecodeError, EncodeError};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::{collections::BTreeSet, path::PathBuf};
+
+/// prefixes every versioned database binary,
+/// binaries without it are in the unversioned layout of 'ProjectV0'
+const DATABASE_BINARY_MAGIC: &[u8; 4] = b"PTDB";
+
+/// bincode ignores '#[serde(default)]', so every change to the layout of 'SerializedDatabase'
+/// needs a new version. the previous layout is kept below and migrated in 'decode_database_binary'
+const DATABASE_BINARY_VERSION: u32 = 12;
+
+pub fn encode_database_binary(serialized: &SerializedDatabase) -> Result<Vec<u8>, EncodeError> {
+	let mut binary = DATABASE_BINARY_MAGIC.to_vec();
+	binary.extend_from_slice(&DATABASE_BINARY_VERSION.to_le_bytes());
+	binary.extend(bincode::serde::encode_to_vec(
+		serialized,
+		bincode::config::legacy(),
+	)?);
+	Ok(binary)
+}
+
+pub fn decode_database_binary(binary: &[u8]) -> Result<SerializedDatabase, DecodeError> {
+	let Some(versioned_binary) = binary.strip_prefix(DATABASE_BINARY_MAGIC) else {
+		return decode_previous_layout::<ProjectV0<TaskV0>>(binary);
+	};
+
+	let Some((version_bytes, serialized_binary)) = versioned_binary.split_first_chunk() else {
+		return Err(DecodeError::UnexpectedEnd {
+			additional: size_of::<u32>() - versioned_binary.len(),
+		});
+	};
+	match u32::from_le_bytes(*version_bytes) {
+		DATABASE_BINARY_VERSION => {
+			let (serialized, _) =
+				bincode::serde::decode_from_slice(serialized_binary, bincode::config::legacy())?;
+			Ok(serialized)
+		}
+		1 => decode_previous_layout::<ProjectV0<TaskV1>>(serialized_binary),
+		2 => decode_previous_layout::<ProjectV0<TaskV2>>(serialized_binary),
+		3 => decode_previous_layout::<ProjectV0<TaskV3>>(serialized_binary),
+		4 => decode_previous_layout::<ProjectV0<TaskV4>>(serialized_binary),
+		5 => decode_previous_layout::<ProjectV0<TaskV5>>(serialized_binary),
+		6 => decode_previous_layout::<ProjectV0<TaskV6>>(serialized_binary),
+		7 => decode_previous_layout::<ProjectV0<TaskV7>>(serialized_binary),
+		8 => decode_previous_layout::<ProjectV8<TaskV7, SourceCodeTodoSettingsV8>>(serialized_binary),
+		9 => decode_previous_layout::<ProjectV8<TaskV9, SourceCodeTodoSettingsV9>>(serialized_binary),
+		10 => decode_previous_layout::<ProjectV8<TaskV10, SourceCodeTodoSettingsV10>>(serialized_binary),
+		11 => decode_previous_layout::<ProjectV8<TaskV11, SourceCodeTodoSettingsV10>>(serialized_binary),
+		version => Err(DecodeError::OtherString(format!(
+			"unsupported database version {version}, the newest supported version is {DATABASE_BINARY_VERSION}"
+		))),
+	}
+}
+
+fn decode_previous_layout<P>(binary: &[u8]) -> Result<SerializedDatabase, DecodeError>
+where
+	P: ProjectLayout + DeserializeOwned + Eq + Clone,
+{
+	let (previous_serialized, _): (OrderedHashMap<ProjectId, P>, usize) =
+		bincode::serde::decode_from_slice(binary, bincode::config::legacy())?;
+
+	let mut serialized = SerializedDatabase::with_capacity(previous_serialized.len());
+	for (project_id, project) in previous_serialized.iter() {
+		serialized.insert(project_id, project.migrate());
+	}
+	Ok(serialized)
+}
+
+trait ProjectLayout {
+	fn migrate(&self) -> Project;
+}
+
+/// bincode writes nested structs like their fields inlined,
+/// so every task layout embeds the previous one and only adds the new fields
+trait TaskLayout: Clone + Eq {
+	fn migrate(&self) -> Task;
+}
+
+/// layout of a project before the database binary got versioned
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct ProjectV0<T: TaskLayout> {
+	name: String,
+	color: SerializableColor,
+	sort_mode: SortMode,
+	task_tags: OrderedHashMap<TaskTagId, TaskTag>,
+	todo_tasks: OrderedHashMap<TaskId, T>,
+	done_tasks: OrderedHashMap<TaskId, T>,
+	source_code_todos: OrderedHashMap<TaskId, T>,
+	source_code_directory: Option<PathBuf>,
+}
+
+impl<T: TaskLayout> ProjectLayout for ProjectV0<T> {
+	fn migrate(&self) -> Project {
+		let migrate_tasks = |tasks: &OrderedHashMap<TaskId, T>| {
+			let mut migrated_tasks = OrderedHashMap::with_capacity(tasks.len());
+			for (task_id, task) in tasks.iter() {
+				migrated_tasks.insert(task_id, task.migrate());
+			}
+			migrated_tasks
+		};
+
+		let mut project = Project::new(
+			self.name.clone(),
+			self.color,
+			self.task_tags.clone(),
+			self.sort_mode,
+		);
+		project.todo_tasks = migrate_tasks(&self.todo_tasks);
+		project.done_tasks = migrate_tasks(&self.done_tasks);
+		project.source_code_todos = migrate_tasks(&self.source_code_todos);
+		project.source_code_directory = self.source_code_directory.clone();
+		project
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct TaskV0 {
+	name: String,
+	description: String,
+	needed_time_minutes: Option<usize>,
+	time_spend: Option<TimeSpend>,
+	due_date: Option<SerializableDate>,
+	tags: BTreeSet<TaskTagId>,
+}
+
+impl TaskLayout for TaskV0 {
+	fn migrate(&self) -> Task {
+		Task::new(
+			self.name.clone(),
+			self.description.clone(),
+			self.needed_time_minutes,
+			self.time_spend.clone(),
+			self.due_date,
+			self.tags.clone(),
+		)
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct TaskV1 {
+	v0: TaskV0,
+	priority: TaskPriority,
+}
+
+impl TaskLayout for TaskV1 {
+	fn migrate(&self) -> Task {
+		let mut task = self.v0.migrate();
+		task.priority = self.priority;
+		task
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct TaskV2 {
+	v1: TaskV1,
+	due_time: Option<SerializableTime>,
+	reminders: Vec<TaskReminder>,
+}
+
+impl TaskLayout for TaskV2 {
+	fn migrate(&self) -> Task {
+		let mut task = self.v1.migrate();
+		task.due_time = self.due_time;
+		task.reminders = self.reminders.clone();
+		task
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct TaskV3 {
+	v2: TaskV2,
+	plan: Option<TaskPlan>,
+}
+
+impl TaskLayout for TaskV3 {
+	fn migrate(&self) -> Task {
+		let mut task = self.v2.migrate();
+		task.plan = self.plan;
+		task
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct TaskV4 {
+	v3: TaskV3,
+	completed_pomodoros: usize,
+}
+
+impl TaskLayout for TaskV4 {
+	fn migrate(&self) -> Task {
+		let mut task = self.v3.migrate();
+		task.completed_pomodoros = self.completed_pomodoros;
+		task
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct TaskV5 {
+	v4: TaskV4,
+	time_block: Option<TimeBlock>,
+	tracked_sessions: Vec<TrackedSession>,
+}
+
+impl TaskLayout for TaskV5 {
+	fn migrate(&self) -> Task {
+		let mut task = self.v4.migrate();
+		task.time_block = self.time_block;
+		task.tracked_sessions = self.tracked_sessions.clone();
+		task
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct TaskV6 {
+	v5: TaskV5,
+	issue_url: Option<String>,
+}
+
+impl TaskLayout for TaskV6 {
+	fn migrate(&self) -> Task {
+		let mut task = self.v5.migrate();
+		task.origin = self
+			.issue_url
+			.clone()
+			.map(|issue_url| TaskOrigin::new(ImportSource::Issues, issue_url));
+		task
+	}
+}
+
+/// replaced 'issue_url' of 'TaskV6' with 'origin'
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct TaskV7 {
+	v5: TaskV5,
+	origin: Option<TaskOrigin>,
+}
+
+impl TaskLayout for TaskV7 {
+	fn migrate(&self) -> Task {
+		let mut task = self.v5.migrate();
+		task.origin = self.origin.clone();
+		task
+	}
+}
+
+trait SourceCodeTodoSettingsLayout: Clone + Eq {
+	fn migrate(&self) -> SourceCodeTodoSettings;
+}
+
+/// added 'source_code_todo_settings' to 'ProjectV0'
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct ProjectV8<T: TaskLayout, S: SourceCodeTodoSettingsLayout> {
+	v0: ProjectV0<T>,
+	source_code_todo_settings: S,
+}
+
+impl<T: TaskLayout, S: SourceCodeTodoSettingsLayout> ProjectLayout for ProjectV8<T, S> {
+	fn migrate(&self) -> Project {
+		let mut project = self.v0.migrate();
+		project.source_code_todo_settings = self.source_code_todo_settings.migrate();
+		project
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct SourceCodeTodoSettingsV8 {
+	keywords: Vec<SourceCodeTodoKeyword>,
+	include_globs: Vec<String>,
+	exclude_globs: Vec<String>,
+	respect_ignore_files: bool,
+	max_file_size_bytes: Option<u64>,
+}
+
+impl SourceCodeTodoSettingsLayout for SourceCodeTodoSettingsV8 {
+	fn migrate(&self) -> SourceCodeTodoSettings {
+		SourceCodeTodoSettings {
+			keywords: self.keywords.clone(),
+			include_globs: self.include_globs.clone(),
+			exclude_globs: self.exclude_globs.clone(),
+			respect_ignore_files: self.respect_ignore_files,
+			max_file_size_bytes: self.max_file_size_bytes,
+			..Default::default()
+		}
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct TaskV9 {
+	v7: TaskV7,
+	source_code_blame: Option<SourceCodeTodoBlame>,
+}
+
+impl TaskLayout for TaskV9 {
+	fn migrate(&self) -> Task {
+		let mut task = self.v7.migrate();
+		task.source_code_blame = self.source_code_blame.clone();
+		task
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct SourceCodeTodoSettingsV9 {
+	v8: SourceCodeTodoSettingsV8,
+	git_blame: bool,
+}
+
+impl SourceCodeTodoSettingsLayout for SourceCodeTodoSettingsV9 {
+	fn migrate(&self) -> SourceCodeTodoSettings {
+		SourceCodeTodoSettings {
+			git_blame: self.git_blame,
+			..self.v8.migrate()
+		}
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct TaskV10 {
+	v9: TaskV9,
+	linked_commits: Vec<GitCommit>,
+}
+
+impl TaskLayout for TaskV10 {
+	fn migrate(&self) -> Task {
+		let mut task = self.v9.migrate();
+		task.linked_commits = self.linked_commits.clone();
+		task
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct SourceCodeTodoSettingsV10 {
+	v9: SourceCodeTodoSettingsV9,
+	mark_done_by_commits: bool,
+}
+
+impl SourceCodeTodoSettingsLayout for SourceCodeTodoSettingsV10 {
+	fn migrate(&self) -> SourceCodeTodoSettings {
+		SourceCodeTodoSettings {
+			mark_done_by_commits: self.mark_done_by_commits,
+			..self.v9.migrate()
+		}
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct TaskV11 {
+	v10: TaskV10,
+	attachments: Vec<Attachment>,
+}
+
+impl TaskLayout for TaskV11 {
+	fn migrate(&self) -> Task {
+		let mut task = self.v10.migrate();
+		task.attachments = self.attachments.clone();
+		task
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use super::{decode_database_binary, encode_database_binary};
+	use crate::{
+		Database, ProjectId, SerializableColor, SerializableDate, SerializedDatabase, SortMode,
+		TaskId, TaskTagId, TimeSpend,
+	};
+	use chrono::Utc;
+	use std::{collections::BTreeSet, path::PathBuf};
+	use uuid::Uuid;
+
+	/// the same database saved by every version of the database binary, do not regenerate them.
+	/// the first one got saved before the database binary got versioned
+	const DATABASE_BINARIES: &[&[u8]] = &[
+		include_bytes!("database_samples/baseline_database.project_tracker"),
+		include_bytes!("database_samples/database_v1.project_tracker"),
+		include_bytes!("database_samples/database_v2.project_tracker"),
+		include_bytes!("database_samples/database_v3.project_tracker"),
+		include_bytes!("database_samples/database_v4.project_tracker"),
+		include_bytes!("database_samples/database_v5.project_tracker"),
+		include_bytes!("database_samples/database_v6.project_tracker"),
+		include_bytes!("database_samples/database_v7.project_tracker"),
+		include_bytes!("database_samples/database_v8.project_tracker"),
+		include_bytes!("database_samples/database_v9.project_tracker"),
+		include_bytes!("database_samples/database_v10.project_tracker"),
+		include_bytes!("database_samples/database_v11.project_tracker"),
+		include_bytes!("database_samples/database_v12.project_tracker"),
+	];
+
+	#[test]
+	fn test_load_baseline_database_binary() {
+		let database = Database::from_binary(DATABASE_BINARIES[0], Utc::now()).unwrap();
+		assert_eq!(database.projects().len(), 2);
+
+		let project = database
+			.get_project(&ProjectId(Uuid::from_u128(1)))
+			.unwrap();
+		assert_eq!(project.name, "Project Tracker");
+		assert_eq!(project.color, SerializableColor([255, 0, 0]));
+		assert_eq!(project.sort_mode, SortMode::DueDate);
+		assert_eq!(
+			project.source_code_directory,
+			Some(PathBuf::from("/home/user/project_tracker"))
+		);
+		let bug_tag_id = TaskTagId(Uuid::from_u128(0x10));
+		assert_eq!(project.task_tags.get(&bug_tag_id).unwrap().name, "bug");
+		assert_eq!(project.todo_tasks.len(), 2);
+		assert_eq!(project.done_tasks.len(), 1);
+		assert_eq!(project.source_code_todos.len(), 1);
+
+		let task = project
+			.todo_tasks
+			.get(&TaskId(Uuid::from_u128(0x20)))
+			.unwrap();
+		assert_eq!(task.name, "Fix sync");
+		assert_eq!(task.description, "- [ ] write a test");
+		assert_eq!(task.needed_time_minutes, Some(30));
+		assert_eq!(task.time_spend, Some(TimeSpend::new(90.0)));
+		assert_eq!(
+			task.due_date,
+			Some(SerializableDate {
+				year: 2025,
+				month: 1,
+				day: 31
+			})
+		);
+		assert_eq!(task.tags, BTreeSet::from([bug_tag_id]));
+		assert!(task.comments.is_empty());
+
+		let empty_project = database
+			.get_project(&ProjectId(Uuid::from_u128(2)))
+			.unwrap();
+		assert_eq!(empty_project.name, "Empty");
+		assert!(empty_project.todo_tasks.is_empty());
+
+		// migrated databases are saved in the current version
+		let migrated = Database::from_binary(&database.to_binary().unwrap(), Utc::now()).unwrap();
+		assert_eq!(migrated.checksum(), database.checksum());
+	}
+
+	#[test]
+	fn test_load_every_database_binary_version() {
+		let baseline = Database::from_binary(DATABASE_BINARIES[0], Utc::now()).unwrap();
+		for binary in DATABASE_BINARIES {
+			let database = Database::from_binary(binary, Utc::now()).unwrap();
+			assert_eq!(database.checksum(), baseline.checksum());
+		}
+	}
+
+	#[test]
+	fn test_unsupported_database_binary_version() {
+		let mut binary = encode_database_binary(&SerializedDatabase::new()).unwrap();
+		binary[4] += 1;
+		assert!(decode_database_binary(&binary).is_err());
+		assert!(decode_database_binary(b"PTDB").is_err());
+	}
+}
@@ -1,6 +1,6 @@
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
 use serde::{Deserialize, Serialize};
-use std::cmp::Ordering;
+use std::{cmp::Ordering, fmt::Display};
 
 #[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct SerializableDate {
@@ -28,6 +28,12 @@ impl Ord for SerializableDate {
 	}
 }
 
+impl SerializableDate {
+	pub fn naive_date(&self) -> Option<NaiveDate> {
+		NaiveDate::from_ymd_opt(self.year, self.month, self.day)
+	}
+}
+
 impl From<NaiveDate> for SerializableDate {
 	fn from(date: NaiveDate) -> Self {
 		Self {
@@ -37,3 +43,44 @@ impl From<NaiveDate> for SerializableDate {
 		}
 	}
 }
+
+/// wall clock time, interpreted in the local timezone of the user
+#[derive(
+	Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct SerializableTime {
+	pub hour: u32,
+	pub minute: u32,
+}
+
+impl SerializableTime {
+	pub fn naive_time(&self) -> Option<NaiveTime> {
+		NaiveTime::from_hms_opt(self.hour, self.minute, 0)
+	}
+
+	/// parses '14:00', '9:30' or '14'
+	pub fn parse(time_str: &str) -> Option<Self> {
+		let (hour_str, minute_str) = time_str
+			.trim()
+			.split_once(':')
+			.unwrap_or((time_str.trim(), "0"));
+		let hour = hour_str.parse().ok()?;
+		let minute = minute_str.parse().ok()?;
+		NaiveTime::from_hms_opt(hour, minute, 0).map(Self::from)
+	}
+}
+
+impl From<NaiveTime> for SerializableTime {
+	fn from(time: NaiveTime) -> Self {
+		Self {
+			hour: time.hour(),
+			minute: time.minute(),
+		}
+	}
+}
+
+impl Display for SerializableTime {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:02}:{:02}", self.hour, self.minute)
+	}
+}
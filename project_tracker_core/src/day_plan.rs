@@ -0,0 +1,232 @@
+use crate::{Database, OrderedHashMap, Project, ProjectId, SerializableDate, TaskId};
+use serde::{Deserialize, Serialize};
+
+/// A task is part of the daily plan of 'date', ordered by 'position'.
+/// Stored on the task itself, so plans get synchronized with the rest of the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TaskPlan {
+	pub date: SerializableDate,
+	pub position: usize,
+}
+
+impl Database {
+	/// all tasks planned for 'date', in plan order (done tasks included)
+	pub fn day_plan(&self, date: SerializableDate) -> Vec<(ProjectId, TaskId)> {
+		day_plan(self.projects(), date)
+	}
+}
+
+fn day_plan(
+	projects: &OrderedHashMap<ProjectId, Project>,
+	date: SerializableDate,
+) -> Vec<(ProjectId, TaskId)> {
+	let mut planned_tasks = Vec::new();
+	for (project_id, project) in projects.iter() {
+		for (task_id, task, _task_type) in project.iter() {
+			if let Some(plan) = &task.plan {
+				if plan.date == date {
+					planned_tasks.push((plan.position, project_id, task_id));
+				}
+			}
+		}
+	}
+	planned_tasks.sort_by_key(|(position, _project_id, _task_id)| *position);
+	planned_tasks
+		.into_iter()
+		.map(|(_position, project_id, task_id)| (project_id, task_id))
+		.collect()
+}
+
+fn next_plan_position(
+	projects: &OrderedHashMap<ProjectId, Project>,
+	date: SerializableDate,
+) -> usize {
+	projects
+		.values()
+		.flat_map(|project| project.iter())
+		.filter_map(|(_task_id, task, _task_type)| task.plan.as_ref())
+		.filter(|plan| plan.date == date)
+		.map(|plan| plan.position + 1)
+		.max()
+		.unwrap_or(0)
+}
+
+/// appends the task to the end of the plan of 'date'
+pub(crate) fn plan_task(
+	projects: &mut OrderedHashMap<ProjectId, Project>,
+	project_id: ProjectId,
+	task_id: TaskId,
+	date: SerializableDate,
+) {
+	let position = next_plan_position(projects, date);
+	if let Some(task) = projects
+		.get_mut(&project_id)
+		.and_then(|project| project.get_task_mut(&task_id))
+	{
+		if task.plan.map(|plan| plan.date) != Some(date) {
+			task.plan = Some(TaskPlan { date, position });
+		}
+	}
+}
+
+pub(crate) fn reorder_day_plan(
+	projects: &mut OrderedHashMap<ProjectId, Project>,
+	date: SerializableDate,
+	tasks: Vec<(ProjectId, TaskId)>,
+) {
+	for (position, (project_id, task_id)) in tasks.into_iter().enumerate() {
+		if let Some(task) = projects
+			.get_mut(&project_id)
+			.and_then(|project| project.get_task_mut(&task_id))
+		{
+			task.plan = Some(TaskPlan { date, position });
+		}
+	}
+}
+
+/// moves all unfinished tasks of the plan of 'from' to the end of the plan of 'to',
+/// done tasks stay in 'from'
+pub(crate) fn carry_over_day_plan(
+	projects: &mut OrderedHashMap<ProjectId, Project>,
+	from: SerializableDate,
+	to: SerializableDate,
+) {
+	let first_position = next_plan_position(projects, to);
+	let unfinished_tasks = day_plan(projects, from)
+		.into_iter()
+		.filter(|(project_id, task_id)| {
+			projects
+				.get(project_id)
+				.and_then(|project| project.get_task_and_type(task_id))
+				.is_some_and(|(_task, task_type)| !task_type.is_done())
+		})
+		.collect::<Vec<_>>();
+
+	for (i, (project_id, task_id)) in unfinished_tasks.into_iter().enumerate() {
+		if let Some(task) = projects
+			.get_mut(&project_id)
+			.and_then(|project| project.get_task_mut(&task_id))
+		{
+			task.plan = Some(TaskPlan {
+				date: to,
+				position: first_position + i,
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use crate::{
+		Database, DatabaseMessage, ProjectId, SerializableColor, SerializableDate, TaskId,
+	};
+	use std::collections::BTreeSet;
+
+	const TODAY: SerializableDate = SerializableDate {
+		year: 2025,
+		month: 3,
+		day: 14,
+	};
+	const TOMORROW: SerializableDate = SerializableDate {
+		year: 2025,
+		month: 3,
+		day: 15,
+	};
+
+	fn database_with_tasks(task_count: usize) -> (Database, ProjectId, Vec<TaskId>) {
+		let mut database = Database::default();
+		let project_id = ProjectId::generate();
+		database.update(DatabaseMessage::CreateProject {
+			project_id,
+			name: "Project".to_string(),
+			color: SerializableColor::default(),
+		});
+		let task_ids: Vec<TaskId> = (0..task_count).map(|_| TaskId::generate()).collect();
+		for (i, task_id) in task_ids.iter().enumerate() {
+			database.update(DatabaseMessage::CreateTask {
+				project_id,
+				task_id: *task_id,
+				task_name: format!("Task {i}"),
+				task_description: String::new(),
+				task_tags: BTreeSet::new(),
+				due_date: None,
+				needed_time_minutes: None,
+				time_spend: None,
+				create_at_top: false,
+			});
+		}
+		(database, project_id, task_ids)
+	}
+
+	#[test]
+	fn test_plan_and_reorder() {
+		let (mut database, project_id, task_ids) = database_with_tasks(3);
+		for task_id in task_ids.iter().rev() {
+			database.update(DatabaseMessage::PlanTask {
+				project_id,
+				task_id: *task_id,
+				date: TODAY,
+			});
+		}
+		assert_eq!(
+			database.day_plan(TODAY),
+			vec![
+				(project_id, task_ids[2]),
+				(project_id, task_ids[1]),
+				(project_id, task_ids[0])
+			]
+		);
+
+		let reordered: Vec<(ProjectId, TaskId)> = task_ids
+			.iter()
+			.map(|task_id| (project_id, *task_id))
+			.collect();
+		database.update(DatabaseMessage::ReorderDayPlan {
+			date: TODAY,
+			tasks: reordered.clone(),
+		});
+		assert_eq!(database.day_plan(TODAY), reordered);
+
+		database.update(DatabaseMessage::UnplanTask {
+			project_id,
+			task_id: task_ids[1],
+		});
+		assert_eq!(
+			database.day_plan(TODAY),
+			vec![(project_id, task_ids[0]), (project_id, task_ids[2])]
+		);
+	}
+
+	#[test]
+	fn test_carry_over_unfinished_tasks() {
+		let (mut database, project_id, task_ids) = database_with_tasks(3);
+		database.update(DatabaseMessage::PlanTask {
+			project_id,
+			task_id: task_ids[0],
+			date: TOMORROW,
+		});
+		for task_id in task_ids.iter().skip(1) {
+			database.update(DatabaseMessage::PlanTask {
+				project_id,
+				task_id: *task_id,
+				date: TODAY,
+			});
+		}
+		database.update(DatabaseMessage::SetTaskDone {
+			project_id,
+			task_id: task_ids[1],
+		});
+
+		database.update(DatabaseMessage::CarryOverDayPlan {
+			from: TODAY,
+			to: TOMORROW,
+		});
+
+		assert_eq!(database.day_plan(TODAY), vec![(project_id, task_ids[1])]);
+		assert_eq!(
+			database.day_plan(TOMORROW),
+			vec![(project_id, task_ids[0]), (project_id, task_ids[2])]
+		);
+	}
+}
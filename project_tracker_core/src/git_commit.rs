@@ -0,0 +1,245 @@
+use crate::{Project, Task, TaskId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// a commit of the projects git repository that references a task
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GitCommit {
+	pub hash: String,
+	pub summary: String,
+	pub author: String,
+	pub date: DateTime<Utc>,
+	/// the commit is part of the default branch, like 'main'.
+	/// linked commits keep it to notice when they land later on
+	pub on_default_branch: bool,
+}
+
+impl GitCommit {
+	pub fn short_hash(&self) -> &str {
+		self.hash.get(..7).unwrap_or(&self.hash)
+	}
+}
+
+/// a commit read from the git log, see 'DatabaseMessage::LinkGitCommits'
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ScannedGitCommit {
+	pub commit: GitCommit,
+	pub message: String,
+}
+
+impl ScannedGitCommit {
+	/// the message contains 'pt-<short task id>' or a 'Fixes: <task name>' line
+	pub fn references(&self, task_id: TaskId, task: &Task) -> bool {
+		contains_word(&self.message.to_lowercase(), &task_id.commit_reference())
+			|| self.message.lines().any(|line| {
+				line.split_once(':').is_some_and(|(key, name)| {
+					key.trim().eq_ignore_ascii_case("fixes")
+						&& !name.trim().is_empty()
+						&& name.trim().to_lowercase() == task.name.trim().to_lowercase()
+				})
+			})
+	}
+}
+
+impl TaskId {
+	/// first 8 hex digits of the id
+	pub fn short_id(&self) -> String {
+		let mut short_id = self.0.simple().to_string();
+		short_id.truncate(8);
+		short_id
+	}
+
+	/// how commit messages reference this task
+	pub fn commit_reference(&self) -> String {
+		format!("pt-{}", self.short_id())
+	}
+}
+
+impl Project {
+	/// attaches the referencing commits to the todo and done tasks.
+	/// only commits that newly landed on the default branch mark a task done,
+	/// so tasks that got reopened stay open
+	pub fn link_git_commits(&mut self, commits: &[ScannedGitCommit], mark_done: bool) {
+		let task_ids: Vec<TaskId> = self
+			.todo_tasks
+			.keys()
+			.chain(self.done_tasks.keys())
+			.copied()
+			.collect();
+
+		for task_id in task_ids {
+			let Some(task) = self.get_task_mut(&task_id) else {
+				continue;
+			};
+			let referencing_commits: Vec<&ScannedGitCommit> = commits
+				.iter()
+				.filter(|scanned_commit| scanned_commit.references(task_id, task))
+				.collect();
+
+			let mut newly_landed_on_default_branch = false;
+			for scanned_commit in referencing_commits {
+				let on_default_branch = scanned_commit.commit.on_default_branch;
+				let linked_commit = task
+					.linked_commits
+					.iter_mut()
+					.find(|commit| commit.hash == scanned_commit.commit.hash);
+				match linked_commit {
+					// got linked on a feature branch and was merged since then
+					Some(linked_commit) => {
+						if on_default_branch && !linked_commit.on_default_branch {
+							linked_commit.on_default_branch = true;
+							newly_landed_on_default_branch = true;
+						}
+					}
+					None => {
+						task.linked_commits.push(scanned_commit.commit.clone());
+						newly_landed_on_default_branch |= on_default_branch;
+					}
+				}
+			}
+			task.linked_commits
+				.sort_by_key(|commit| std::cmp::Reverse(commit.date));
+
+			if mark_done && newly_landed_on_default_branch {
+				self.set_task_done(task_id);
+			}
+		}
+	}
+}
+
+fn contains_word(text: &str, word: &str) -> bool {
+	text.match_indices(word).any(|(index, _)| {
+		let before = text[..index].chars().next_back();
+		let after = text[index + word.len()..].chars().next();
+		!before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+	})
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use crate::{
+		GitCommit, OrderedHashMap, Project, ScannedGitCommit, SerializableColor, SortMode, Task,
+		TaskId,
+	};
+	use chrono::DateTime;
+	use std::collections::BTreeSet;
+
+	fn scanned_commit(hash: &str, message: &str, on_default_branch: bool) -> ScannedGitCommit {
+		ScannedGitCommit {
+			commit: GitCommit {
+				hash: hash.to_string(),
+				summary: message.lines().next().unwrap_or_default().to_string(),
+				author: "Jane Doe".to_string(),
+				date: DateTime::from_timestamp(1700000000, 0).unwrap(),
+				on_default_branch,
+			},
+			message: message.to_string(),
+		}
+	}
+
+	#[test]
+	fn test_link_git_commits() {
+		let mut project = Project::new(
+			"project".to_string(),
+			SerializableColor::default(),
+			OrderedHashMap::new(),
+			SortMode::default(),
+		);
+		let referenced_id = TaskId::generate();
+		let fixed_id = TaskId::generate();
+		let unrelated_id = TaskId::generate();
+		for (task_id, name) in [
+			(referenced_id, "Add login"),
+			(fixed_id, "Crash on startup"),
+			(unrelated_id, "Write docs"),
+		] {
+			project.todo_tasks.insert(
+				task_id,
+				Task::new(
+					name.to_string(),
+					String::new(),
+					None,
+					None,
+					None,
+					BTreeSet::new(),
+				),
+			);
+		}
+
+		let commits = [
+			scanned_commit(
+				"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+				&format!("wip login form ({})", referenced_id.commit_reference()),
+				false,
+			),
+			scanned_commit(
+				"bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb",
+				"check config first\n\nFixes: crash on startup",
+				true,
+			),
+			scanned_commit(
+				"cccccccccccccccccccccccccccccccccccccccc",
+				&format!("not a reference: x{}", unrelated_id.commit_reference()),
+				true,
+			),
+		];
+		project.link_git_commits(&commits, true);
+
+		let referenced = project.todo_tasks.get(&referenced_id).unwrap();
+		assert_eq!(referenced.linked_commits.len(), 1);
+		assert_eq!(referenced.linked_commits[0].short_hash(), "aaaaaaa");
+		assert!(project.done_tasks.contains_key(&fixed_id));
+		assert!(project
+			.todo_tasks
+			.get(&unrelated_id)
+			.unwrap()
+			.linked_commits
+			.is_empty());
+
+		// reopened tasks stay open when the same commits are linked again
+		project.set_task_todo(fixed_id);
+		project.link_git_commits(&commits, true);
+		let fixed = project.todo_tasks.get(&fixed_id).unwrap();
+		assert_eq!(fixed.linked_commits.len(), 1);
+	}
+
+	#[test]
+	fn test_linked_commit_landing_later_marks_done() {
+		let mut project = Project::new(
+			"project".to_string(),
+			SerializableColor::default(),
+			OrderedHashMap::new(),
+			SortMode::default(),
+		);
+		let task_id = TaskId::generate();
+		project.todo_tasks.insert(
+			task_id,
+			Task::new(
+				"Add login".to_string(),
+				String::new(),
+				None,
+				None,
+				None,
+				BTreeSet::new(),
+			),
+		);
+		let message = format!("login form ({})", task_id.commit_reference());
+		let hash = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+		// still on a feature branch
+		project.link_git_commits(&[scanned_commit(hash, &message, false)], true);
+		assert!(project.todo_tasks.contains_key(&task_id));
+
+		// merged into the default branch
+		project.link_git_commits(&[scanned_commit(hash, &message, true)], true);
+		let task = project.done_tasks.get(&task_id).unwrap();
+		assert_eq!(task.linked_commits.len(), 1);
+		assert!(task.linked_commits[0].on_default_branch);
+
+		// only lands once, reopened tasks stay open
+		project.set_task_todo(task_id);
+		project.link_git_commits(&[scanned_commit(hash, &message, true)], true);
+		assert!(project.todo_tasks.contains_key(&task_id));
+	}
+}
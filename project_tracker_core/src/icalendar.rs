@@ -0,0 +1,314 @@
+use crate::{
+	Database, SaveDatabaseError, SaveDatabaseResult, SerializableDate, SerializableTime, Task,
+	TaskId, TaskPriority,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// how due tasks are exported, time blocks are always exported as events
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ICalendarTaskComponent {
+	/// shows up in regular calendar apps
+	#[default]
+	Event,
+	/// shows up in task lists of calendar apps that support them
+	Todo,
+}
+
+impl Database {
+	pub const ICALENDAR_FILE_NAME: &'static str = "project_tracker.ics";
+
+	/// exports all unfinished tasks with a due date and all time blocks.
+	/// times are 'floating', since they are interpreted in the local timezone of the user
+	pub fn to_icalendar(&self, task_component: ICalendarTaskComponent) -> String {
+		let mut icalendar = ICalendarWriter::default();
+		icalendar.line("BEGIN:VCALENDAR");
+		icalendar.line("VERSION:2.0");
+		icalendar.line("PRODID:-//Project Tracker//Project Tracker//EN");
+		icalendar.line("CALSCALE:GREGORIAN");
+		icalendar.line("X-WR-CALNAME:Project Tracker");
+
+		let timestamp = format_date_time_utc(self.last_changed_time());
+
+		for (_project_id, project) in self.projects().iter() {
+			for (task_id, task, task_type) in project.iter() {
+				if task_type.is_done() {
+					continue;
+				}
+
+				if let Some(due_date) = &task.due_date {
+					match task_component {
+						ICalendarTaskComponent::Event => {
+							icalendar.line("BEGIN:VEVENT");
+							icalendar.property("UID", &task_uid(task_id, "due"));
+							icalendar.property("DTSTAMP", &timestamp);
+							icalendar.date_time_property("DTSTART", due_date, task.due_time);
+							if task.due_time.is_none() {
+								icalendar.property("DURATION", "P1D");
+							}
+							icalendar.task_properties(task, &project.name);
+							icalendar.line("END:VEVENT");
+						}
+						ICalendarTaskComponent::Todo => {
+							icalendar.line("BEGIN:VTODO");
+							icalendar.property("UID", &task_uid(task_id, "due"));
+							icalendar.property("DTSTAMP", &timestamp);
+							icalendar.date_time_property("DUE", due_date, task.due_time);
+							icalendar.property("STATUS", "NEEDS-ACTION");
+							icalendar.task_properties(task, &project.name);
+							icalendar.line("END:VTODO");
+						}
+					}
+				}
+
+				if let (Some(time_block), Some(minutes)) =
+					(&task.time_block, task.time_block_minutes())
+				{
+					icalendar.line("BEGIN:VEVENT");
+					icalendar.property("UID", &task_uid(task_id, "time-block"));
+					icalendar.property("DTSTAMP", &timestamp);
+					icalendar.date_time_property(
+						"DTSTART",
+						&time_block.date,
+						Some(time_block.start),
+					);
+					icalendar.property("DURATION", &format!("PT{minutes}M"));
+					icalendar.task_properties(task, &project.name);
+					icalendar.line("END:VEVENT");
+				}
+			}
+		}
+
+		icalendar.line("END:VCALENDAR");
+		icalendar.content
+	}
+
+	pub async fn export_as_icalendar(
+		filepath: PathBuf,
+		icalendar: String,
+	) -> SaveDatabaseResult<()> {
+		tokio::fs::write(filepath.as_path(), icalendar)
+			.await
+			.map_err(|error| SaveDatabaseError::FailedToWriteToFile { filepath, error })
+	}
+}
+
+/// stays the same between exports, so calendar apps update instead of duplicating entries
+fn task_uid(task_id: TaskId, kind: &str) -> String {
+	format!("{}-{kind}@project-tracker", task_id.0)
+}
+
+fn format_date(date: &SerializableDate) -> String {
+	format!("{:04}{:02}{:02}", date.year, date.month, date.day)
+}
+
+fn format_date_time_utc(date_time: &DateTime<Utc>) -> String {
+	date_time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn priority_value(priority: TaskPriority) -> Option<u8> {
+	match priority {
+		TaskPriority::None => None,
+		TaskPriority::Low => Some(9),
+		TaskPriority::Medium => Some(5),
+		TaskPriority::High => Some(3),
+		TaskPriority::Urgent => Some(1),
+	}
+}
+
+fn escape_text(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for c in text.chars() {
+		match c {
+			'\\' => escaped.push_str("\\\\"),
+			';' => escaped.push_str("\\;"),
+			',' => escaped.push_str("\\,"),
+			'\n' => escaped.push_str("\\n"),
+			'\r' => {}
+			c => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+#[derive(Default)]
+struct ICalendarWriter {
+	content: String,
+}
+
+impl ICalendarWriter {
+	// lines are limited to 75 bytes and continued with a leading space
+	const MAX_LINE_LENGTH: usize = 75;
+
+	fn line(&mut self, line: &str) {
+		let mut line_length = 0;
+		for c in line.chars() {
+			if line_length + c.len_utf8() > Self::MAX_LINE_LENGTH {
+				self.content.push_str("\r\n ");
+				line_length = 1;
+			}
+			self.content.push(c);
+			line_length += c.len_utf8();
+		}
+		self.content.push_str("\r\n");
+	}
+
+	fn property(&mut self, name: &str, value: &str) {
+		self.line(&format!("{name}:{value}"));
+	}
+
+	fn date_time_property(
+		&mut self,
+		name: &str,
+		date: &SerializableDate,
+		time: Option<SerializableTime>,
+	) {
+		match time {
+			Some(time) => self.line(&format!(
+				"{name}:{}T{:02}{:02}00",
+				format_date(date),
+				time.hour,
+				time.minute
+			)),
+			None => self.line(&format!("{name};VALUE=DATE:{}", format_date(date))),
+		}
+	}
+
+	fn task_properties(&mut self, task: &Task, project_name: &str) {
+		self.property("SUMMARY", &escape_text(&task.name));
+		if !task.description.is_empty() {
+			self.property("DESCRIPTION", &escape_text(&task.description));
+		}
+		self.property("CATEGORIES", &escape_text(project_name));
+		if let Some(priority) = priority_value(task.priority) {
+			self.property("PRIORITY", &priority.to_string());
+		}
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use crate::{
+		Database, DatabaseMessage, ICalendarTaskComponent, ProjectId, SerializableColor,
+		SerializableDate, SerializableTime, TaskId, TaskPriority, TimeBlock,
+	};
+	use std::collections::BTreeSet;
+
+	fn test_database() -> (Database, TaskId) {
+		let mut database = Database::default();
+		let project_id = ProjectId::generate();
+		let task_id = TaskId::generate();
+		database.update(DatabaseMessage::CreateProject {
+			project_id,
+			name: "Project, One".to_string(),
+			color: SerializableColor::default(),
+		});
+		database.update(DatabaseMessage::CreateTask {
+			project_id,
+			task_id,
+			task_name: "Write the report; then send it".to_string(),
+			task_description: "first line\nsecond line".to_string(),
+			task_tags: BTreeSet::new(),
+			due_date: Some(SerializableDate {
+				year: 2025,
+				month: 3,
+				day: 14,
+			}),
+			needed_time_minutes: Some(90),
+			time_spend: None,
+			create_at_top: false,
+		});
+		database.update(DatabaseMessage::ChangeTaskPriority {
+			project_id,
+			task_id,
+			new_priority: TaskPriority::High,
+		});
+		database.update(DatabaseMessage::ChangeTaskTimeBlock {
+			project_id,
+			task_id,
+			new_time_block: Some(TimeBlock {
+				date: SerializableDate {
+					year: 2025,
+					month: 3,
+					day: 13,
+				},
+				start: SerializableTime {
+					hour: 9,
+					minute: 30,
+				},
+				duration_minutes: None,
+			}),
+		});
+		(database, task_id)
+	}
+
+	#[test]
+	fn test_icalendar_events() {
+		let (database, task_id) = test_database();
+		let icalendar = database.to_icalendar(ICalendarTaskComponent::Event);
+
+		assert!(icalendar.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+		assert!(icalendar.ends_with("END:VCALENDAR\r\n"));
+		assert!(icalendar.contains(&format!("UID:{}-due@project-tracker\r\n", task_id.0)));
+		assert!(icalendar.contains("DTSTART;VALUE=DATE:20250314\r\nDURATION:P1D\r\n"));
+		assert!(icalendar.contains("SUMMARY:Write the report\\; then send it\r\n"));
+		assert!(icalendar.contains("DESCRIPTION:first line\\nsecond line\r\n"));
+		assert!(icalendar.contains("CATEGORIES:Project\\, One\r\n"));
+		assert!(icalendar.contains("PRIORITY:3\r\n"));
+
+		// time block with the needed time as duration
+		assert!(icalendar.contains(&format!("UID:{}-time-block@project-tracker\r\n", task_id.0)));
+		assert!(icalendar.contains("DTSTART:20250313T093000\r\nDURATION:PT90M\r\n"));
+
+		// same database exports the same calendar
+		assert_eq!(
+			icalendar,
+			database.to_icalendar(ICalendarTaskComponent::Event)
+		);
+	}
+
+	#[test]
+	fn test_icalendar_todos() {
+		let (database, _task_id) = test_database();
+		let icalendar = database.to_icalendar(ICalendarTaskComponent::Todo);
+
+		assert!(icalendar.contains("BEGIN:VTODO\r\n"));
+		assert!(icalendar.contains("DUE;VALUE=DATE:20250314\r\n"));
+		assert!(icalendar.contains("STATUS:NEEDS-ACTION\r\n"));
+	}
+
+	#[test]
+	fn test_icalendar_line_folding() {
+		let (mut database, _task_id) = test_database();
+		let project_id = ProjectId::generate();
+		database.update(DatabaseMessage::CreateProject {
+			project_id,
+			name: "Project".to_string(),
+			color: SerializableColor::default(),
+		});
+		database.update(DatabaseMessage::CreateTask {
+			project_id,
+			task_id: TaskId::generate(),
+			task_name: "ä".repeat(100),
+			task_description: String::new(),
+			task_tags: BTreeSet::new(),
+			due_date: Some(SerializableDate {
+				year: 2025,
+				month: 3,
+				day: 14,
+			}),
+			needed_time_minutes: None,
+			time_spend: None,
+			create_at_top: false,
+		});
+
+		let icalendar = database.to_icalendar(ICalendarTaskComponent::Event);
+		for line in icalendar.split("\r\n") {
+			assert!(line.len() <= 75);
+		}
+		assert!(icalendar.contains("\r\n ää"));
+	}
+}
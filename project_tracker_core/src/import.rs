@@ -0,0 +1,480 @@
+use crate::{
+	Database, OrderedHashMap, Project, ProjectId, Task, TaskId, TaskTag, TaskTagId, TaskType,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ImportSource {
+	GoogleTasks,
+	ICalendar,
+	Issues,
+	/// a promoted source code todo, identified by its file and normalized text
+	SourceCode,
+}
+
+/// where an imported task came from, so importing it again updates it instead of duplicating it
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TaskOrigin {
+	pub source: ImportSource,
+	/// id of the task inside of the source, like the google tasks id, the ics 'UID' or the issue url
+	pub external_id: String,
+}
+
+impl TaskOrigin {
+	pub fn new(source: ImportSource, external_id: String) -> Self {
+		Self {
+			source,
+			external_id,
+		}
+	}
+
+	pub fn url(&self) -> Option<&str> {
+		match self.source {
+			ImportSource::Issues => Some(&self.external_id),
+			ImportSource::GoogleTasks | ImportSource::ICalendar | ImportSource::SourceCode => None,
+		}
+	}
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportSummary {
+	pub created: usize,
+	pub updated: usize,
+	pub unchanged: usize,
+}
+
+/// an import with every id and merge target already resolved by the client,
+/// so that 'DatabaseMessage::ImportProjects' is applied the same way on every replica
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportPlan {
+	/// projects that didnt exist before, either imported as is or created for new tasks
+	new_projects: Vec<(ProjectId, Project)>,
+	new_task_tags: Vec<(ProjectId, TaskTagId, TaskTag)>,
+	tasks: Vec<ImportedTask>,
+	pub summary: ImportSummary,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ImportedTask {
+	project_id: ProjectId,
+	task_id: TaskId,
+	/// tags already point to the task tags of the target project
+	task: Task,
+	done: bool,
+}
+
+impl Database {
+	/// resolves where the imported projects go, without changing anything
+	pub fn plan_import(&self, imported_projects: Vec<Project>) -> ImportPlan {
+		plan_import(self.projects(), imported_projects)
+	}
+}
+
+/// tasks with an already imported origin get updated wherever they are now,
+/// new tasks go into the project with the same name or a newly created one
+fn plan_import(
+	projects: &OrderedHashMap<ProjectId, Project>,
+	imported_projects: Vec<Project>,
+) -> ImportPlan {
+	let mut plan = ImportPlan::default();
+
+	let mut imported_tasks: HashMap<TaskOrigin, (ProjectId, TaskId)> = HashMap::new();
+	for (project_id, project) in projects.iter() {
+		for (task_id, task, _task_type) in project.iter() {
+			if let Some(origin) = &task.origin {
+				imported_tasks.insert(origin.clone(), (project_id, task_id));
+			}
+		}
+	}
+
+	for imported_project in imported_projects {
+		let mut target_project_id = projects
+			.iter()
+			.find(|(_, project)| project.name == imported_project.name)
+			.map(|(project_id, _)| project_id)
+			.or_else(|| {
+				plan.new_projects
+					.iter()
+					.find(|(_, project)| project.name == imported_project.name)
+					.map(|(project_id, _)| *project_id)
+			});
+
+		if target_project_id.is_none() && imported_project.total_tasks() == 0 {
+			plan.new_projects
+				.push((ProjectId::generate(), imported_project));
+			continue;
+		}
+
+		for (_, imported_task, task_type) in imported_project.iter() {
+			if task_type == TaskType::SourceCodeTodo {
+				continue;
+			}
+			let done = task_type.is_done();
+
+			let existing_task = imported_task
+				.origin
+				.as_ref()
+				.and_then(|origin| imported_tasks.get(origin))
+				.copied();
+
+			let (project_id, task_id) = existing_task.unwrap_or_else(|| {
+				let project_id = *target_project_id.get_or_insert_with(|| {
+					let project_id = ProjectId::generate();
+					plan.new_projects.push((
+						project_id,
+						Project::new(
+							imported_project.name.clone(),
+							imported_project.color,
+							OrderedHashMap::new(),
+							imported_project.sort_mode,
+						),
+					));
+					project_id
+				});
+				(project_id, TaskId::generate())
+			});
+
+			let mut task = imported_task.clone();
+			task.tags = plan.resolve_task_tags(
+				projects,
+				project_id,
+				&imported_task.tags,
+				&imported_project.task_tags,
+			);
+
+			match plan.existing_task(projects, project_id, task_id) {
+				Some((existing_task, existing_done)) => {
+					let changed = existing_task.name != task.name
+						|| existing_task.description != task.description
+						|| existing_task.due_date != task.due_date
+						|| existing_task.due_time != task.due_time
+						|| existing_task.priority != task.priority
+						|| existing_task.tags != task.tags
+						|| existing_done != done;
+					if changed {
+						plan.summary.updated += 1;
+					} else {
+						plan.summary.unchanged += 1;
+					}
+				}
+				None => {
+					if let Some(origin) = &task.origin {
+						imported_tasks.insert(origin.clone(), (project_id, task_id));
+					}
+					plan.summary.created += 1;
+				}
+			}
+
+			plan.tasks.push(ImportedTask {
+				project_id,
+				task_id,
+				task,
+				done,
+			});
+		}
+	}
+
+	plan
+}
+
+pub(crate) fn apply_import(projects: &mut OrderedHashMap<ProjectId, Project>, import: ImportPlan) {
+	for (project_id, project) in import.new_projects {
+		projects.insert(project_id, project);
+	}
+	for (project_id, task_tag_id, task_tag) in import.new_task_tags {
+		if let Some(project) = projects.get_mut(&project_id) {
+			project.task_tags.insert(task_tag_id, task_tag);
+		}
+	}
+	for imported_task in import.tasks {
+		if let Some(project) = projects.get_mut(&imported_task.project_id) {
+			if project.get_task(&imported_task.task_id).is_some() {
+				project.update_imported_task(
+					imported_task.task_id,
+					imported_task.task,
+					imported_task.done,
+				);
+			} else {
+				project.insert_imported_task(
+					imported_task.task_id,
+					imported_task.task,
+					imported_task.done,
+				);
+			}
+		}
+	}
+}
+
+impl ImportPlan {
+	/// the task as it will be before this import touches it again,
+	/// either already in the database or imported earlier in this plan
+	fn existing_task<'a>(
+		&'a self,
+		projects: &'a OrderedHashMap<ProjectId, Project>,
+		project_id: ProjectId,
+		task_id: TaskId,
+	) -> Option<(&'a Task, bool)> {
+		self.tasks
+			.iter()
+			.rev()
+			.find(|imported_task| imported_task.task_id == task_id)
+			.map(|imported_task| (&imported_task.task, imported_task.done))
+			.or_else(|| {
+				projects
+					.get(&project_id)
+					.and_then(|project| project.get_task_and_type(&task_id))
+					.map(|(task, task_type)| (task, task_type.is_done()))
+			})
+	}
+
+	/// tags are matched by name, missing ones are planned to be created
+	fn resolve_task_tags(
+		&mut self,
+		projects: &OrderedHashMap<ProjectId, Project>,
+		project_id: ProjectId,
+		tags: &BTreeSet<TaskTagId>,
+		imported_task_tags: &OrderedHashMap<TaskTagId, TaskTag>,
+	) -> BTreeSet<TaskTagId> {
+		tags.iter()
+			.filter_map(|imported_tag_id| imported_task_tags.get(imported_tag_id))
+			.map(|imported_tag| {
+				projects
+					.get(&project_id)
+					.and_then(|project| project.find_task_tag(&imported_tag.name))
+					.or_else(|| {
+						self.new_task_tags
+							.iter()
+							.find(|(tag_project_id, _, task_tag)| {
+								*tag_project_id == project_id && task_tag.name == imported_tag.name
+							})
+							.map(|(_, task_tag_id, _)| *task_tag_id)
+					})
+					.unwrap_or_else(|| {
+						let task_tag_id = TaskTagId::generate();
+						self.new_task_tags
+							.push((project_id, task_tag_id, imported_tag.clone()));
+						task_tag_id
+					})
+			})
+			.collect()
+	}
+}
+
+impl Project {
+	fn insert_imported_task(&mut self, task_id: TaskId, task: Task, done: bool) {
+		if done {
+			self.done_tasks.insert(task_id, task);
+		} else {
+			self.todo_tasks.insert(task_id, task);
+		}
+	}
+
+	/// only updates what the source knows about, local changes like the time spend are kept
+	fn update_imported_task(&mut self, task_id: TaskId, imported_task: Task, done: bool) {
+		let Some(task) = self.get_task_mut(&task_id) else {
+			return;
+		};
+		task.name = imported_task.name;
+		task.description = imported_task.description;
+		task.due_date = imported_task.due_date;
+		task.due_time = imported_task.due_time;
+		task.priority = imported_task.priority;
+		task.tags = imported_task.tags;
+
+		let is_done = self.done_tasks.contains_key(&task_id);
+		if done != is_done {
+			if done {
+				self.set_task_done(task_id);
+			} else {
+				self.set_task_todo(task_id);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use crate::{
+		Database, DatabaseMessage, ImportSource, ImportSummary, OrderedHashMap, Project,
+		SerializableColor, SerializableTime, SortMode, Task, TaskId, TaskOrigin, TaskPriority,
+		TaskTag, TaskTagId, TaskType,
+	};
+	use std::collections::BTreeSet;
+
+	fn issues_project(issues: &[(&str, &str, bool)]) -> Project {
+		let mut project = Project::new(
+			"owner/repo".to_string(),
+			SerializableColor::default(),
+			OrderedHashMap::new(),
+			SortMode::default(),
+		);
+		let bug_tag_id = TaskTagId::generate();
+		project.task_tags.insert(
+			bug_tag_id,
+			TaskTag::new("bug".to_string(), SerializableColor([215, 58, 74])),
+		);
+		for (issue_url, name, closed) in issues {
+			let mut task = Task::new(
+				name.to_string(),
+				"issue body".to_string(),
+				None,
+				None,
+				None,
+				BTreeSet::from([bug_tag_id]),
+			);
+			task.origin = Some(TaskOrigin::new(ImportSource::Issues, issue_url.to_string()));
+			if *closed {
+				project.done_tasks.insert(TaskId::generate(), task);
+			} else {
+				project.todo_tasks.insert(TaskId::generate(), task);
+			}
+		}
+		project
+	}
+
+	#[test]
+	fn test_reimport_updates_tasks_by_origin() {
+		let mut database = Database::default();
+		let first_import = vec![issues_project(&[
+			("https://github.com/owner/repo/issues/1", "Crash", false),
+			("https://github.com/owner/repo/issues/2", "Typo", false),
+		])];
+		let first_import = database.plan_import(first_import);
+		assert_eq!(
+			first_import.summary,
+			ImportSummary {
+				created: 2,
+				updated: 0,
+				unchanged: 0
+			}
+		);
+		database.update(DatabaseMessage::ImportProjects(first_import));
+
+		let (project_id, project) = database.projects().iter().next().unwrap();
+		let (task_id, _, _) = project.iter().next().unwrap();
+		database.update(DatabaseMessage::ChangeTaskNeededTime {
+			project_id,
+			task_id,
+			new_needed_time_minutes: Some(30),
+		});
+
+		let second_import = vec![issues_project(&[
+			(
+				"https://github.com/owner/repo/issues/1",
+				"Crash on startup",
+				true,
+			),
+			("https://github.com/owner/repo/issues/2", "Typo", false),
+			("https://github.com/owner/repo/issues/3", "Dark mode", false),
+		])];
+		let expected_summary = ImportSummary {
+			created: 1,
+			updated: 1,
+			unchanged: 1,
+		};
+		let second_import = database.plan_import(second_import);
+		assert_eq!(second_import.summary, expected_summary);
+		database.update(DatabaseMessage::ImportProjects(second_import));
+
+		// the already imported project is reused
+		assert_eq!(database.projects().len(), 1);
+		let project = database.get_project(&project_id).unwrap();
+		assert_eq!(project.total_tasks(), 3);
+		assert_eq!(project.task_tags.len(), 1);
+
+		let (task, task_type) = project.get_task_and_type(&task_id).unwrap();
+		assert_eq!(task_type, TaskType::Done);
+		assert_eq!(task.name, "Crash on startup");
+		// local changes that the source doesnt know about are kept
+		assert_eq!(task.needed_time_minutes, Some(30));
+	}
+
+	#[test]
+	fn test_reimport_updates_priority_and_due_time() {
+		let mut database = Database::default();
+		let issues = issues_project(&[("https://github.com/owner/repo/issues/1", "Crash", false)]);
+		let import = database.plan_import(vec![issues.clone()]);
+		database.update(DatabaseMessage::ImportProjects(import));
+
+		let mut changed_issues = issues;
+		let due_time = SerializableTime {
+			hour: 14,
+			minute: 30,
+		};
+		for task in changed_issues.values_mut() {
+			task.priority = TaskPriority::Urgent;
+			task.due_time = Some(due_time);
+		}
+		let reimport = database.plan_import(vec![changed_issues]);
+		assert_eq!(
+			reimport.summary,
+			ImportSummary {
+				created: 0,
+				updated: 1,
+				unchanged: 0
+			}
+		);
+		database.update(DatabaseMessage::ImportProjects(reimport));
+
+		let (_, project) = database.projects().iter().next().unwrap();
+		assert_eq!(project.total_tasks(), 1);
+		let (_, task, _) = project.iter().next().unwrap();
+		assert_eq!(task.priority, TaskPriority::Urgent);
+		assert_eq!(task.due_time, Some(due_time));
+	}
+
+	#[test]
+	fn test_import_without_origin_creates_tasks() {
+		let mut database = Database::default();
+		let mut project = Project::new(
+			"todo".to_string(),
+			SerializableColor::default(),
+			OrderedHashMap::new(),
+			SortMode::default(),
+		);
+		project.add_task(
+			TaskId::generate(),
+			"Buy milk".to_string(),
+			String::new(),
+			BTreeSet::new(),
+			None,
+			None,
+			None,
+			false,
+		);
+
+		let first_import = database.plan_import(vec![project.clone()]);
+		database.update(DatabaseMessage::ImportProjects(first_import));
+		let second_import = database.plan_import(vec![project]);
+		database.update(DatabaseMessage::ImportProjects(second_import));
+
+		// imported into the existing project with the same name
+		assert_eq!(database.projects().len(), 1);
+		let (_, project) = database.projects().iter().next().unwrap();
+		assert_eq!(project.total_tasks(), 2);
+	}
+
+	#[test]
+	fn test_replicas_apply_the_same_import() {
+		let mut client = Database::default();
+		let mut server = Database::default();
+		let import = client.plan_import(vec![issues_project(&[
+			("https://github.com/owner/repo/issues/1", "Crash", false),
+			("https://github.com/owner/repo/issues/2", "Typo", true),
+		])]);
+		client.update(DatabaseMessage::ImportProjects(import.clone()));
+		server.update(DatabaseMessage::ImportProjects(import));
+		assert_eq!(client.checksum(), server.checksum());
+
+		let reimport = client.plan_import(vec![issues_project(&[
+			("https://github.com/owner/repo/issues/1", "Crash", true),
+			("https://github.com/owner/repo/issues/3", "Dark mode", false),
+		])]);
+		client.update(DatabaseMessage::ImportProjects(reimport.clone()));
+		server.update(DatabaseMessage::ImportProjects(reimport));
+		assert_eq!(client.checksum(), server.checksum());
+		assert_eq!(server.projects().len(), 1);
+	}
+}
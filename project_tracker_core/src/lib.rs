@@ -7,10 +7,12 @@
 mod database;
 pub use database::{
 	get_last_modification_date_time, toggle_task_description_markdown_task, Database,
-	DatabaseMessage, LoadDatabaseError, LoadDatabaseResult, SaveDatabaseError, SerializedDatabase,
-	SyncDatabaseResult,
+	DatabaseMessage, LoadDatabaseError, LoadDatabaseResult, SaveDatabaseError, SaveDatabaseResult,
+	SerializedDatabase, SyncDatabaseResult,
 };
 
+mod database_binary;
+
 mod ordered_hash_map;
 pub use ordered_hash_map::OrderedHashMap;
 
@@ -20,11 +22,50 @@ pub use project::{Project, ProjectId, SerializableColor, SortMode};
 mod task;
 pub use task::{
 	duration_str, duration_to_minutes, parse_duration_from_str, round_duration_to_minutes,
-	round_duration_to_seconds, Task, TaskId, TaskType, TimeSpend,
+	round_duration_to_seconds, Task, TaskId, TaskPriority, TaskType, TimeSpend,
 };
 
 mod date;
-pub use date::SerializableDate;
+pub use date::{SerializableDate, SerializableTime};
+
+mod day_plan;
+pub use day_plan::TaskPlan;
+
+mod time_block;
+pub use time_block::{TimeBlock, TrackedSession, DEFAULT_TIME_BLOCK_MINUTES};
+
+mod icalendar;
+pub use icalendar::ICalendarTaskComponent;
+
+mod import;
+pub use import::{ImportPlan, ImportSource, ImportSummary, TaskOrigin};
+
+mod markdown_import;
+pub use markdown_import::ImportMarkdownError;
+
+mod reminder;
+pub use reminder::{resolve_local_date_time, TaskReminder};
+
+mod source_code_todo_settings;
+pub use source_code_todo_settings::{
+	SourceCodeTodoBlame, SourceCodeTodoKeyword, SourceCodeTodoSettings,
+	DEFAULT_MAX_SOURCE_CODE_FILE_SIZE_BYTES,
+};
+
+mod git_commit;
+pub use git_commit::{GitCommit, ScannedGitCommit};
+
+mod attachment;
+pub use attachment::{
+	attachment_hash, attachments_directory, is_valid_blob_file_name, load_attachment_blob,
+	missing_attachment_blobs, remove_attachment_blobs, store_attachment_blob, Attachment,
+};
+
+mod comment;
+pub use comment::{TaskComment, TaskCommentId};
+
+mod quick_add;
+pub use quick_add::{parse_quick_add_date, QuickAddTask};
 
 mod task_tag;
 pub use task_tag::{TaskTag, TaskTagId};
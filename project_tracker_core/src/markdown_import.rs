@@ -0,0 +1,421 @@
+use crate::{
+	parse_duration_from_str, Database, OrderedHashMap, Project, SerializableColor,
+	SerializableDate, SortMode, Task, TaskId, TimeSpend,
+};
+use chrono::NaiveDate;
+use std::{collections::BTreeSet, path::PathBuf, time::Duration};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ImportMarkdownError {
+	#[error("failed to read markdown folder: {folder_path}, error: {error}")]
+	FailedToReadFolder {
+		folder_path: PathBuf,
+		error: std::io::Error,
+	},
+	#[error("failed to read markdown file: {filepath}, error: {error}")]
+	FailedToReadFile {
+		filepath: PathBuf,
+		error: std::io::Error,
+	},
+}
+
+impl Database {
+	/// reverses 'Database::export_as_markdown': every '.md' file inside the folder becomes a project
+	pub async fn import_markdown(
+		folder_path: PathBuf,
+	) -> Result<Vec<Project>, ImportMarkdownError> {
+		let read_folder_error = |error| ImportMarkdownError::FailedToReadFolder {
+			folder_path: folder_path.clone(),
+			error,
+		};
+
+		let mut filepaths = Vec::new();
+		let mut read_dir = tokio::fs::read_dir(&folder_path)
+			.await
+			.map_err(read_folder_error)?;
+		while let Some(entry) = read_dir.next_entry().await.map_err(read_folder_error)? {
+			let filepath = entry.path();
+			if filepath
+				.extension()
+				.is_some_and(|extension| extension == "md")
+			{
+				filepaths.push(filepath);
+			}
+		}
+		filepaths.sort();
+
+		let mut projects = Vec::with_capacity(filepaths.len());
+		for filepath in filepaths {
+			let markdown = tokio::fs::read_to_string(&filepath)
+				.await
+				.map_err(|error| ImportMarkdownError::FailedToReadFile {
+					filepath: filepath.clone(),
+					error,
+				})?;
+			let project_name = filepath
+				.file_stem()
+				.map(|file_stem| file_stem.to_string_lossy().to_string())
+				.unwrap_or_default();
+			projects.push(Project::from_markdown(project_name, &markdown));
+		}
+		Ok(projects)
+	}
+}
+
+impl Project {
+	/// parses '- [ ]' and '- [x]' checklist items, indented lines below an item become its description.
+	/// '#tags', the '📅' due date and the time spend/needed of the markdown export are recognized
+	pub fn from_markdown(name: String, markdown: &str) -> Self {
+		let mut project = Project::new(
+			name,
+			SerializableColor([255, 255, 255]),
+			OrderedHashMap::new(),
+			SortMode::default(),
+		);
+
+		let mut current_task: Option<MarkdownTask> = None;
+
+		for line in markdown.lines() {
+			if let Some(task) = &mut current_task {
+				if let Some(description_line) = strip_indentation(line) {
+					task.description_lines.push(description_line.to_string());
+					continue;
+				}
+				if line.trim().is_empty() {
+					task.description_lines.push(String::new());
+					continue;
+				}
+			}
+
+			if let Some(task) = current_task.take() {
+				task.insert_into(&mut project);
+			}
+			current_task = MarkdownTask::parse(line);
+		}
+
+		if let Some(task) = current_task {
+			task.insert_into(&mut project);
+		}
+
+		project
+	}
+}
+
+#[derive(Debug, Default)]
+struct TimeInfo {
+	time_spend: Option<Duration>,
+	needed_time: Option<Duration>,
+}
+
+#[derive(Debug, Default)]
+struct MarkdownTask {
+	done: bool,
+	name: String,
+	tags: Vec<String>,
+	due_date: Option<SerializableDate>,
+	time_info: Option<TimeInfo>,
+	description_lines: Vec<String>,
+}
+
+impl MarkdownTask {
+	fn parse(line: &str) -> Option<Self> {
+		let item = ["- ", "* ", "+ "]
+			.iter()
+			.find_map(|bullet| line.strip_prefix(bullet))?;
+
+		let (done, content) = if let Some(content) = item.strip_prefix("[ ]") {
+			(false, content)
+		} else if let Some(content) = item
+			.strip_prefix("[x]")
+			.or_else(|| item.strip_prefix("[X]"))
+		{
+			(true, content)
+		} else {
+			return None;
+		};
+		if !content.is_empty() && !content.starts_with(char::is_whitespace) {
+			return None;
+		}
+
+		let (text, due_date, time_info) = match content.split_once('📅') {
+			Some((text, due_date_and_time_info)) => {
+				let due_date_and_time_info = due_date_and_time_info.trim_start();
+				let (due_date_str, time_info_str) = due_date_and_time_info
+					.split_once(char::is_whitespace)
+					.unwrap_or((due_date_and_time_info, ""));
+				(
+					text,
+					parse_due_date(due_date_str),
+					parse_time_info(time_info_str),
+				)
+			}
+			None => {
+				let (text, time_info) = split_time_info(content);
+				(text, None, time_info)
+			}
+		};
+
+		let mut tags = Vec::new();
+		let mut name_words = Vec::new();
+		for word in text.split_whitespace() {
+			match word.strip_prefix('#') {
+				// '#123' is most likely an issue number and not a tag
+				Some(tag)
+					if !tag.is_empty()
+						&& !tag.starts_with('#')
+						&& !tag.chars().all(|c| c.is_ascii_digit()) =>
+				{
+					tags.push(tag.to_string())
+				}
+				_ => name_words.push(word),
+			}
+		}
+		// the export pads '<' and '>' with spaces
+		let name = name_words.join(" ").replace(" < ", "<").replace(" > ", ">");
+
+		Some(Self {
+			done,
+			name,
+			tags,
+			due_date,
+			time_info,
+			description_lines: Vec::new(),
+		})
+	}
+
+	fn insert_into(mut self, project: &mut Project) {
+		let mut tags = BTreeSet::new();
+		for tag_name in self.tags {
+			tags.insert(
+				project.get_or_insert_task_tag(tag_name, SerializableColor([255, 255, 255])),
+			);
+		}
+
+		while self
+			.description_lines
+			.last()
+			.is_some_and(|line| line.is_empty())
+		{
+			self.description_lines.pop();
+		}
+
+		let time_info = self.time_info.unwrap_or_default();
+
+		let task = Task::new(
+			self.name,
+			self.description_lines.join("\n"),
+			time_info
+				.needed_time
+				.map(|needed_time| needed_time.as_secs() as usize / 60),
+			time_info
+				.time_spend
+				.map(|time_spend| TimeSpend::new(time_spend.as_secs_f32())),
+			self.due_date,
+			tags,
+		);
+
+		if self.done {
+			project.done_tasks.insert(TaskId::generate(), task);
+		} else {
+			project.todo_tasks.insert(TaskId::generate(), task);
+		}
+	}
+}
+
+/// the export indents with tabs, nested markdown lists with spaces
+fn strip_indentation(line: &str) -> Option<&str> {
+	line.strip_prefix('\t')
+		.or_else(|| line.strip_prefix("    "))
+		.or_else(|| line.strip_prefix("  "))
+}
+
+/// '2025-3-14' of the export or zero padded '2025-03-14'
+fn parse_due_date(due_date: &str) -> Option<SerializableDate> {
+	let mut parts = due_date.splitn(3, '-');
+	let year = parts.next()?.parse().ok()?;
+	let month = parts.next()?.parse().ok()?;
+	let day = parts.next()?.parse().ok()?;
+	NaiveDate::from_ymd_opt(year, month, day).map(SerializableDate::from)
+}
+
+/// ' time needed: 2h', ' time spend: 30m' or ' 30m/2h'
+fn parse_time_info(time_info: &str) -> Option<TimeInfo> {
+	let time_info = time_info.trim();
+	if let Some(needed_time) = time_info.strip_prefix("time needed:") {
+		return Some(TimeInfo {
+			time_spend: None,
+			needed_time: Some(parse_duration_from_str(needed_time.trim())?),
+		});
+	}
+	if let Some(time_spend) = time_info.strip_prefix("time spend:") {
+		return Some(TimeInfo {
+			time_spend: Some(parse_duration_from_str(time_spend.trim())?),
+			needed_time: None,
+		});
+	}
+	let (time_spend, needed_time) = time_info.split_once('/')?;
+	Some(TimeInfo {
+		time_spend: Some(parse_duration_from_str(time_spend.trim())?),
+		needed_time: Some(parse_duration_from_str(needed_time.trim())?),
+	})
+}
+
+/// splits the time info at the end of tasks without a due date from the rest of the text
+fn split_time_info(text: &str) -> (&str, Option<TimeInfo>) {
+	for marker in [" time needed:", " time spend:"] {
+		if let Some(index) = text.rfind(marker) {
+			if let Some(time_info) = parse_time_info(&text[index..]) {
+				return (&text[..index], Some(time_info));
+			}
+		}
+	}
+
+	if let Some((before_slash, after_slash)) = text.rsplit_once('/') {
+		if let Some(needed_time) = parse_duration_from_str(after_slash.trim()) {
+			// longest suffix of words before the slash that is a duration, like '1h 30m'
+			for (index, _) in before_slash
+				.char_indices()
+				.filter(|(_, c)| c.is_whitespace())
+			{
+				if let Some(time_spend) = parse_duration_from_str(before_slash[index..].trim()) {
+					return (
+						&before_slash[..index],
+						Some(TimeInfo {
+							time_spend: Some(time_spend),
+							needed_time: Some(needed_time),
+						}),
+					);
+				}
+			}
+		}
+	}
+
+	(text, None)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use crate::{
+		Database, OrderedHashMap, Project, ProjectId, SerializableColor, SerializableDate,
+		SortMode, TaskId, TaskTag, TaskTagId, TimeSpend,
+	};
+	use std::collections::BTreeSet;
+
+	#[test]
+	fn test_generic_markdown_checklist() {
+		let markdown = "\
+# Groceries
+
+Some notes that are not a task.
+
+- [ ] Milk #dairy
+* [x] Bread #bakery #dairy
+  from the bakery
+  - [ ] ask for whole grain
+
+- [ ] Fix #12 <script> tags 📅 2025-03-14
+- not a task
+";
+		let project = Project::from_markdown("Groceries".to_string(), markdown);
+		assert_eq!(project.todo_tasks.len(), 2);
+		assert_eq!(project.done_tasks.len(), 1);
+		assert_eq!(project.task_tags.len(), 2);
+
+		let (_, bread) = project.done_tasks.iter().next().unwrap();
+		assert_eq!(bread.name, "Bread");
+		assert_eq!(bread.tags.len(), 2);
+		assert_eq!(
+			bread.description,
+			"from the bakery\n- [ ] ask for whole grain"
+		);
+
+		let (_, fix) = project.todo_tasks.iter().nth(1).unwrap();
+		assert_eq!(fix.name, "Fix #12 <script> tags");
+		assert!(fix.tags.is_empty());
+		assert_eq!(
+			fix.due_date,
+			Some(SerializableDate {
+				year: 2025,
+				month: 3,
+				day: 14
+			})
+		);
+	}
+
+	#[tokio::test]
+	async fn test_markdown_export_round_trip() {
+		let mut project = Project::new(
+			"Round Trip".to_string(),
+			SerializableColor::default(),
+			OrderedHashMap::new(),
+			SortMode::default(),
+		);
+		let tag_id = TaskTagId::generate();
+		project.task_tags.insert(
+			tag_id,
+			TaskTag::new("feature".to_string(), SerializableColor::default()),
+		);
+		project.add_task(
+			TaskId::generate(),
+			"Write a parser".to_string(),
+			"first line\n\nthird line".to_string(),
+			BTreeSet::from([tag_id]),
+			Some(SerializableDate {
+				year: 2025,
+				month: 3,
+				day: 4,
+			}),
+			Some(120),
+			Some(TimeSpend::new(90.0 * 60.0)),
+			false,
+		);
+		project.add_task(
+			TaskId::generate(),
+			"Needs time".to_string(),
+			String::new(),
+			BTreeSet::new(),
+			None,
+			Some(45),
+			None,
+			false,
+		);
+		project.add_task(
+			TaskId::generate(),
+			"Already spend time".to_string(),
+			String::new(),
+			BTreeSet::new(),
+			None,
+			None,
+			Some(TimeSpend::new(30.0 * 60.0)),
+			false,
+		);
+		let mut projects = OrderedHashMap::new();
+		projects.insert(ProjectId::generate(), project.clone());
+
+		let folder_path = std::env::temp_dir().join("tmp_test_markdown_export_round_trip");
+		tokio::fs::create_dir_all(&folder_path).await.unwrap();
+		Database::export_as_markdown(folder_path.clone(), projects)
+			.await
+			.unwrap();
+		let imported_projects = Database::import_markdown(folder_path.clone())
+			.await
+			.unwrap();
+		tokio::fs::remove_dir_all(&folder_path).await.unwrap();
+
+		assert_eq!(imported_projects.len(), 1);
+		let imported_project = &imported_projects[0];
+		assert_eq!(imported_project.name, project.name);
+		assert_eq!(imported_project.todo_tasks.len(), 3);
+
+		for ((_, task, _), (_, imported_task, _)) in project.iter().zip(imported_project.iter()) {
+			assert_eq!(imported_task.name, task.name);
+			assert_eq!(imported_task.description, task.description);
+			assert_eq!(imported_task.due_date, task.due_date);
+			assert_eq!(imported_task.needed_time_minutes, task.needed_time_minutes);
+			assert_eq!(imported_task.time_spend, task.time_spend);
+			assert_eq!(imported_task.tags.len(), task.tags.len());
+		}
+	}
+}
@@ -191,22 +191,22 @@ where
 		self.order.is_empty()
 	}
 
-	pub fn iter(&self) -> OrderedHashMapIter<K, V> {
+	pub fn iter(&self) -> OrderedHashMapIter<'_, K, V> {
 		OrderedHashMapIter {
 			order_iter: self.order.iter(),
 			hash_map: &self.hash_map,
 		}
 	}
 
-	pub fn keys(&self) -> Iter<K> {
+	pub fn keys(&self) -> Iter<'_, K> {
 		self.order.iter()
 	}
 
-	pub fn values(&self) -> Values<K, V> {
+	pub fn values(&self) -> Values<'_, K, V> {
 		self.hash_map.values()
 	}
 
-	pub fn values_mut(&mut self) -> ValuesMut<K, V> {
+	pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
 		self.hash_map.values_mut()
 	}
 }
@@ -1,5 +1,7 @@
 use crate::{
-	OrderedHashMap, SerializableDate, Task, TaskId, TaskTag, TaskTagId, TaskType, TimeSpend,
+	OrderedHashMap, SerializableDate, SerializableTime, SourceCodeTodoSettings, Task, TaskId,
+	TaskOrigin, TaskPriority, TaskReminder, TaskTag, TaskTagId, TaskType, TimeBlock, TimeSpend,
+	TrackedSession,
 };
 use serde::{Deserialize, Serialize};
 use std::{collections::BTreeSet, path::PathBuf};
@@ -20,6 +22,9 @@ pub enum SortMode {
 	Manual,
 	DueDate,
 	NeededTime,
+	Priority,
+	/// oldest source code todos first, see 'SourceCodeTodoBlame'
+	Age,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -33,6 +38,8 @@ pub struct Project {
 	pub source_code_todos: OrderedHashMap<TaskId, Task>,
 	#[serde(default)]
 	pub source_code_directory: Option<PathBuf>,
+	#[serde(default)]
+	pub source_code_todo_settings: SourceCodeTodoSettings,
 }
 
 impl Project {
@@ -51,6 +58,7 @@ impl Project {
 			done_tasks: OrderedHashMap::new(),
 			source_code_todos: OrderedHashMap::new(),
 			source_code_directory: None,
+			source_code_todo_settings: SourceCodeTodoSettings::default(),
 		}
 	}
 
@@ -81,6 +89,23 @@ impl Project {
 			.or(self.source_code_todos.get_mut(task_id))
 	}
 
+	pub fn find_task_tag(&self, name: &str) -> Option<TaskTagId> {
+		self.task_tags
+			.iter()
+			.find(|(_, task_tag)| task_tag.name == name)
+			.map(|(task_tag_id, _)| task_tag_id)
+	}
+
+	/// task tags are matched by name, a missing one is created with the given color
+	pub fn get_or_insert_task_tag(&mut self, name: String, color: SerializableColor) -> TaskTagId {
+		self.find_task_tag(&name).unwrap_or_else(|| {
+			let task_tag_id = TaskTagId::generate();
+			self.task_tags
+				.insert(task_tag_id, TaskTag::new(name, color));
+			task_tag_id
+		})
+	}
+
 	#[allow(clippy::too_many_arguments)]
 	pub fn add_task(
 		&mut self,
@@ -142,16 +167,35 @@ impl Project {
 		}
 	}
 
+	/// source code todos stay until their comment is removed from the code
 	pub fn set_task_done(&mut self, task_id: TaskId) {
-		if let Some(task) = self
-			.todo_tasks
-			.remove(&task_id)
-			.or(self.source_code_todos.remove(&task_id))
-		{
+		if let Some(task) = self.todo_tasks.remove(&task_id) {
 			self.done_tasks.insert(task_id, task);
 		}
 	}
 
+	/// copies the source code todo into the todo tasks, linked back to it by 'origin'
+	pub fn promote_source_code_todo(
+		&mut self,
+		source_code_todo_id: TaskId,
+		task_id: TaskId,
+		origin: TaskOrigin,
+	) {
+		let already_promoted = self
+			.todo_tasks
+			.values()
+			.chain(self.done_tasks.values())
+			.any(|task| task.origin.as_ref() == Some(&origin));
+		if already_promoted {
+			return;
+		}
+		if let Some(source_code_todo) = self.source_code_todos.get(&source_code_todo_id) {
+			let mut task = source_code_todo.clone();
+			task.origin = Some(origin);
+			self.todo_tasks.insert(task_id, task);
+		}
+	}
+
 	pub fn set_task_needed_time(
 		&mut self,
 		task_id: TaskId,
@@ -181,20 +225,69 @@ impl Project {
 		}
 	}
 
-	pub fn stop_task_time_spend(&mut self, task_id: TaskId) {
+	pub fn stop_task_time_spend(
+		&mut self,
+		task_id: TaskId,
+		tracked_session: Option<TrackedSession>,
+	) {
 		if let Some(task) = self.get_task_mut(&task_id) {
 			if let Some(time_spend) = &mut task.time_spend {
 				time_spend.stop();
 			}
+			if let Some(tracked_session) = tracked_session {
+				task.record_tracked_session(tracked_session);
+			}
 		}
 	}
 
 	pub fn set_task_due_date(&mut self, task_id: TaskId, new_due_date: Option<SerializableDate>) {
 		if let Some(task) = self.get_task_mut(&task_id) {
+			if new_due_date.is_none() {
+				task.due_time = None;
+			}
 			task.due_date = new_due_date;
 		}
 	}
 
+	pub fn set_task_due_time(&mut self, task_id: TaskId, new_due_time: Option<SerializableTime>) {
+		if let Some(task) = self.get_task_mut(&task_id) {
+			task.due_time = new_due_time;
+		}
+	}
+
+	pub fn set_task_time_block(&mut self, task_id: TaskId, new_time_block: Option<TimeBlock>) {
+		if let Some(task) = self.get_task_mut(&task_id) {
+			task.time_block = new_time_block;
+		}
+	}
+
+	pub fn add_task_reminder(&mut self, task_id: TaskId, reminder: TaskReminder) {
+		if let Some(task) = self.get_task_mut(&task_id) {
+			if !task.reminders.contains(&reminder) {
+				task.reminders.push(reminder);
+			}
+		}
+	}
+
+	pub fn remove_task_reminder(&mut self, task_id: TaskId, reminder: TaskReminder) {
+		if let Some(task) = self.get_task_mut(&task_id) {
+			task.reminders
+				.retain(|task_reminder| *task_reminder != reminder);
+		}
+	}
+
+	pub fn add_task_pomodoro(&mut self, task_id: TaskId) {
+		if let Some(task) = self.get_task_mut(&task_id) {
+			task.completed_pomodoros += 1;
+		}
+	}
+
+	pub fn set_task_priority(&mut self, task_id: TaskId, new_priority: TaskPriority) {
+		if let Some(task) = self.get_task_mut(&task_id) {
+			task.priority = new_priority;
+		}
+	}
+
 	pub fn toggle_task_tag(&mut self, task_id: TaskId, task_tag_id: TaskTagId) {
 		if let Some(task) = self.get_task_mut(&task_id) {
 			if task.tags.contains(&task_tag_id) {
@@ -248,7 +341,10 @@ pub struct SerializableColor(pub [u8; 3]);
 
 #[cfg(test)]
 mod tests {
-	use crate::{OrderedHashMap, Project, SerializableColor, SortMode, Task, TaskId, TaskType};
+	use crate::{
+		ImportSource, OrderedHashMap, Project, SerializableColor, SortMode, Task, TaskId,
+		TaskOrigin, TaskType,
+	};
 	use std::collections::BTreeSet;
 
 	#[test]
@@ -312,4 +408,49 @@ mod tests {
 		assert_eq!(iterated_task_count, project.total_tasks());
 		assert_eq!(iterated_task_count, 3);
 	}
+
+	#[test]
+	fn test_promoting_source_code_todo_links_copy_once() {
+		let mut project = Project::new(
+			"Test Project".to_string(),
+			SerializableColor::default(),
+			OrderedHashMap::new(),
+			SortMode::Manual,
+		);
+
+		let source_code_task_id = TaskId::generate();
+		project.source_code_todos.insert(
+			source_code_task_id,
+			Task::new(
+				"Source code todo".to_string(),
+				"src/main.rs:1:4".to_string(),
+				None,
+				None,
+				None,
+				BTreeSet::new(),
+			),
+		);
+
+		// the comment still exists, so it can't be done
+		project.set_task_done(source_code_task_id);
+		assert!(project.done_tasks.is_empty());
+		assert_eq!(project.source_code_todos.len(), 1);
+
+		let origin = TaskOrigin::new(
+			ImportSource::SourceCode,
+			"src/main.rs\nsource code todo".to_string(),
+		);
+		let task_id = TaskId::generate();
+		project.promote_source_code_todo(source_code_task_id, task_id, origin.clone());
+		project.promote_source_code_todo(source_code_task_id, TaskId::generate(), origin.clone());
+
+		assert_eq!(project.todo_tasks.len(), 1);
+		assert_eq!(project.source_code_todos.len(), 1);
+		let task = project.todo_tasks.get(&task_id);
+		assert_eq!(
+			task.map(|task| task.name.as_str()),
+			Some("Source code todo")
+		);
+		assert_eq!(task.and_then(|task| task.origin.clone()), Some(origin));
+	}
 }
@@ -0,0 +1,248 @@
+use crate::{
+	duration_to_minutes, parse_duration_from_str, OrderedHashMap, SerializableDate, TaskTag,
+	TaskTagId,
+};
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use std::collections::BTreeSet;
+
+/// Result of parsing a single line like `Fix login crash #Bug @tomorrow ~2h !top`
+///
+/// `#tag`: existing tag of the project (case insensitive), otherwise a new tag name
+/// `@date`: today, tomorrow, weekday names, `+3d`/`+2w` or an iso date (2025-03-14)
+/// `~duration`: needed time, parsed with `parse_duration_from_str`
+/// `!top`/`!bottom`: where to insert the task
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuickAddTask {
+	pub name: String,
+	pub task_tags: BTreeSet<TaskTagId>,
+	/// only populated when 'create_missing_task_tags' is enabled
+	pub new_task_tag_names: Vec<String>,
+	pub due_date: Option<SerializableDate>,
+	pub needed_time_minutes: Option<usize>,
+	/// None: use preferences default
+	pub create_at_top: Option<bool>,
+}
+
+impl QuickAddTask {
+	pub fn parse(
+		input: &str,
+		task_tags: &OrderedHashMap<TaskTagId, TaskTag>,
+		create_missing_task_tags: bool,
+		today: NaiveDate,
+	) -> Self {
+		let mut quick_add_task = Self::default();
+		let mut name_words = Vec::new();
+
+		for word in input.split_whitespace() {
+			let parsed = if let Some(tag_name) = word.strip_prefix('#') {
+				quick_add_task.parse_tag(tag_name, task_tags, create_missing_task_tags)
+			} else if let Some(date_str) = word.strip_prefix('@') {
+				parse_quick_add_date(date_str, today)
+					.map(|due_date| quick_add_task.due_date = Some(due_date.into()))
+					.is_some()
+			} else if let Some(duration_str) = word.strip_prefix('~') {
+				parse_duration_from_str(duration_str)
+					.map(|duration| {
+						quick_add_task.needed_time_minutes = Some(duration_to_minutes(duration))
+					})
+					.is_some()
+			} else if let Some(position) = word.strip_prefix('!') {
+				match position.to_lowercase().as_str() {
+					"top" => {
+						quick_add_task.create_at_top = Some(true);
+						true
+					}
+					"bottom" => {
+						quick_add_task.create_at_top = Some(false);
+						true
+					}
+					_ => false,
+				}
+			} else {
+				false
+			};
+
+			if !parsed {
+				name_words.push(word);
+			}
+		}
+
+		quick_add_task.name = name_words.join(" ");
+		quick_add_task
+	}
+
+	// returns if the word was consumed as a tag
+	fn parse_tag(
+		&mut self,
+		tag_name: &str,
+		task_tags: &OrderedHashMap<TaskTagId, TaskTag>,
+		create_missing_task_tags: bool,
+	) -> bool {
+		if tag_name.is_empty() {
+			return false;
+		}
+
+		let existing_tag_id = task_tags
+			.iter()
+			.find(|(_tag_id, tag)| tag.name.to_lowercase() == tag_name.to_lowercase())
+			.map(|(tag_id, _tag)| tag_id);
+
+		match existing_tag_id {
+			Some(tag_id) => {
+				self.task_tags.insert(tag_id);
+				true
+			}
+			None if create_missing_task_tags => {
+				if !self
+					.new_task_tag_names
+					.iter()
+					.any(|new_tag_name| new_tag_name.to_lowercase() == tag_name.to_lowercase())
+				{
+					self.new_task_tag_names.push(tag_name.to_string());
+				}
+				true
+			}
+			None => false,
+		}
+	}
+}
+
+/// weekdays always refer to the next occurence after today
+pub fn parse_quick_add_date(date_str: &str, today: NaiveDate) -> Option<NaiveDate> {
+	let lowercase_date_str = date_str.to_lowercase();
+
+	match lowercase_date_str.as_str() {
+		"today" | "tod" => return Some(today),
+		"tomorrow" | "tmr" | "tom" => return today.succ_opt(),
+		"yesterday" => return today.pred_opt(),
+		"nextweek" | "next-week" => {
+			return today
+				.week(Weekday::Mon)
+				.first_day()
+				.checked_add_days(Days::new(7))
+		}
+		_ => {}
+	}
+
+	if let Some(weekday) = parse_weekday(&lowercase_date_str) {
+		let days_until =
+			(7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+		let days_until = if days_until == 0 { 7 } else { days_until };
+		return today.checked_add_days(Days::new(days_until as u64));
+	}
+
+	if let Some(relative) = lowercase_date_str.strip_prefix('+') {
+		if let Some(amount) = relative.strip_suffix('d') {
+			return today.checked_add_days(Days::new(amount.parse().ok()?));
+		}
+		if let Some(amount) = relative.strip_suffix('w') {
+			let amount: u64 = amount.parse().ok()?;
+			return today.checked_add_days(Days::new(amount * 7));
+		}
+		return None;
+	}
+
+	NaiveDate::parse_from_str(&lowercase_date_str, "%Y-%m-%d").ok()
+}
+
+fn parse_weekday(weekday_str: &str) -> Option<Weekday> {
+	match weekday_str {
+		"mon" | "monday" => Some(Weekday::Mon),
+		"tue" | "tuesday" => Some(Weekday::Tue),
+		"wed" | "wednesday" => Some(Weekday::Wed),
+		"thu" | "thursday" => Some(Weekday::Thu),
+		"fri" | "friday" => Some(Weekday::Fri),
+		"sat" | "saturday" => Some(Weekday::Sat),
+		"sun" | "sunday" => Some(Weekday::Sun),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use crate::{OrderedHashMap, QuickAddTask, SerializableColor, TaskTag, TaskTagId};
+	use chrono::NaiveDate;
+
+	// friday
+	fn today() -> NaiveDate {
+		NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()
+	}
+
+	#[test]
+	fn test_parse_all_fields() {
+		let mut task_tags = OrderedHashMap::new();
+		let bug_tag_id = TaskTagId::generate();
+		task_tags.insert(
+			bug_tag_id,
+			TaskTag::new("bug".to_string(), SerializableColor::default()),
+		);
+
+		let quick_add_task = QuickAddTask::parse(
+			"Fix login crash #Bug @tomorrow ~2h !top",
+			&task_tags,
+			true,
+			today(),
+		);
+
+		assert_eq!(quick_add_task.name, "Fix login crash");
+		assert!(quick_add_task.task_tags.contains(&bug_tag_id));
+		assert!(quick_add_task.new_task_tag_names.is_empty());
+		assert_eq!(
+			quick_add_task.due_date,
+			Some(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap().into())
+		);
+		assert_eq!(quick_add_task.needed_time_minutes, Some(120));
+		assert_eq!(quick_add_task.create_at_top, Some(true));
+	}
+
+	#[test]
+	fn test_parse_missing_tags() {
+		let task_tags = OrderedHashMap::new();
+
+		let creating = QuickAddTask::parse("Write docs #Docs #docs", &task_tags, true, today());
+		assert_eq!(creating.name, "Write docs");
+		assert_eq!(creating.new_task_tag_names, vec!["Docs".to_string()]);
+
+		let not_creating = QuickAddTask::parse("Write docs #Docs", &task_tags, false, today());
+		assert_eq!(not_creating.name, "Write docs #Docs");
+		assert!(not_creating.new_task_tag_names.is_empty());
+	}
+
+	#[test]
+	fn test_parse_dates() {
+		let parse = |input: &str| QuickAddTask::parse(input, &OrderedHashMap::new(), true, today());
+
+		assert_eq!(parse("a @today").due_date, Some(today().into()));
+		assert_eq!(
+			parse("a @monday").due_date,
+			Some(NaiveDate::from_ymd_opt(2025, 2, 3).unwrap().into())
+		);
+		assert_eq!(
+			parse("a @fri").due_date,
+			Some(NaiveDate::from_ymd_opt(2025, 2, 7).unwrap().into())
+		);
+		assert_eq!(
+			parse("a @+3d").due_date,
+			Some(NaiveDate::from_ymd_opt(2025, 2, 3).unwrap().into())
+		);
+		assert_eq!(
+			parse("a @+2w").due_date,
+			Some(NaiveDate::from_ymd_opt(2025, 2, 14).unwrap().into())
+		);
+		assert_eq!(
+			parse("a @2025-03-14").due_date,
+			Some(NaiveDate::from_ymd_opt(2025, 3, 14).unwrap().into())
+		);
+
+		let invalid = parse("meet @someone ~soon !later");
+		assert_eq!(invalid.name, "meet @someone ~soon !later");
+		assert_eq!(invalid.due_date, None);
+		assert_eq!(invalid.needed_time_minutes, None);
+		assert_eq!(invalid.create_at_top, None);
+
+		// multibyte characters after the amount
+		assert_eq!(parse("a @+3ä").due_date, None);
+		assert_eq!(parse("a @+ä").due_date, None);
+	}
+}
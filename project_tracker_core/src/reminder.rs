@@ -0,0 +1,188 @@
+use crate::{duration_to_minutes, parse_duration_from_str, Task};
+use chrono::{DateTime, NaiveDateTime, TimeDelta, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TaskReminder {
+	/// fires at a fixed point in time, independent of the timezone
+	At(DateTime<Utc>),
+	/// fires relative to the due date (and due time, if set) of the task
+	BeforeDue { minutes: usize },
+}
+
+impl TaskReminder {
+	pub fn fire_time<Tz: TimeZone>(&self, task: &Task, timezone: &Tz) -> Option<DateTime<Utc>> {
+		match self {
+			Self::At(date_time) => Some(*date_time),
+			Self::BeforeDue { minutes } => task
+				.due_date_time(timezone)?
+				.checked_sub_signed(TimeDelta::minutes(*minutes as i64)),
+		}
+	}
+
+	/// parses durations like '15min' or '1h 30min' as 'BeforeDue'
+	/// and local date times like '2025-03-14 09:30' as 'At'
+	pub fn parse<Tz: TimeZone>(input: &str, timezone: &Tz) -> Option<Self> {
+		let input = input.trim();
+		if let Some(duration) = parse_duration_from_str(input) {
+			return Some(Self::BeforeDue {
+				minutes: duration_to_minutes(duration),
+			});
+		}
+		NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M")
+			.ok()
+			.and_then(|date_time| resolve_local_date_time(date_time, timezone))
+			.map(Self::At)
+	}
+}
+
+/// ambiguous times (dst ends) resolve to the earlier one,
+/// times that dont exist (dst starts) are moved one hour later
+pub fn resolve_local_date_time<Tz: TimeZone>(
+	date_time: NaiveDateTime,
+	timezone: &Tz,
+) -> Option<DateTime<Utc>> {
+	timezone
+		.from_local_datetime(&date_time)
+		.earliest()
+		.or_else(|| {
+			timezone
+				.from_local_datetime(&(date_time + TimeDelta::hours(1)))
+				.earliest()
+		})
+		.map(|date_time| date_time.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use crate::{
+		Database, DatabaseMessage, ProjectId, SerializableColor, SerializableDate,
+		SerializableTime, Task, TaskId, TaskReminder,
+	};
+	use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+	use std::collections::BTreeSet;
+
+	fn task_due_at(hour: u32, minute: u32) -> Task {
+		let mut task = Task::new(
+			"Standup".to_string(),
+			String::new(),
+			None,
+			None,
+			Some(NaiveDate::from_ymd_opt(2025, 3, 14).unwrap().into()),
+			BTreeSet::new(),
+		);
+		task.due_time = Some(SerializableTime { hour, minute });
+		task
+	}
+
+	#[test]
+	fn test_before_due_respects_timezone() {
+		let task = task_due_at(14, 0);
+		let utc_plus_two = FixedOffset::east_opt(2 * 3600).unwrap();
+
+		assert_eq!(
+			task.due_date_time(&utc_plus_two),
+			Some(Utc.with_ymd_and_hms(2025, 3, 14, 12, 0, 0).unwrap())
+		);
+		assert_eq!(
+			TaskReminder::BeforeDue { minutes: 15 }.fire_time(&task, &utc_plus_two),
+			Some(Utc.with_ymd_and_hms(2025, 3, 14, 11, 45, 0).unwrap())
+		);
+
+		let mut task_without_time = task.clone();
+		task_without_time.due_time = None;
+		assert_eq!(
+			task_without_time.due_date_time(&Utc),
+			Some(Utc.with_ymd_and_hms(2025, 3, 14, 0, 0, 0).unwrap())
+		);
+
+		task_without_time.due_date = None;
+		assert_eq!(
+			TaskReminder::BeforeDue { minutes: 15 }.fire_time(&task_without_time, &Utc),
+			None
+		);
+	}
+
+	#[test]
+	fn test_parse_reminders() {
+		assert_eq!(
+			TaskReminder::parse("1h 30min", &Utc),
+			Some(TaskReminder::BeforeDue { minutes: 90 })
+		);
+		assert_eq!(
+			TaskReminder::parse("2025-03-14 09:30", &FixedOffset::west_opt(3600).unwrap()),
+			Some(TaskReminder::At(
+				Utc.with_ymd_and_hms(2025, 3, 14, 10, 30, 0).unwrap()
+			))
+		);
+		assert_eq!(TaskReminder::parse("tomorrow-ish", &Utc), None);
+		assert_eq!(
+			SerializableTime::parse("9:05"),
+			Some(SerializableTime { hour: 9, minute: 5 })
+		);
+		assert_eq!(SerializableTime::parse("25:00"), None);
+	}
+
+	#[test]
+	fn test_reminders_fire_once_in_window() {
+		let mut database = Database::default();
+		let project_id = ProjectId::generate();
+		let task_id = TaskId::generate();
+		database.update(DatabaseMessage::CreateProject {
+			project_id,
+			name: "Work".to_string(),
+			color: SerializableColor::default(),
+		});
+		database.update(DatabaseMessage::CreateTask {
+			project_id,
+			task_id,
+			task_name: "Standup".to_string(),
+			task_description: String::new(),
+			task_tags: BTreeSet::new(),
+			due_date: Some(SerializableDate {
+				year: 2025,
+				month: 3,
+				day: 14,
+			}),
+			needed_time_minutes: None,
+			time_spend: None,
+			create_at_top: false,
+		});
+		database.update(DatabaseMessage::ChangeTaskDueTime {
+			project_id,
+			task_id,
+			new_due_time: Some(SerializableTime {
+				hour: 14,
+				minute: 0,
+			}),
+		});
+		let reminder = TaskReminder::BeforeDue { minutes: 10 };
+		database.update(DatabaseMessage::AddTaskReminder {
+			project_id,
+			task_id,
+			reminder,
+		});
+
+		let at = |hour, minute| Utc.with_ymd_and_hms(2025, 3, 14, hour, minute, 0).unwrap();
+
+		assert!(database
+			.task_reminders_between(at(13, 0), at(13, 49), &Utc)
+			.is_empty());
+		assert_eq!(
+			database.task_reminders_between(at(13, 49), at(13, 50), &Utc),
+			vec![(project_id, task_id, reminder)]
+		);
+		assert!(database
+			.task_reminders_between(at(13, 50), at(13, 51), &Utc)
+			.is_empty());
+
+		database.update(DatabaseMessage::SetTaskDone {
+			project_id,
+			task_id,
+		});
+		assert!(database
+			.task_reminders_between(at(13, 49), at(13, 50), &Utc)
+			.is_empty());
+	}
+}
@@ -0,0 +1,128 @@
+use crate::TaskTagId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_MAX_SOURCE_CODE_FILE_SIZE_BYTES: u64 = 1024 * 1024;
+
+/// a comment keyword like 'TODO' or 'FIXME', matched case insensitive
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourceCodeTodoKeyword {
+	pub keyword: String,
+	/// imported todos with this keyword get this tag
+	pub task_tag: Option<TaskTagId>,
+}
+
+impl SourceCodeTodoKeyword {
+	pub fn new(keyword: String, task_tag: Option<TaskTagId>) -> Self {
+		Self { keyword, task_tag }
+	}
+}
+
+/// who added a source code todo and when, read from the git history of its file
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourceCodeTodoBlame {
+	pub author: String,
+	pub added: DateTime<Utc>,
+}
+
+/// how the todos of a projects 'source_code_directory' are imported
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourceCodeTodoSettings {
+	pub keywords: Vec<SourceCodeTodoKeyword>,
+	/// only files matching one of these are imported, if any
+	pub include_globs: Vec<String>,
+	pub exclude_globs: Vec<String>,
+	/// skip everything ignored by '.gitignore' and '.ignore' files
+	pub respect_ignore_files: bool,
+	pub max_file_size_bytes: Option<u64>,
+	/// look up the author and age of every todo with 'git blame'
+	#[serde(default)]
+	pub git_blame: bool,
+	/// mark tasks done once a commit referencing them lands on the default branch
+	#[serde(default)]
+	pub mark_done_by_commits: bool,
+}
+
+impl Default for SourceCodeTodoSettings {
+	fn default() -> Self {
+		Self {
+			keywords: vec![SourceCodeTodoKeyword::new("TODO".to_string(), None)],
+			include_globs: Vec::new(),
+			exclude_globs: vec!["target/".to_string()],
+			respect_ignore_files: true,
+			max_file_size_bytes: Some(DEFAULT_MAX_SOURCE_CODE_FILE_SIZE_BYTES),
+			git_blame: false,
+			mark_done_by_commits: false,
+		}
+	}
+}
+
+impl SourceCodeTodoSettings {
+	pub fn get_keyword(&self, keyword: &str) -> Option<&SourceCodeTodoKeyword> {
+		self.keywords.iter().find(|source_code_todo_keyword| {
+			source_code_todo_keyword
+				.keyword
+				.eq_ignore_ascii_case(keyword)
+		})
+	}
+
+	pub fn remove_task_tag(&mut self, task_tag_id: &TaskTagId) {
+		for keyword in self.keywords.iter_mut() {
+			if keyword.task_tag.as_ref() == Some(task_tag_id) {
+				keyword.task_tag = None;
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use crate::{
+		database::tests::create_test_project_database, DatabaseMessage, SerializableColor,
+		SourceCodeTodoKeyword, TaskTag, TaskTagId,
+	};
+
+	#[test]
+	fn test_deleting_task_tag_unlinks_keyword() {
+		let (mut database, project_id) = create_test_project_database([]);
+		let fixme_tag_id = TaskTagId::generate();
+		database.update(DatabaseMessage::CreateTaskTag {
+			project_id,
+			task_tag_id: fixme_tag_id,
+			task_tag: TaskTag::new("fixme".to_string(), SerializableColor::default()),
+		});
+
+		let mut new_settings = database
+			.get_project(&project_id)
+			.unwrap()
+			.source_code_todo_settings
+			.clone();
+		new_settings.keywords.push(SourceCodeTodoKeyword::new(
+			"FIXME".to_string(),
+			Some(fixme_tag_id),
+		));
+		database.update(DatabaseMessage::ChangeSourceCodeTodoSettings {
+			project_id,
+			new_settings,
+		});
+		let settings = &database
+			.get_project(&project_id)
+			.unwrap()
+			.source_code_todo_settings;
+		assert_eq!(
+			settings.get_keyword("fixme").unwrap().task_tag,
+			Some(fixme_tag_id)
+		);
+
+		database.update(DatabaseMessage::DeleteTaskTag {
+			project_id,
+			task_tag_id: fixme_tag_id,
+		});
+		let settings = &database
+			.get_project(&project_id)
+			.unwrap()
+			.source_code_todo_settings;
+		assert_eq!(settings.get_keyword("fixme").unwrap().task_tag, None);
+	}
+}
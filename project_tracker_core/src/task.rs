@@ -1,4 +1,9 @@
-use crate::{SerializableDate, TaskTagId};
+use crate::{
+	resolve_local_date_time, Attachment, GitCommit, SerializableDate, SerializableTime,
+	SourceCodeTodoBlame, TaskComment, TaskOrigin, TaskPlan, TaskReminder, TaskTagId, TimeBlock,
+	TrackedSession,
+};
+use chrono::{DateTime, NaiveTime, TimeZone, Utc};
 use humantime::format_duration;
 use serde::{Deserialize, Serialize};
 use std::{
@@ -30,6 +35,42 @@ impl TaskType {
 	}
 }
 
+#[derive(
+	Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum TaskPriority {
+	#[default]
+	None,
+	Low,
+	Medium,
+	High,
+	Urgent,
+}
+
+impl TaskPriority {
+	pub const ALL: [TaskPriority; 5] = [
+		Self::None,
+		Self::Low,
+		Self::Medium,
+		Self::High,
+		Self::Urgent,
+	];
+
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::None => "None",
+			Self::Low => "Low",
+			Self::Medium => "Medium",
+			Self::High => "High",
+			Self::Urgent => "Urgent",
+		}
+	}
+
+	pub fn is_none(&self) -> bool {
+		matches!(self, Self::None)
+	}
+}
+
 /// Note: hash implementation of 'TimeSpend' ignores 'tracking_time_start' and only uses u64 resolution of 'offset_seconds'
 /// this is needed for server synchronization!
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -83,6 +124,12 @@ impl TimeSpend {
 		}
 	}
 
+	/// duration of the currently running tracking session
+	pub fn tracking_duration(&self) -> Option<Duration> {
+		self.tracking_time_start
+			.map(|tracking_time_start| Instant::now().duration_since(tracking_time_start))
+	}
+
 	pub fn start(&mut self) {
 		self.stop();
 
@@ -110,6 +157,33 @@ pub struct Task {
 	#[serde(default)]
 	pub due_date: Option<SerializableDate>,
 	pub tags: BTreeSet<TaskTagId>,
+	#[serde(default)]
+	pub priority: TaskPriority,
+	#[serde(default)]
+	pub due_time: Option<SerializableTime>,
+	#[serde(default)]
+	pub reminders: Vec<TaskReminder>,
+	#[serde(default)]
+	pub plan: Option<TaskPlan>,
+	#[serde(default)]
+	pub completed_pomodoros: usize,
+	#[serde(default)]
+	pub time_block: Option<TimeBlock>,
+	#[serde(default)]
+	pub tracked_sessions: Vec<TrackedSession>,
+	#[serde(default)]
+	pub origin: Option<TaskOrigin>,
+	/// only set for (promoted) source code todos
+	#[serde(default)]
+	pub source_code_blame: Option<SourceCodeTodoBlame>,
+	/// newest first
+	#[serde(default)]
+	pub linked_commits: Vec<GitCommit>,
+	#[serde(default)]
+	pub attachments: Vec<Attachment>,
+	/// oldest first
+	#[serde(default)]
+	pub comments: Vec<TaskComment>,
 }
 
 impl Task {
@@ -128,9 +202,31 @@ impl Task {
 			time_spend,
 			due_date,
 			tags,
+			priority: TaskPriority::default(),
+			due_time: None,
+			reminders: Vec::new(),
+			plan: None,
+			completed_pomodoros: 0,
+			time_block: None,
+			tracked_sessions: Vec::new(),
+			origin: None,
+			source_code_blame: None,
+			linked_commits: Vec::new(),
+			attachments: Vec::new(),
+			comments: Vec::new(),
 		}
 	}
 
+	/// tasks without a due time are due at the start of their due date
+	pub fn due_date_time<Tz: TimeZone>(&self, timezone: &Tz) -> Option<DateTime<Utc>> {
+		let due_date = self.due_date?.naive_date()?;
+		let due_time = match &self.due_time {
+			Some(due_time) => due_time.naive_time()?,
+			None => NaiveTime::MIN,
+		};
+		resolve_local_date_time(due_date.and_time(due_time), timezone)
+	}
+
 	pub fn matches_filter(&self, filter: &HashSet<TaskTagId>) -> bool {
 		for tag_id in filter.iter() {
 			if !self.tags.contains(tag_id) {
@@ -0,0 +1,203 @@
+use crate::{resolve_local_date_time, SerializableDate, SerializableTime, Task};
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+pub const DEFAULT_TIME_BLOCK_MINUTES: usize = 30;
+
+/// planned slot of time to work on a task, interpreted in the local timezone of the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TimeBlock {
+	pub date: SerializableDate,
+	pub start: SerializableTime,
+	/// 'None' falls back to the needed time of the task
+	pub duration_minutes: Option<usize>,
+}
+
+impl TimeBlock {
+	pub fn start_date_time<Tz: TimeZone>(&self, timezone: &Tz) -> Option<DateTime<Utc>> {
+		let date = self.date.naive_date()?;
+		let start = self.start.naive_time()?;
+		resolve_local_date_time(date.and_time(start), timezone)
+	}
+}
+
+/// finished time tracking session of a task
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TrackedSession {
+	pub start: DateTime<Utc>,
+	pub end: DateTime<Utc>,
+}
+
+impl TrackedSession {
+	/// only call this on the client, replicas of the database
+	/// need to record the exact same session
+	pub fn ending_now(duration: Duration) -> Self {
+		let end = Utc::now();
+		let start = TimeDelta::from_std(duration)
+			.ok()
+			.and_then(|duration| end.checked_sub_signed(duration))
+			.unwrap_or(end);
+		Self { start, end }
+	}
+
+	pub fn overlap(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> TimeDelta {
+		let overlap = self.end.min(end) - self.start.max(start);
+		overlap.max(TimeDelta::zero())
+	}
+}
+
+impl Task {
+	pub fn time_block_minutes(&self) -> Option<usize> {
+		self.time_block.map(|time_block| {
+			time_block
+				.duration_minutes
+				.or(self.needed_time_minutes)
+				.unwrap_or(DEFAULT_TIME_BLOCK_MINUTES)
+		})
+	}
+
+	/// start and end of the time block
+	pub fn time_block_range<Tz: TimeZone>(
+		&self,
+		timezone: &Tz,
+	) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+		let start = self.time_block?.start_date_time(timezone)?;
+		let end =
+			start.checked_add_signed(TimeDelta::minutes(self.time_block_minutes()? as i64))?;
+		Some((start, end))
+	}
+
+	/// time tracked on this task between 'start' and 'end'
+	pub fn tracked_time_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> TimeDelta {
+		self.tracked_sessions
+			.iter()
+			.map(|session| session.overlap(start, end))
+			.sum()
+	}
+
+	pub(crate) fn record_tracked_session(&mut self, session: TrackedSession) {
+		// stopping the timer right after starting it isnt worth remembering
+		if session.end - session.start >= TimeDelta::minutes(1) {
+			self.tracked_sessions.push(session);
+		}
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use crate::{
+		database::tests::create_test_project_database, DatabaseMessage, SerializableDate,
+		SerializableTime, Task, TaskId, TimeBlock, TrackedSession,
+	};
+	use chrono::{TimeDelta, TimeZone, Utc};
+	use std::collections::BTreeSet;
+
+	fn task_with_time_block(duration_minutes: Option<usize>) -> Task {
+		let mut task = Task::new(
+			"task".to_string(),
+			String::new(),
+			Some(90),
+			None,
+			None,
+			BTreeSet::new(),
+		);
+		task.time_block = Some(TimeBlock {
+			date: SerializableDate {
+				year: 2025,
+				month: 3,
+				day: 14,
+			},
+			start: SerializableTime {
+				hour: 9,
+				minute: 30,
+			},
+			duration_minutes,
+		});
+		task
+	}
+
+	#[test]
+	fn test_time_block_duration() {
+		assert_eq!(
+			task_with_time_block(Some(45)).time_block_minutes(),
+			Some(45)
+		);
+		// falls back to the needed time
+		assert_eq!(task_with_time_block(None).time_block_minutes(), Some(90));
+
+		let (start, end) = task_with_time_block(None).time_block_range(&Utc).unwrap();
+		assert_eq!(start, Utc.with_ymd_and_hms(2025, 3, 14, 9, 30, 0).unwrap());
+		assert_eq!(end, Utc.with_ymd_and_hms(2025, 3, 14, 11, 0, 0).unwrap());
+	}
+
+	#[test]
+	fn test_tracked_time_inside_time_block() {
+		let mut task = task_with_time_block(Some(60));
+		task.tracked_sessions = vec![
+			// starts before the block
+			TrackedSession {
+				start: Utc.with_ymd_and_hms(2025, 3, 14, 9, 0, 0).unwrap(),
+				end: Utc.with_ymd_and_hms(2025, 3, 14, 9, 45, 0).unwrap(),
+			},
+			// completely after the block
+			TrackedSession {
+				start: Utc.with_ymd_and_hms(2025, 3, 14, 12, 0, 0).unwrap(),
+				end: Utc.with_ymd_and_hms(2025, 3, 14, 13, 0, 0).unwrap(),
+			},
+		];
+		let (start, end) = task.time_block_range(&Utc).unwrap();
+		assert_eq!(
+			task.tracked_time_between(start, end),
+			TimeDelta::minutes(15)
+		);
+	}
+
+	#[test]
+	fn test_replicas_record_the_same_tracked_session() {
+		let task_id = TaskId::generate();
+		let (mut client, project_id) =
+			create_test_project_database([(task_id, task_with_time_block(None))]);
+		let mut server = client.clone();
+
+		let session = TrackedSession {
+			start: Utc.with_ymd_and_hms(2025, 3, 14, 9, 0, 0).unwrap(),
+			end: Utc.with_ymd_and_hms(2025, 3, 14, 9, 25, 0).unwrap(),
+		};
+		let too_short_session = TrackedSession {
+			start: session.end,
+			end: session.end + TimeDelta::seconds(30),
+		};
+		let messages = [session, too_short_session].map(|session| {
+			[
+				DatabaseMessage::StartTaskTimeSpend {
+					project_id,
+					task_id,
+				},
+				DatabaseMessage::StopTaskTimeSpend {
+					project_id,
+					task_id,
+					tracked_session: Some(session),
+				},
+			]
+		});
+
+		for message in messages.iter().flatten() {
+			client.update(message.clone());
+		}
+		// the server applies the messages later
+		for message in messages.iter().flatten() {
+			server.update(message.clone());
+		}
+
+		assert_eq!(client.checksum(), server.checksum());
+		assert_eq!(
+			server
+				.get_task(&project_id, &task_id)
+				.unwrap()
+				.tracked_sessions,
+			vec![session]
+		);
+	}
+}
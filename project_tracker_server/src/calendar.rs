@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use project_tracker_core::{Database, ICalendarTaskComponent};
+use serde::Deserialize;
+use tokio::sync::RwLock;
+use tracing::info;
+use warp::{
+	filters::{method::get, query::query},
+	http::StatusCode,
+	path,
+	reply::{html, with_header, with_status, Response},
+	Filter, Rejection, Reply,
+};
+
+/// calendar apps can only subscribe to plain urls, so the password is part of the query:
+/// '/api/calendar.ics?password=1234' or '/api/calendar.ics?password=1234&tasks_as=todo'
+#[derive(Deserialize)]
+struct CalendarQuery {
+	password: Option<String>,
+	#[serde(default)]
+	tasks_as: ICalendarTaskComponent,
+}
+
+pub fn calendar_route(
+	password: String,
+	shared_database: Arc<RwLock<Database>>,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+	path!("api" / "calendar.ics")
+		.and(get())
+		.and(query::<CalendarQuery>())
+		.and(warp::any().map(move || password.clone()))
+		.and(warp::any().map(move || shared_database.clone()))
+		.then(calendar)
+}
+
+async fn calendar(
+	query: CalendarQuery,
+	password: String,
+	shared_database: Arc<RwLock<Database>>,
+) -> Response {
+	if query.password == Some(password) {
+		info!("sending database as icalendar");
+		let icalendar = shared_database.read().await.to_icalendar(query.tasks_as);
+		with_header(icalendar, "Content-Type", "text/calendar; charset=utf-8").into_response()
+	} else {
+		info!("invalid password providied, refusing access!");
+		with_status(html("Unauthorized".to_string()), StatusCode::UNAUTHORIZED).into_response()
+	}
+}
@@ -11,6 +11,12 @@ pub enum ServerError {
 	RequestParseError,
 	#[error("failed to parse response")]
 	ResponseParseError,
+	#[error("invalid attachment: its name doesnt match the hash of its content")]
+	InvalidAttachment,
+	#[error("failed to store attachment")]
+	FailedToStoreAttachment,
+	#[error("failed to load attachment")]
+	FailedToLoadAttachment,
 }
 
 pub type ServerResult<T> = Result<T, ServerError>;
@@ -40,6 +40,16 @@ pub enum Request {
 	ImportDatabase {
 		database: SerializedDatabase,
 	},
+	/// stores the content of a task attachment in the servers attachments directory,
+	/// see 'Attachment::blob_file_name'
+	UploadAttachment {
+		blob_file_name: String,
+		content: Vec<u8>,
+	},
+	/// the content of an attachment that got uploaded by another client
+	DownloadAttachment {
+		blob_file_name: String,
+	},
 	AdminInfos,
 }
 
@@ -61,6 +71,11 @@ pub enum Response {
 		database_messages: Vec<DatabaseMessage>,
 	},
 	DatabaseUpdated,
+	AttachmentUploaded,
+	AttachmentDownloaded {
+		blob_file_name: String,
+		content: Vec<u8>,
+	},
 	AdminInfos(AdminInfos),
 }
 
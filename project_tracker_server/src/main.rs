@@ -27,6 +27,9 @@ use get_admin_infos::get_admin_infos_route;
 mod load_database;
 use load_database::load_database_route;
 
+mod calendar;
+use calendar::calendar_route;
+
 mod ws;
 use ws::ws_route;
 
@@ -145,6 +148,7 @@ async fn main() {
 			password.clone(),
 			shared_database.clone(),
 		))
+		.or(calendar_route(password.clone(), shared_database.clone()))
 		.or(get_admin_infos_route(
 			password.clone(),
 			connected_clients.clone(),
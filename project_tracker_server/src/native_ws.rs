@@ -1,12 +1,19 @@
 use chrono::Utc;
 use futures_util::{stream::SplitSink, SinkExt, StreamExt};
-use project_tracker_core::{Database, ProjectId, TaskId};
+use project_tracker_core::{
+	attachment_hash, attachments_directory, is_valid_blob_file_name, load_attachment_blob,
+	remove_attachment_blobs, store_attachment_blob, Database,
+};
 use project_tracker_server::{
 	save_database_to_file, AdminInfos, ConnectedClient, CpuUsageAverage, DatabaseUpdateEvent,
 	ModifiedEvent, Request, Response, SerializedRequest, SerializedResponse, ServerError,
 };
-use serde::{Deserialize, Serialize};
-use std::{collections::HashSet, net::SocketAddr, path::PathBuf, sync::Arc};
+use std::{
+	collections::HashSet,
+	net::SocketAddr,
+	path::{Path, PathBuf},
+	sync::Arc,
+};
 use tokio::sync::{
 	broadcast::{Receiver, Sender},
 	RwLock,
@@ -118,19 +125,6 @@ async fn on_upgrade_ws(
 	connected_clients.write().await.remove(&connected_client);
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
-enum WsModifyAction {
-	ToggleTask {
-		project_id: ProjectId,
-		task_id: TaskId,
-		checked: bool,
-	},
-	CreateTask {
-		project_id: ProjectId,
-		task_name: String,
-	},
-}
-
 #[allow(clippy::too_many_arguments)]
 async fn handle_ws(
 	ws: WebSocket,
@@ -252,14 +246,19 @@ async fn respond_to_client_request(
 			if database_synced {
 				info!("updating database");
 
-				let database = {
+				let (database, blob_file_names_before_update) = {
 					let mut shared_database = shared_database.write().await;
+					let blob_file_names_before_update =
+						shared_database.attachment_blob_file_names();
 					for database_message in database_messages.clone() {
 						shared_database.update(database_message);
 					}
-					shared_database.clone()
+					(shared_database.clone(), blob_file_names_before_update)
 				};
 
+				let orphaned_blob_file_names =
+					database.orphaned_attachment_blob_file_names(blob_file_names_before_update);
+
 				let database_binary = database.to_binary();
 
 				broadcast_modified_event(
@@ -282,6 +281,8 @@ async fn respond_to_client_request(
 						"failed to serialize database to binary -> cant save database to file"
 					),
 				}
+
+				remove_orphaned_attachments(database_filepath, orphaned_blob_file_names).await;
 			} else {
 				warn!("clients wanted to update db but checksum doesnt match ours -> sending full db instead");
 				send_more_up_to_date_database(shared_database, ws_write).await;
@@ -290,12 +291,16 @@ async fn respond_to_client_request(
 		Request::ImportDatabase { database } => {
 			info!("importing database");
 
-			let database = {
+			let (database, blob_file_names_before_import) = {
 				let mut shared_database = shared_database.write().await;
+				let blob_file_names_before_import = shared_database.attachment_blob_file_names();
 				*shared_database = Database::from_serialized(database, Utc::now());
-				shared_database.clone()
+				(shared_database.clone(), blob_file_names_before_import)
 			};
 
+			let orphaned_blob_file_names =
+				database.orphaned_attachment_blob_file_names(blob_file_names_before_import);
+
 			let database_binary = database.to_binary();
 
 			broadcast_modified_event(
@@ -315,6 +320,59 @@ async fn respond_to_client_request(
 					error!("failed to serialize database to binary -> cant save database to file");
 				}
 			}
+
+			remove_orphaned_attachments(database_filepath, orphaned_blob_file_names).await;
+		}
+		Request::UploadAttachment {
+			blob_file_name,
+			content,
+		} => {
+			let content_matches_name = is_valid_blob_file_name(&blob_file_name)
+				&& blob_file_name.starts_with(&attachment_hash(&content));
+
+			if !content_matches_name {
+				warn!("rejecting attachment '{blob_file_name}' since its content doesnt match its name");
+				send_error_response(ServerError::InvalidAttachment, ws_write).await;
+				return;
+			}
+
+			info!("storing attachment '{blob_file_name}'");
+			match store_attachment_blob(
+				&attachments_directory(database_filepath),
+				&blob_file_name,
+				&content,
+			)
+			.await
+			{
+				Ok(()) => {
+					send_response(Response::AttachmentUploaded, ws_write).await;
+				}
+				Err(e) => {
+					error!("failed to store attachment '{blob_file_name}': {e}");
+					send_error_response(ServerError::FailedToStoreAttachment, ws_write).await;
+				}
+			}
+		}
+		Request::DownloadAttachment { blob_file_name } => {
+			info!("sending attachment '{blob_file_name}'");
+			match load_attachment_blob(&attachments_directory(database_filepath), &blob_file_name)
+				.await
+			{
+				Ok(content) => {
+					send_response(
+						Response::AttachmentDownloaded {
+							blob_file_name,
+							content,
+						},
+						ws_write,
+					)
+					.await;
+				}
+				Err(e) => {
+					error!("failed to load attachment '{blob_file_name}': {e}");
+					send_error_response(ServerError::FailedToLoadAttachment, ws_write).await;
+				}
+			}
 		}
 		Request::GetFullDatabase => {
 			send_more_up_to_date_database(shared_database, ws_write).await;
@@ -396,6 +454,24 @@ async fn send_error_response(
 	}
 }
 
+async fn remove_orphaned_attachments(
+	database_filepath: &Path,
+	orphaned_blob_file_names: Vec<String>,
+) {
+	if orphaned_blob_file_names.is_empty() {
+		return;
+	}
+	match remove_attachment_blobs(
+		&attachments_directory(database_filepath),
+		orphaned_blob_file_names,
+	)
+	.await
+	{
+		Ok(removed_blobs) => info!("removed {removed_blobs} orphaned attachments"),
+		Err(e) => error!("failed to remove orphaned attachments: {e}"),
+	}
+}
+
 fn broadcast_modified_event(
 	update_event: DatabaseUpdateEvent,
 	modified_sender: &Sender<ModifiedEvent>,
@@ -91,11 +91,11 @@ document.addEventListener("DOMContentLoaded", () => {
 		on_task_description_html_callback_map[`${project_id},${task_id}`] =
 			callback;
 	}
-	function on_task_description_html(project_id, task_id, html) {
+	function on_task_description_html(project_id, task_id, html, comments) {
 		const callback =
 			on_task_description_html_callback_map[`${project_id},${task_id}`];
 		if (callback) {
-			callback(html);
+			callback(html, comments);
 		}
 	}
 
@@ -338,6 +338,25 @@ document.addEventListener("DOMContentLoaded", () => {
 		return array;
 	}
 
+	const TASK_PRIORITY_RANK = {
+		None: 0,
+		Low: 1,
+		Medium: 2,
+		High: 3,
+		Urgent: 4,
+	};
+
+	const TASK_PRIORITY_COLOR = {
+		Low: "rgb(80, 150, 255)",
+		Medium: "rgb(240, 200, 0)",
+		High: "rgb(255, 130, 0)",
+		Urgent: "rgb(230, 30, 30)",
+	};
+
+	function task_priority_rank(task) {
+		return TASK_PRIORITY_RANK[task.priority] || 0;
+	}
+
 	function sort_project_tasks(sort_mode, tasks) {
 		if (sort_mode === "DueDate") {
 			tasks.sort((task_a_with_id, task_b_with_id) => {
@@ -389,6 +408,24 @@ document.addEventListener("DOMContentLoaded", () => {
 					}
 				}
 			});
+		} else if (sort_mode === "Priority") {
+			tasks.sort((task_a_with_id, task_b_with_id) => {
+				const priority_a = task_priority_rank(task_a_with_id[1]);
+				const priority_b = task_priority_rank(task_b_with_id[1]);
+				if (priority_a) {
+					if (priority_b) {
+						return priority_b - priority_a;
+					} else {
+						return -1;
+					}
+				} else {
+					if (priority_b) {
+						return 1;
+					} else {
+						return 0;
+					}
+				}
+			});
 		}
 	}
 
@@ -452,13 +489,27 @@ document.addEventListener("DOMContentLoaded", () => {
 				"." +
 				task.due_date.month +
 				"." +
-				task.due_date.year +
-				" - ";
+				task.due_date.year;
+			if (task.due_time) {
+				task_info +=
+					" " +
+					String(task.due_time.hour).padStart(2, "0") +
+					":" +
+					String(task.due_time.minute).padStart(2, "0");
+			}
+			task_info += " - ";
 		}
 		task_info += task.name;
 
 		const task_info_div = document.createElement("div");
-		task_info_div.textContent = task_info;
+		if (task_priority_rank(task)) {
+			const task_priority_span = document.createElement("span");
+			task_priority_span.textContent = "\u2691 ";
+			task_priority_span.title = task.priority + " priority";
+			task_priority_span.style.color = TASK_PRIORITY_COLOR[task.priority];
+			task_info_div.appendChild(task_priority_span);
+		}
+		task_info_div.appendChild(document.createTextNode(task_info));
 
 		task_tags_and_name_div.appendChild(task_info_div);
 
@@ -466,7 +517,10 @@ document.addEventListener("DOMContentLoaded", () => {
 		task_content_div.className = "task_content";
 		task_content_div.appendChild(task_tags_and_name_div);
 
-		if (task.description && task.description.trim().length > 0) {
+		const has_description =
+			task.description && task.description.trim().length > 0;
+		const has_comments = task.comments && task.comments.length > 0;
+		if (has_description || has_comments) {
 			const task_description_section = document.createElement("details");
 			task_description_section.style.width = "fit-content";
 
@@ -476,6 +530,11 @@ document.addEventListener("DOMContentLoaded", () => {
 			task_description_summary.append(task_description_icon);
 			const task_description_div = document.createElement("div");
 			task_description_div.style.display = "none";
+			const task_description_html_div = document.createElement("div");
+			task_description_div.appendChild(task_description_html_div);
+			const task_comments_div = document.createElement("div");
+			task_comments_div.className = "comment_list";
+			task_description_div.appendChild(task_comments_div);
 			task_description_summary.appendChild(task_description_div);
 			task_description_section.append(task_description_summary);
 
@@ -490,9 +549,14 @@ document.addEventListener("DOMContentLoaded", () => {
 				}
 			});
 
-			add_on_task_description_html_callback(project_id, task_id, (html) => {
-				task_description_div.innerHTML = html;
-			});
+			add_on_task_description_html_callback(
+				project_id,
+				task_id,
+				(html, comments) => {
+					task_description_html_div.innerHTML = has_description ? html : "";
+					task_comments_div.replaceChildren(...comments.map(comment_dom));
+				},
+			);
 
 			task_content_div.appendChild(task_description_section);
 		}
@@ -502,6 +566,27 @@ document.addEventListener("DOMContentLoaded", () => {
 		task_list.appendChild(task_div);
 	}
 
+	function comment_dom(comment) {
+		const comment_div = document.createElement("div");
+		comment_div.className = "comment";
+
+		const comment_info_div = document.createElement("div");
+		comment_info_div.className = "comment_info";
+		let comment_info =
+			comment.author + " - " + new Date(comment.created).toLocaleString();
+		if (comment.edited) {
+			comment_info += " (edited)";
+		}
+		comment_info_div.textContent = comment_info;
+		comment_div.appendChild(comment_info_div);
+
+		const comment_body_div = document.createElement("div");
+		comment_body_div.innerHTML = comment.html;
+		comment_div.appendChild(comment_body_div);
+
+		return comment_div;
+	}
+
 	function connect_ws() {
 		console.log("connecting to ws...");
 		ws = new WebSocket("wss://" + location.host + "/api/ws/");
@@ -550,7 +635,8 @@ document.addEventListener("DOMContentLoaded", () => {
 				const project_id = response.HtmlMarkdown.project_id;
 				const task_id = response.HtmlMarkdown.task_id;
 				const html = response.HtmlMarkdown.html;
-				on_task_description_html(project_id, task_id, html);
+				const comments = response.HtmlMarkdown.comments;
+				on_task_description_html(project_id, task_id, html, comments);
 			} else {
 				populate_dom_from_database(response);
 				localStorage.setItem("last_loaded_database", JSON.stringify(response));
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use futures_util::{SinkExt, StreamExt};
 use project_tracker_core::{Database, DatabaseMessage, ProjectId, SerializedDatabase, TaskId};
 use project_tracker_server::{
@@ -161,9 +162,18 @@ enum WsResponse {
 		project_id: ProjectId,
 		task_id: TaskId,
 		html: String,
+		comments: Vec<HtmlComment>,
 	},
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+struct HtmlComment {
+	author: String,
+	created: DateTime<Utc>,
+	edited: Option<DateTime<Utc>>,
+	html: String,
+}
+
 async fn handle_ws(
 	ws: WebSocket,
 	client_addr: SocketAddr,
@@ -253,16 +263,26 @@ async fn handle_ws(
 											}
 										}
 										WsRequest::ProduceHtmlFromMarkdown { project_id, task_id } => {
-											let html = match shared_database.read().await.get_task(&project_id, &task_id) {
-												Some(task) => {
-													produce_html_from_markdown(&task.description)
-												}
-												None => "Task not found".to_string(),
+											let (html, comments) = match shared_database.read().await.get_task(&project_id, &task_id) {
+												Some(task) => (
+													produce_html_from_markdown(&task.description),
+													task.comments
+														.iter()
+														.map(|comment| HtmlComment {
+															author: comment.author.clone(),
+															created: comment.created,
+															edited: comment.edited,
+															html: produce_html_from_markdown(&comment.body),
+														})
+														.collect(),
+												),
+												None => ("Task not found".to_string(), Vec::new()),
 											};
 											if let Ok(serialized_response) = serde_json::to_string(&WsResponse::HtmlMarkdown {
 												project_id,
 												task_id,
-												html
+												html,
+												comments,
 											}) {
 												if let Err(e) = write_ws.send(Message::text(serialized_response)).await {
 													error!("failed to send html markdown response: {e}");
@@ -300,7 +320,8 @@ fn produce_html_from_markdown(description: &str) -> String {
 			| pulldown_cmark::Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS
 			| pulldown_cmark::Options::ENABLE_TABLES
 			| pulldown_cmark::Options::ENABLE_STRIKETHROUGH
-			| pulldown_cmark::Options::ENABLE_TASKLISTS,
+			| pulldown_cmark::Options::ENABLE_TASKLISTS
+			| pulldown_cmark::Options::ENABLE_FOOTNOTES,
 	);
 	pulldown_cmark::html::push_html(&mut html, parser);
 	html
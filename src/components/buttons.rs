@@ -1,16 +1,19 @@
 use crate::{
 	components::{self, date_text, duration_text},
-	core::{IcedColorConversion, SerializableDateConversion, SortModeUI},
+	core::{IcedColorConversion, SerializableDateConversion, SortModeUI, TaskPriorityUI},
 	icons::{icon_to_text, Bootstrap},
 	integrations::CodeEditor,
 	modals::{
-		confirm_modal, create_task_modal, error_msg_modal, manage_task_tags_modal, settings_modal,
-		task_modal, wait_closing_modal,
+		confirm_modal, create_task_modal, error_msg_modal, import_summary_modal,
+		manage_task_tags_modal, settings_modal, source_code_todo_settings_modal, task_modal,
+		wait_closing_modal,
 	},
 	pages::{
 		self, format_stopwatch_duration,
 		overview_page::{self, CalendarView},
-		project_page, sidebar_page, stopwatch_page, STOPWATCH_TASK_DROPZONE_ID,
+		project_page, sidebar_page,
+		stopwatch_page::{self, PomodoroPhase},
+		today_page, STOPWATCH_TASK_DROPZONE_ID,
 	},
 	preferences::{FirstWeekday, SerializedOverviewPage},
 	project_tracker::Message,
@@ -18,23 +21,25 @@ use crate::{
 		circle_button_style, danger_text_style, dangerous_button_style, delete_button_style,
 		delete_done_tasks_button_style, dropdown_container_style, enum_dropdown_button_style,
 		hidden_secondary_button_style, overview_button_style, primary_button_style,
-		secondary_button_style, secondary_button_style_default, secondary_button_style_no_rounding,
-		secondary_button_style_only_round_left, secondary_button_style_only_round_right,
-		secondary_button_style_only_round_top, selection_list_button_style,
-		settings_tab_button_style, stopwatch_page_button_style, task_tag_button_style,
-		text_input_style, timer_button_style, tooltip_container_style, BOLD_FONT, GAP,
-		HEADING_TEXT_SIZE, JET_BRAINS_MONO_FONT, LARGE_TEXT_SIZE, SMALL_HORIZONTAL_PADDING,
-		SMALL_PADDING_AMOUNT, SMALL_SPACING_AMOUNT, SMALL_TEXT_SIZE, SPACING_AMOUNT,
+		rounded_container_style, secondary_button_style, secondary_button_style_default,
+		secondary_button_style_no_rounding, secondary_button_style_only_round_left,
+		secondary_button_style_only_round_right, secondary_button_style_only_round_top,
+		selection_list_button_style, settings_tab_button_style, stopwatch_page_button_style,
+		task_tag_button_style, text_input_style, timer_button_style, tooltip_container_style,
+		BOLD_FONT, GAP, HEADING_TEXT_SIZE, JET_BRAINS_MONO_FONT, LARGE_TEXT_SIZE,
+		SMALL_HORIZONTAL_PADDING, SMALL_PADDING_AMOUNT, SMALL_SPACING_AMOUNT, SMALL_TEXT_SIZE,
+		SPACING_AMOUNT,
 	},
 	theme_mode::ThemeMode,
 	DateFormatting, PreferenceMessage,
 };
+use chrono::{Local, NaiveDate};
 use iced::{
 	alignment::{Horizontal, Vertical},
 	border::rounded,
 	widget::{
 		button, column, container, rich_text, row, text, text::Span, text_input, tooltip, Button,
-		Column, Space,
+		Column, Row, Space,
 	},
 	Alignment, Color, Element,
 	Length::{self, Fill, Fixed},
@@ -42,9 +47,10 @@ use iced::{
 use iced_aw::{drop_down, drop_down::Offset, quad::Quad, widgets::InnerBounds, DropDown, Spinner};
 use iced_date_picker::{date_picker, Date};
 use project_tracker_core::{
-	Database, DatabaseMessage, ProjectId, SerializableDate, SortMode, TaskId, TaskTag, TaskTagId,
+	Attachment, Database, DatabaseMessage, ProjectId, SerializableDate, SerializableTime, SortMode,
+	TaskCommentId, TaskId, TaskPriority, TaskTag, TaskTagId, TimeBlock,
 };
-use std::{path::PathBuf, time::Duration};
+use std::{collections::BTreeSet, path::PathBuf, time::Duration};
 
 pub const ICON_FONT_SIZE: f32 = 16.0;
 pub const ICON_BUTTON_WIDTH: f32 = ICON_FONT_SIZE * 1.8;
@@ -71,10 +77,10 @@ fn large_icon_button<Message: 'static>(icon: Bootstrap) -> Button<'static, Messa
 	.width(LARGE_ICON_BUTTON_WIDTH)
 }
 
-fn icon_label_button<Message: 'static>(
-	label: impl text::IntoFragment<'static>,
+fn icon_label_button<'a, Message: 'a>(
+	label: impl text::IntoFragment<'a>,
 	icon: Bootstrap,
-) -> Button<'static, Message> {
+) -> Button<'a, Message> {
 	button(
 		row![icon_to_text(icon).size(ICON_FONT_SIZE), text(label)]
 			.align_y(Alignment::Center)
@@ -136,6 +142,7 @@ pub fn project_context_menu_button(opened: bool) -> Element<'static, Message> {
 			column![
 				manage_task_tags_button(),
 				import_source_code_todos_button(),
+				source_code_todo_settings_button(),
 				delete_project_button(),
 			]
 			.width(Length::Fixed(150.0)),
@@ -174,7 +181,7 @@ pub fn toggle_view_edit_task_description_button(viewing: bool) -> Element<'stati
 	.into()
 }
 
-pub fn delete_task_button() -> Button<'static, Message> {
+pub fn delete_task_button<'a>() -> Button<'a, Message> {
 	icon_label_button("Delete", Bootstrap::Trash)
 		.on_press(task_modal::Message::DeleteTask.into())
 		.style(move |t, s| delete_button_style(t, s, true, true, true, true))
@@ -262,6 +269,21 @@ pub fn overview_button(selected: bool) -> Button<'static, Message> {
 	.style(move |t, s| overview_button_style(t, s, selected))
 }
 
+pub fn today_button(selected: bool) -> Button<'static, Message> {
+	button(
+		row![
+			icon_to_text(Bootstrap::CalendarDay).size(LARGE_TEXT_SIZE),
+			text("Today").size(LARGE_TEXT_SIZE),
+		]
+		.width(Fill)
+		.spacing(SPACING_AMOUNT)
+		.align_y(Alignment::Center),
+	)
+	.width(Fill)
+	.on_press(pages::Message::OpenToday.into())
+	.style(move |t, s| overview_button_style(t, s, selected))
+}
+
 pub fn stopwatch_button(
 	stopwatch_page: &stopwatch_page::Page,
 	selected: bool,
@@ -269,7 +291,8 @@ pub fn stopwatch_button(
 	database: Option<&Database>,
 ) -> Element<'static, Message> {
 	let stopwatch_label = match stopwatch_page {
-		stopwatch_page::Page::TakingBreak { clock, .. } => Some(clock.label().to_string()),
+		stopwatch_page::Page::TakingBreak { clock, .. }
+		| stopwatch_page::Page::Pomodoro { clock, .. } => Some(clock.label().to_string()),
 		stopwatch_page::Page::StopTaskTime {
 			clock,
 			project_id,
@@ -294,6 +317,10 @@ pub fn stopwatch_button(
 
 	let stopwatch_icon = match stopwatch_page {
 		stopwatch_page::Page::TakingBreak { .. } => Bootstrap::CupHot,
+		stopwatch_page::Page::Pomodoro { phase, .. } => match phase {
+			PomodoroPhase::Work => Bootstrap::Bullseye,
+			PomodoroPhase::ShortBreak | PomodoroPhase::LongBreak => Bootstrap::CupHot,
+		},
 		stopwatch_page::Page::StopTaskTime { .. } => Bootstrap::HourglassSplit,
 		_ => Bootstrap::Stopwatch,
 	};
@@ -562,6 +589,92 @@ pub fn export_database_as_markdown_button(exporting_json: bool) -> Element<'stat
 	.into()
 }
 
+pub fn export_database_as_icalendar_button(exporting: bool) -> Element<'static, Message> {
+	button(
+		row![
+			if exporting {
+				Element::new(
+					Spinner::new()
+						.width(Length::Fixed(ICON_FONT_SIZE))
+						.height(Length::Fixed(ICON_FONT_SIZE))
+						.circle_radius(2.0),
+				)
+			} else {
+				icon_to_text(Bootstrap::CalendarEvent)
+					.align_y(Vertical::Center)
+					.into()
+			},
+			text("Export as Calendar")
+		]
+		.spacing(SMALL_SPACING_AMOUNT)
+		.align_y(Alignment::Center),
+	)
+	.on_press_maybe(if exporting {
+		None
+	} else {
+		Some(Message::ExportDatabaseAsICalendarDialog)
+	})
+	.style(dangerous_button_style)
+	.into()
+}
+
+pub fn export_database_as_todo_txt_button(exporting: bool) -> Element<'static, Message> {
+	button(
+		row![
+			if exporting {
+				Element::new(
+					Spinner::new()
+						.width(Length::Fixed(ICON_FONT_SIZE))
+						.height(Length::Fixed(ICON_FONT_SIZE))
+						.circle_radius(2.0),
+				)
+			} else {
+				icon_to_text(Bootstrap::FiletypeTxt)
+					.align_y(Vertical::Center)
+					.into()
+			},
+			text("Export as todo.txt")
+		]
+		.spacing(SMALL_SPACING_AMOUNT)
+		.align_y(Alignment::Center),
+	)
+	.on_press_maybe(if exporting {
+		None
+	} else {
+		Some(Message::ExportDatabaseAsTodoTxtDialog)
+	})
+	.style(dangerous_button_style)
+	.into()
+}
+
+pub fn import_markdown_button() -> Element<'static, Message> {
+	button(
+		row![
+			icon_to_text(Bootstrap::FiletypeMd).align_y(Vertical::Center),
+			text("Import Markdown")
+		]
+		.spacing(SMALL_SPACING_AMOUNT)
+		.align_y(Alignment::Center),
+	)
+	.on_press(settings_modal::Message::ImportMarkdownFolderDialog.into())
+	.style(dangerous_button_style)
+	.into()
+}
+
+pub fn import_todo_txt_button() -> Element<'static, Message> {
+	button(
+		row![
+			icon_to_text(Bootstrap::FiletypeTxt).align_y(Vertical::Center),
+			text("Import todo.txt")
+		]
+		.spacing(SMALL_SPACING_AMOUNT)
+		.align_y(Alignment::Center),
+	)
+	.on_press(settings_modal::Message::ImportTodoTxtFileDialog.into())
+	.style(dangerous_button_style)
+	.into()
+}
+
 pub fn task_tag_button<Message>(task_tag: &TaskTag, toggled: bool) -> Button<Message> {
 	let button = button(text(&task_tag.name))
 		.style(move |t, s| task_tag_button_style(t, s, task_tag.color.to_iced_color(), toggled));
@@ -615,6 +728,80 @@ pub fn clear_task_due_date_button<Message>(on_press: Message) -> Button<'static,
 		.style(secondary_button_style_only_round_right)
 }
 
+pub fn clear_task_due_time_button<Message>(on_press: Message) -> Button<'static, Message> {
+	icon_button(Bootstrap::XLg)
+		.on_press(on_press)
+		.style(secondary_button_style_only_round_right)
+}
+
+pub fn remove_task_reminder_button<Message>(on_press: Message) -> Button<'static, Message> {
+	icon_button(Bootstrap::XLg)
+		.on_press(on_press)
+		.style(secondary_button_style_only_round_right)
+}
+
+pub fn open_attachment_button<'a>(
+	preview: Element<'a, Message>,
+	name: String,
+	filepath: Option<PathBuf>,
+) -> Button<'a, Message> {
+	button(
+		row![preview, text(name)]
+			.align_y(Alignment::Center)
+			.spacing(SPACING_AMOUNT),
+	)
+	.on_press_maybe(filepath.map(Message::OpenAttachment))
+	.width(Fill)
+	.style(hidden_secondary_button_style)
+}
+
+pub fn remove_task_attachment_button(
+	project_id: ProjectId,
+	task_id: TaskId,
+	attachment: Attachment,
+) -> Button<'static, Message> {
+	icon_button(Bootstrap::Trash)
+		.on_press(
+			DatabaseMessage::RemoveTaskAttachment {
+				project_id,
+				task_id,
+				attachment,
+			}
+			.into(),
+		)
+		.style(move |t, s| delete_button_style(t, s, true, true, true, true))
+}
+
+pub fn add_task_comment_button(enabled: bool) -> Button<'static, Message> {
+	icon_label_button("Comment", Bootstrap::Send)
+		.on_press_maybe(enabled.then_some(task_modal::Message::AddComment.into()))
+		.style(primary_button_style)
+}
+
+pub fn edit_task_comment_button(comment_id: TaskCommentId) -> Button<'static, Message> {
+	icon_button(Bootstrap::Pencil)
+		.on_press(task_modal::Message::EditComment(comment_id).into())
+		.style(secondary_button_style_default)
+}
+
+pub fn save_task_comment_button() -> Button<'static, Message> {
+	button(text("Save").align_x(Horizontal::Center))
+		.on_press(task_modal::Message::SaveEditedComment.into())
+		.style(primary_button_style)
+}
+
+pub fn cancel_editing_task_comment_button() -> Button<'static, Message> {
+	button(text("Cancel").align_x(Horizontal::Center))
+		.on_press(task_modal::Message::StopEditingComment.into())
+		.style(secondary_button_style_default)
+}
+
+pub fn delete_task_comment_button(comment_id: TaskCommentId) -> Button<'static, Message> {
+	icon_button(Bootstrap::Trash)
+		.on_press(task_modal::Message::DeleteComment(comment_id).into())
+		.style(move |t, s| delete_button_style(t, s, true, true, true, true))
+}
+
 pub fn add_due_date_button<Message: 'static>(on_press: Message) -> Button<'static, Message> {
 	button(
 		row![icon_to_text(Bootstrap::CalendarCheck), text("Add due date")]
@@ -736,6 +923,23 @@ fn import_source_code_todos_button() -> Button<'static, Message> {
 		.style(secondary_button_style_no_rounding)
 }
 
+fn source_code_todo_settings_button() -> Button<'static, Message> {
+	icon_label_button("Todo Settings", Bootstrap::Gear)
+		.width(Fill)
+		.on_press(project_page::Message::OpenSourceCodeTodoSettingsModal.into())
+		.style(secondary_button_style_no_rounding)
+}
+
+pub fn remove_source_code_todo_keyword_button(
+	keyword_index: usize,
+) -> Button<'static, source_code_todo_settings_modal::Message> {
+	icon_button(Bootstrap::Trash)
+		.on_press(source_code_todo_settings_modal::Message::RemoveKeyword(
+			keyword_index,
+		))
+		.style(move |t, s| delete_button_style(t, s, true, true, true, true))
+}
+
 pub fn reimport_source_code_todos_button(
 	importing: bool,
 	reimport_possible: bool,
@@ -790,6 +994,59 @@ pub fn show_source_code_todos_button(
 	.style(secondary_button_style_default)
 }
 
+pub fn source_code_todo_author_dropdown_button(
+	authors: BTreeSet<String>,
+	author_filter: Option<&str>,
+	opened: bool,
+) -> Element<'static, Message> {
+	let options_len = authors.len() + 1;
+	let options = std::iter::once(None)
+		.chain(authors.into_iter().map(Some))
+		.enumerate()
+		.map(|(i, author)| {
+			let selected = author_filter == author.as_deref();
+			button(text(
+				author.clone().unwrap_or_else(|| "All authors".to_string()),
+			))
+			.width(Fill)
+			.style(move |t, s| {
+				enum_dropdown_button_style(t, s, selected, i == 0, i == options_len - 1)
+			})
+			.on_press(project_page::Message::SetSourceCodeTodoAuthorFilter(author).into())
+			.into()
+		});
+
+	DropDown::new(
+		button(
+			row![
+				icon_to_text(if opened {
+					Bootstrap::CaretDownFill
+				} else {
+					Bootstrap::CaretRightFill
+				})
+				.size(ICON_FONT_SIZE),
+				text("Author:"),
+				text(author_filter.unwrap_or("All").to_string()),
+			]
+			.spacing(SMALL_SPACING_AMOUNT)
+			.align_y(Vertical::Center),
+		)
+		.on_press(if opened {
+			project_page::Message::CloseSourceCodeTodoAuthorDropdown.into()
+		} else {
+			project_page::Message::OpenSourceCodeTodoAuthorDropdown.into()
+		})
+		.style(secondary_button_style_default),
+		container(Column::with_children(options)).style(dropdown_container_style),
+		opened,
+	)
+	.width(Fixed(200.0))
+	.alignment(drop_down::Alignment::Bottom)
+	.offset(0.0)
+	.on_dismiss(project_page::Message::CloseSourceCodeTodoAuthorDropdown.into())
+	.into()
+}
+
 pub fn track_time_button() -> Button<'static, Message> {
 	button(
 		icon_to_text(Bootstrap::PlayFill)
@@ -842,6 +1099,46 @@ pub fn pause_timer_button() -> Button<'static, Message> {
 	.style(move |t, s| timer_button_style(t, s, true))
 }
 
+pub fn start_pomodoro_button(task: Option<(ProjectId, TaskId)>) -> Element<'static, Message> {
+	tooltip(
+		button(
+			icon_to_text(Bootstrap::Bullseye)
+				.size(45)
+				.align_x(Horizontal::Center)
+				.align_y(Vertical::Center),
+		)
+		.width(Length::Fixed(1.75 * 45.0))
+		.height(Length::Fixed(1.75 * 45.0))
+		.on_press(stopwatch_page::Message::StartPomodoro(task).into())
+		.style(move |t, s| timer_button_style(t, s, false)),
+		text("Start pomodoro").size(SMALL_TEXT_SIZE),
+		tooltip::Position::Bottom,
+	)
+	.gap(GAP)
+	.style(tooltip_container_style)
+	.into()
+}
+
+pub fn skip_pomodoro_phase_button() -> Element<'static, Message> {
+	tooltip(
+		button(
+			icon_to_text(Bootstrap::SkipEndFill)
+				.size(45)
+				.align_x(Horizontal::Center)
+				.align_y(Vertical::Center),
+		)
+		.width(Length::Fixed(1.75 * 45.0))
+		.height(Length::Fixed(1.75 * 45.0))
+		.on_press(stopwatch_page::Message::SkipPomodoroPhase.into())
+		.style(move |t, s| timer_button_style(t, s, true)),
+		text("Skip to the next phase").size(SMALL_TEXT_SIZE),
+		tooltip::Position::Bottom,
+	)
+	.gap(GAP)
+	.style(tooltip_container_style)
+	.into()
+}
+
 pub fn complete_task_timer_button() -> Button<'static, Message> {
 	button(
 		icon_to_text(Bootstrap::CheckLg)
@@ -855,6 +1152,98 @@ pub fn complete_task_timer_button() -> Button<'static, Message> {
 	.style(move |t, s| timer_button_style(t, s, true))
 }
 
+pub fn plan_task_for_today_button(
+	project_id: ProjectId,
+	task_id: TaskId,
+	planned_for_today: bool,
+) -> Element<'static, Message> {
+	tooltip(
+		icon_button(if planned_for_today {
+			Bootstrap::CalendarX
+		} else {
+			Bootstrap::CalendarPlus
+		})
+		.on_press(if planned_for_today {
+			DatabaseMessage::UnplanTask {
+				project_id,
+				task_id,
+			}
+			.into()
+		} else {
+			DatabaseMessage::PlanTask {
+				project_id,
+				task_id,
+				date: Local::now().date_naive().into(),
+			}
+			.into()
+		})
+		.style(secondary_button_style_default),
+		if planned_for_today {
+			text("Remove from today's plan")
+		} else {
+			text("Plan for today")
+		},
+		tooltip::Position::Bottom,
+	)
+	.gap(GAP)
+	.style(tooltip_container_style)
+	.into()
+}
+
+pub fn plan_task_button(project_id: ProjectId, task_id: TaskId) -> Button<'static, Message> {
+	icon_button(Bootstrap::PlusLg)
+		.on_press(
+			today_page::Message::PlanTask {
+				project_id,
+				task_id,
+			}
+			.into(),
+		)
+		.style(secondary_button_style_default)
+}
+
+pub fn unplan_task_button(project_id: ProjectId, task_id: TaskId) -> Button<'static, Message> {
+	icon_button(Bootstrap::XLg)
+		.on_press(
+			today_page::Message::UnplanTask {
+				project_id,
+				task_id,
+			}
+			.into(),
+		)
+		.style(secondary_button_style_only_round_right)
+}
+
+pub fn move_planned_task_up_button(index: usize) -> Button<'static, Message> {
+	icon_button(Bootstrap::ChevronUp)
+		.on_press_maybe(if index == 0 {
+			None
+		} else {
+			Some(today_page::Message::MoveTaskUp(index).into())
+		})
+		.style(secondary_button_style_only_round_left)
+}
+
+pub fn move_planned_task_down_button(index: usize, task_count: usize) -> Button<'static, Message> {
+	icon_button(Bootstrap::ChevronDown)
+		.on_press_maybe(if index + 1 < task_count {
+			Some(today_page::Message::MoveTaskDown(index).into())
+		} else {
+			None
+		})
+		.style(secondary_button_style_no_rounding)
+}
+
+pub fn carry_over_day_plan_button(unfinished_task_count: usize) -> Button<'static, Message> {
+	icon_label_button("Carry over to tomorrow", Bootstrap::ArrowReturnRight)
+		.on_press_maybe(if unfinished_task_count > 0 {
+			Some(today_page::Message::CarryOverToTomorrow.into())
+		} else {
+			None
+		})
+		.style(secondary_button_style_default)
+}
+
 pub fn start_task_timer_button<'a>(
 	project_id: ProjectId,
 	task_id: TaskId,
@@ -896,6 +1285,61 @@ pub fn import_google_tasks_button() -> Button<'static, Message> {
 		.style(dangerous_button_style)
 }
 
+pub fn import_issues_button() -> Button<'static, Message> {
+	button("Import")
+		.on_press(settings_modal::Message::ImportIssuesFileDialog.into())
+		.style(dangerous_button_style)
+}
+
+pub fn open_issue_button<'a>(issue_url: String) -> Button<'a, Message> {
+	button(
+		row![icon_to_text(Bootstrap::BoxArrowUpRight), text("Open issue")]
+			.align_y(Alignment::Center)
+			.spacing(SMALL_SPACING_AMOUNT),
+	)
+	.on_press(Message::OpenUrl(issue_url))
+	.style(secondary_button_style_default)
+}
+
+pub fn promote_source_code_todo_button<'a>(promoted: bool) -> Button<'a, Message> {
+	button(
+		row![
+			icon_to_text(Bootstrap::ArrowUpSquare),
+			text(if promoted {
+				"Promoted"
+			} else {
+				"Promote to task"
+			})
+		]
+		.align_y(Alignment::Center)
+		.spacing(SMALL_SPACING_AMOUNT),
+	)
+	.on_press_maybe((!promoted).then_some(task_modal::Message::PromoteSourceCodeTodo.into()))
+	.style(secondary_button_style_default)
+}
+
+pub fn mark_task_done_button(project_id: ProjectId, task_id: TaskId) -> Button<'static, Message> {
+	button(
+		row![icon_to_text(Bootstrap::CheckSquare), text("Mark as done")]
+			.align_y(Alignment::Center)
+			.spacing(SMALL_SPACING_AMOUNT),
+	)
+	.on_press(
+		DatabaseMessage::SetTaskDone {
+			project_id,
+			task_id,
+		}
+		.into(),
+	)
+	.style(secondary_button_style_default)
+}
+
+pub fn import_ics_button() -> Button<'static, Message> {
+	button("Import")
+		.on_press(settings_modal::Message::ImportIcsFileDialog.into())
+		.style(dangerous_button_style)
+}
+
 pub fn sort_dropdown_button(opened: bool, sort_mode: SortMode) -> Element<'static, Message> {
 	DropDown::new(
 		button(
@@ -946,6 +1390,60 @@ pub fn sort_dropdown_button(opened: bool, sort_mode: SortMode) -> Element<'stati
 	.into()
 }
 
+fn task_priority_label(priority: TaskPriority) -> Row<'static, Message> {
+	let color = priority.color();
+	row![
+		icon_to_text(priority.icon())
+			.size(ICON_FONT_SIZE)
+			.style(move |_theme| text::Style { color }),
+		text(priority.as_str()),
+	]
+	.spacing(SMALL_SPACING_AMOUNT)
+	.align_y(Vertical::Center)
+}
+
+pub fn task_priority_dropdown_button(
+	opened: bool,
+	priority: TaskPriority,
+) -> Element<'static, Message> {
+	DropDown::new(
+		button(task_priority_label(priority))
+			.on_press(if opened {
+				task_modal::Message::ClosePriorityDropdown.into()
+			} else {
+				task_modal::Message::OpenPriorityDropdown.into()
+			})
+			.style(secondary_button_style_default),
+		container(Column::with_children(
+			TaskPriority::ALL
+				.iter()
+				.enumerate()
+				.map(|(i, new_priority)| {
+					button(task_priority_label(*new_priority))
+						.width(Fill)
+						.style(move |t, s| {
+							enum_dropdown_button_style(
+								t,
+								s,
+								priority == *new_priority,
+								i == 0,
+								i == TaskPriority::ALL.len() - 1,
+							)
+						})
+						.on_press(task_modal::Message::ChangePriority(*new_priority).into())
+						.into()
+				}),
+		))
+		.style(dropdown_container_style),
+		opened,
+	)
+	.width(Fixed(130.0))
+	.alignment(drop_down::Alignment::Bottom)
+	.offset(0.0)
+	.on_dismiss(task_modal::Message::ClosePriorityDropdown.into())
+	.into()
+}
+
 pub fn show_password_button() -> Element<'static, Message> {
 	tooltip(
 		icon_button(Bootstrap::EyeFill)
@@ -1002,6 +1500,13 @@ pub fn error_msg_ok_button() -> Button<'static, Message> {
 		.on_press(error_msg_modal::Message::Close.into())
 }
 
+pub fn import_summary_ok_button() -> Button<'static, Message> {
+	button(text("Ok").align_x(Horizontal::Center).width(Fill))
+		.width(Fill)
+		.style(primary_button_style)
+		.on_press(import_summary_modal::Message::Close.into())
+}
+
 pub fn task_tag_name_button(
 	task_tag_id: TaskTagId,
 	task_tag_name: &str,
@@ -1099,6 +1604,113 @@ pub fn edit_needed_time_button<'a, Message: 'static + Clone>(
 	}
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn due_time_button<'a, Message: 'static + Clone>(
+	due_time: Option<SerializableTime>,
+	new_due_time: &'a Option<String>,
+	on_edit: Message,
+	on_input: impl Fn(String) -> Message + 'a,
+	on_submit: Message,
+	stop_editing: Message,
+	clear_due_time: Message,
+	text_input_id: text_input::Id,
+) -> Element<'a, Message> {
+	match new_due_time {
+		Some(new_due_time) => {
+			let edit_due_time_element = components::on_input(
+				text_input("ex: 14:00", new_due_time)
+					.id(text_input_id)
+					.width(Fixed(80.0))
+					.on_input(on_input)
+					.on_submit(on_submit)
+					.style(move |t, s| text_input_style(t, s, true, false, false, true)),
+			)
+			.on_esc(stop_editing);
+
+			row![
+				edit_due_time_element,
+				clear_task_due_time_button(clear_due_time)
+			]
+			.into()
+		}
+		None => match due_time {
+			Some(due_time) => row![
+				button(
+					row![icon_to_text(Bootstrap::Clock), text(due_time.to_string())]
+						.spacing(SMALL_SPACING_AMOUNT)
+				)
+				.on_press(on_edit)
+				.style(secondary_button_style_only_round_left),
+				clear_task_due_time_button(clear_due_time),
+			]
+			.into(),
+			None => button(
+				row![icon_to_text(Bootstrap::Clock), text("Add time")]
+					.spacing(SMALL_SPACING_AMOUNT),
+			)
+			.on_press(on_edit)
+			.style(secondary_button_style_default)
+			.into(),
+		},
+	}
+}
+
+pub fn time_block_button(
+	project_id: ProjectId,
+	task_id: TaskId,
+	time_block: Option<TimeBlock>,
+	time_block_minutes: Option<usize>,
+	date_formatting: DateFormatting,
+	on_add: Message,
+) -> Element<'static, Message> {
+	match time_block.zip(time_block_minutes) {
+		Some((time_block, minutes)) => {
+			let start_minute =
+				time_block.start.hour as usize * 60 + time_block.start.minute as usize;
+			let end_minute = (start_minute + minutes).min(24 * 60);
+			let end = SerializableTime {
+				hour: (end_minute / 60) as u32,
+				minute: (end_minute % 60) as u32,
+			};
+
+			row![
+				container(
+					row![
+						icon_to_text(Bootstrap::CalendarRange),
+						text(format!(
+							"{} {} - {end}",
+							date_formatting.format(&time_block.date),
+							time_block.start
+						))
+					]
+					.spacing(SMALL_SPACING_AMOUNT)
+				)
+				.padding(SMALL_HORIZONTAL_PADDING)
+				.style(rounded_container_style),
+				icon_button(Bootstrap::XLg)
+					.on_press(
+						DatabaseMessage::ChangeTaskTimeBlock {
+							project_id,
+							task_id,
+							new_time_block: None,
+						}
+						.into()
+					)
+					.style(secondary_button_style_only_round_right),
+			]
+			.align_y(Vertical::Center)
+			.into()
+		}
+		None => button(
+			row![icon_to_text(Bootstrap::CalendarRange), text("Block time")]
+				.spacing(SMALL_SPACING_AMOUNT),
+		)
+		.on_press(on_add)
+		.style(secondary_button_style_default)
+		.into(),
+	}
+}
+
 pub fn due_date_button<Message: 'static + Clone>(
 	edit_due_date: bool,
 	due_date: &Option<SerializableDate>,
@@ -1133,10 +1745,10 @@ pub fn due_date_button<Message: 'static + Clone>(
 	}
 }
 
-pub fn open_in_code_editor_button(
+pub fn open_in_code_editor_button<'a>(
 	file_location: String,
 	code_editor: &CodeEditor,
-) -> Button<'static, Message> {
+) -> Button<'a, Message> {
 	button(
 		row![
 			code_editor.icon(),
@@ -1157,6 +1769,15 @@ pub fn code_editor_dropdown_button(
 		name: "Custom editor name".to_string(),
 		command: "custom_editor --open".to_string(),
 	};
+	// the installed editors and the selected one, even if it wasn't detected
+	let mut code_editors = CodeEditor::installed().to_vec();
+	if let Some(selected_code_editor) = selected_code_editor {
+		if !matches!(selected_code_editor, CodeEditor::Custom { .. })
+			&& !code_editors.contains(selected_code_editor)
+		{
+			code_editors.push(selected_code_editor.clone());
+		}
+	}
 
 	DropDown::new(
 		button(
@@ -1177,14 +1798,15 @@ pub fn code_editor_dropdown_button(
 			.spacing(SPACING_AMOUNT)
 			.align_y(Vertical::Center),
 		)
-		.width(Length::Fixed(150.0))
+		.width(Length::Fixed(200.0))
 		.on_press(settings_modal::Message::ToggleCodeEditorDropdownExpanded.into())
 		.style(secondary_button_style_default),
 		container(
 			column![
 				code_editor_button(None, selected_code_editor, true, false),
-				code_editor_button(Some(CodeEditor::VSCode), selected_code_editor, false, false),
-				code_editor_button(Some(CodeEditor::Zed), selected_code_editor, false, false),
+				Column::with_children(code_editors.into_iter().map(|code_editor| {
+					code_editor_button(Some(code_editor), selected_code_editor, false, false).into()
+				})),
 				code_editor_button(
 					Some(default_custom_code_editor),
 					selected_code_editor,
@@ -1197,7 +1819,7 @@ pub fn code_editor_dropdown_button(
 		.style(dropdown_container_style),
 		dropdown_expanded,
 	)
-	.width(Fixed(150.0))
+	.width(Fixed(200.0))
 	.alignment(drop_down::Alignment::Bottom)
 	.offset(0.0)
 	.on_dismiss(settings_modal::Message::CollapseCodeEditorDropdown.into())
@@ -1329,7 +1951,7 @@ pub fn calendar_view_button(
 	round_right: bool,
 ) -> Button<'static, Message> {
 	button(text(calendar_view.label()).align_x(Horizontal::Center))
-		.width(SETTINGS_SELECTION_LIST_WIDTH / 3.0)
+		.width(SETTINGS_SELECTION_LIST_WIDTH / 4.0)
 		.on_press(
 			PreferenceMessage::SetOverviewPage(SerializedOverviewPage::Calendar {
 				view: calendar_view,
@@ -1366,9 +1988,155 @@ pub fn calendar_navigation_button(forward: bool) -> Button<'static, Message> {
 	.style(hidden_secondary_button_style)
 }
 
+pub fn calendar_task_button(
+	project_id: ProjectId,
+	task_id: TaskId,
+	task_name: &str,
+) -> Button<'_, Message> {
+	button(text(task_name).size(SMALL_TEXT_SIZE))
+		.width(Fill)
+		.padding(SMALL_HORIZONTAL_PADDING)
+		.on_press(Message::OpenTaskModal {
+			project_id,
+			task_id,
+		})
+		.style(hidden_secondary_button_style)
+}
+
+pub fn calendar_day_overflow_button<'a>(
+	day: NaiveDate,
+	hidden_task_count: usize,
+) -> Button<'a, Message> {
+	button(text(format!("+{hidden_task_count} more")).size(SMALL_TEXT_SIZE))
+		.padding(SMALL_HORIZONTAL_PADDING)
+		.on_press(
+			PreferenceMessage::SetOverviewPage(SerializedOverviewPage::Calendar {
+				view: CalendarView::Week { week_day: day },
+			})
+			.into(),
+		)
+		.style(hidden_secondary_button_style)
+}
+
+pub fn show_unscheduled_tasks_button(
+	show: bool,
+	unscheduled_task_count: usize,
+) -> Button<'static, Message> {
+	icon_label_button(
+		format!("Unscheduled ({unscheduled_task_count})"),
+		if show {
+			Bootstrap::InboxFill
+		} else {
+			Bootstrap::Inbox
+		},
+	)
+	.on_press(overview_page::Message::ShowUnscheduledTasks(!show).into())
+	.style(secondary_button_style_default)
+}
+
 pub fn calendar_today_button() -> Button<'static, Message> {
 	button(text("Today").align_y(Vertical::Center))
 		.on_press(overview_page::Message::GoToToday.into())
 		.style(primary_button_style)
 		.height(LARGE_ICON_BUTTON_WIDTH)
 }
+
+pub fn set_selected_tasks_done_button() -> Element<'static, Message> {
+	tooltip(
+		icon_button(Bootstrap::CheckSquare)
+			.on_press(project_page::Message::SetSelectedTasksDone.into())
+			.style(secondary_button_style_default),
+		text("Mark as done").size(SMALL_TEXT_SIZE),
+		tooltip::Position::Bottom,
+	)
+	.gap(GAP)
+	.style(tooltip_container_style)
+	.into()
+}
+
+pub fn set_selected_tasks_todo_button() -> Element<'static, Message> {
+	tooltip(
+		icon_button(Bootstrap::Square)
+			.on_press(project_page::Message::SetSelectedTasksTodo.into())
+			.style(secondary_button_style_default),
+		text("Mark as todo").size(SMALL_TEXT_SIZE),
+		tooltip::Position::Bottom,
+	)
+	.gap(GAP)
+	.style(tooltip_container_style)
+	.into()
+}
+
+pub fn delete_selected_tasks_button(selected_tasks_len: usize) -> Button<'static, Message> {
+	icon_label_button("Delete", Bootstrap::Trash)
+		.on_press(confirm_modal::Message::open(
+			format!("Delete {selected_tasks_len} selected tasks?"),
+			project_page::Message::DeleteSelectedTasks,
+		))
+		.style(move |t, s| delete_button_style(t, s, true, true, true, true))
+}
+
+pub fn clear_task_selection_button() -> Element<'static, Message> {
+	tooltip(
+		icon_button(Bootstrap::XLg)
+			.on_press(project_page::Message::ClearTaskSelection.into())
+			.style(secondary_button_style_default),
+		text("Clear selection (Esc)").size(SMALL_TEXT_SIZE),
+		tooltip::Position::Bottom,
+	)
+	.gap(GAP)
+	.style(tooltip_container_style)
+	.into()
+}
+
+pub fn move_selected_tasks_dropdown_button<'a>(
+	opened: bool,
+	project_id: ProjectId,
+	database: &'a Database,
+) -> Element<'a, Message> {
+	let other_projects: Vec<_> = database
+		.projects()
+		.iter()
+		.filter(|(other_project_id, _project)| *other_project_id != project_id)
+		.collect();
+	let other_projects_len = other_projects.len();
+
+	DropDown::new(
+		icon_label_button("Move to", Bootstrap::FolderSymlink)
+			.on_press_maybe(if other_projects.is_empty() {
+				None
+			} else if opened {
+				Some(project_page::Message::CloseMoveSelectedTasksDropdown.into())
+			} else {
+				Some(project_page::Message::OpenMoveSelectedTasksDropdown.into())
+			})
+			.style(secondary_button_style_default),
+		container(Column::with_children(
+			other_projects
+				.into_iter()
+				.enumerate()
+				.map(|(i, (other_project_id, other_project))| {
+					button(text(other_project.name.clone()))
+						.width(Fill)
+						.style(move |t, s| {
+							enum_dropdown_button_style(
+								t,
+								s,
+								false,
+								i == 0,
+								i + 1 == other_projects_len,
+							)
+						})
+						.on_press(project_page::Message::MoveSelectedTasks(other_project_id).into())
+						.into()
+				}),
+		))
+		.style(dropdown_container_style),
+		opened,
+	)
+	.width(Fixed(200.0))
+	.alignment(drop_down::Alignment::Bottom)
+	.offset(0.0)
+	.on_dismiss(project_page::Message::CloseMoveSelectedTasksDropdown.into())
+	.into()
+}
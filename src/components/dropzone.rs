@@ -1,6 +1,13 @@
-use crate::styles::{in_between_dropzone_container_style, SPACING_AMOUNT};
+use crate::{
+	icons::{icon_to_text, Bootstrap},
+	styles::{
+		dropzone_container_style, in_between_dropzone_container_style, PADDING_AMOUNT,
+		SMALL_SPACING_AMOUNT, SPACING_AMOUNT,
+	},
+};
 use iced::{
-	widget::{container, container::Id, Space},
+	alignment::Vertical,
+	widget::{container, container::Id, row, text, Space},
 	Element,
 	Length::Fill,
 	Padding,
@@ -24,3 +31,19 @@ pub fn in_between_dropzone<Message: 'static>(id: Id, highlight: bool) -> Element
 	.id(id)
 	.into()
 }
+
+/// highlighted while files are dragged over the window, see 'window::Event::FileHovered'
+pub fn file_dropzone<Message: 'static>(
+	label: &'static str,
+	highlight: bool,
+) -> Element<'static, Message> {
+	container(
+		row![icon_to_text(Bootstrap::Paperclip), text(label)]
+			.spacing(SMALL_SPACING_AMOUNT)
+			.align_y(Vertical::Center),
+	)
+	.center_x(Fill)
+	.padding(PADDING_AMOUNT)
+	.style(move |t| dropzone_container_style(t, highlight))
+	.into()
+}
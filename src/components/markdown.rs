@@ -1,13 +1,26 @@
 use std::{
 	cell::{Cell, RefCell},
 	ops::Range,
+	path::PathBuf,
 	sync::Arc,
 };
 
-use crate::styles::{checkbox_style, markdown_code_container_style, JET_BRAINS_MONO_FONT};
+use crate::{
+	components::horizontal_seperator,
+	styles::{
+		checkbox_style, markdown_block_quote_container_style, markdown_code_container_style,
+		markdown_table_container_style, tooltip_container_style, GAP, JET_BRAINS_MONO_FONT,
+		SMALL_TEXT_SIZE,
+	},
+};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use iced::{
+	alignment::Horizontal,
 	font, padding,
-	widget::{checkbox, column, container, markdown, rich_text, row, scrollable, span, text},
+	widget::{
+		checkbox, column, container, image, markdown, rich_text, row, scrollable, span, text,
+		tooltip, Column, Row,
+	},
 	Color, Element, Font, Length, Pixels,
 };
 
@@ -36,6 +49,23 @@ pub enum Item {
 		/// The items of the list.
 		items: Arc<[ListItems]>,
 	},
+	/// A table, its cells only contain text.
+	Table(Table),
+	/// A block quote.
+	BlockQuote(Arc<[Item]>),
+	/// A horizontal rule.
+	Rule,
+	/// An image from a local path or an embedded data url.
+	Image { handle: image::Handle, alt: String },
+	/// A footnote definition, referenced with its label.
+	FootnoteDefinition { label: String, items: Arc<[Item]> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+	alignments: Vec<pulldown_cmark::Alignment>,
+	head: Vec<Text>,
+	rows: Vec<Vec<Text>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -62,6 +92,17 @@ enum DynamicItem {
 		start: Option<u64>,
 		items: Vec<DynamicListItems>,
 	},
+	Table(Table),
+	BlockQuote(Vec<DynamicItem>),
+	Rule,
+	Image {
+		handle: image::Handle,
+		alt: String,
+	},
+	FootnoteDefinition {
+		label: String,
+		items: Vec<DynamicItem>,
+	},
 }
 
 impl From<DynamicItem> for Item {
@@ -74,6 +115,16 @@ impl From<DynamicItem> for Item {
 			DynamicItem::Heading(heading_level, text) => Item::Heading(heading_level, text),
 			DynamicItem::CodeBlock(text) => Item::CodeBlock(text),
 			DynamicItem::Paragraph(text) => Item::Paragraph(text),
+			DynamicItem::Table(table) => Item::Table(table),
+			DynamicItem::BlockQuote(items) => {
+				Item::BlockQuote(items.into_iter().map(|di| di.into()).collect())
+			}
+			DynamicItem::Rule => Item::Rule,
+			DynamicItem::Image { handle, alt } => Item::Image { handle, alt },
+			DynamicItem::FootnoteDefinition { label, items } => Item::FootnoteDefinition {
+				label,
+				items: items.into_iter().map(|di| di.into()).collect(),
+			},
 		}
 	}
 }
@@ -152,9 +203,19 @@ enum Span {
 		color: Option<Color>,
 		font: Option<Font>,
 	},
+	FootnoteReference {
+		label: String,
+	},
 }
 
 impl Span {
+	fn text(&self) -> &str {
+		match self {
+			Span::Standard { text, .. } | Span::Highlight { text, .. } => text,
+			Span::FootnoteReference { label } => label,
+		}
+	}
+
 	fn view(&self, style: &markdown::Style) -> text::Span<'static, MarkdownMessage> {
 		match self {
 			Span::Standard {
@@ -203,6 +264,9 @@ impl Span {
 			Span::Highlight { text, color, font } => {
 				span(text.clone()).color_maybe(*color).font_maybe(*font)
 			}
+			Span::FootnoteReference { label } => span(format!("[{label}]"))
+				.color(style.link_color)
+				.size(SMALL_TEXT_SIZE),
 		}
 	}
 }
@@ -237,7 +301,7 @@ pub fn markdown_with_jetbrainsmono_font<'a>(
 
 	let spacing = text_size * 0.625;
 
-	let blocks = items.iter().enumerate().map(|(i, item)| match item {
+	let parents = items.iter().enumerate().map(|(i, item)| match item {
 		Item::Heading(level, heading) => container(
 			rich_text(heading.spans(style))
 				.size(match level {
@@ -314,9 +378,73 @@ pub fn markdown_with_jetbrainsmono_font<'a>(
 		.padding(spacing.0 / 2.0)
 		.style(markdown_code_container_style)
 		.into(),
+		Item::Table(table) => {
+			let table_row = |cells: &[Text]| -> Element<'a, MarkdownMessage> {
+				Row::with_children(cells.iter().enumerate().map(|(column_index, cell)| {
+					rich_text(cell.spans(style))
+						.size(text_size)
+						.font(JET_BRAINS_MONO_FONT)
+						.width(Length::FillPortion(1))
+						.align_x(match table.alignments.get(column_index) {
+							Some(pulldown_cmark::Alignment::Center) => Horizontal::Center,
+							Some(pulldown_cmark::Alignment::Right) => Horizontal::Right,
+							_ => Horizontal::Left,
+						})
+						.into()
+				}))
+				.spacing(spacing)
+				.into()
+			};
+
+			container(
+				Column::new()
+					.push(table_row(&table.head))
+					.push(horizontal_seperator())
+					.extend(table.rows.iter().map(|cells| table_row(cells)))
+					.spacing(spacing.0 / 2.0),
+			)
+			.width(Length::Fill)
+			.padding(spacing.0 / 2.0)
+			.style(markdown_table_container_style)
+			.into()
+		}
+		Item::BlockQuote(items) => container(markdown_with_jetbrainsmono_font(
+			items.clone(),
+			settings,
+			style,
+		))
+		.width(Length::Fill)
+		.padding(spacing.0 / 2.0)
+		.style(markdown_block_quote_container_style)
+		.into(),
+		Item::Rule => horizontal_seperator().into(),
+		Item::Image { handle, alt } => {
+			let image_view = image(handle.clone()).width(Length::Shrink);
+			if alt.is_empty() {
+				image_view.into()
+			} else {
+				tooltip(
+					image_view,
+					text(alt.clone()).size(SMALL_TEXT_SIZE),
+					tooltip::Position::Bottom,
+				)
+				.gap(GAP)
+				.style(tooltip_container_style)
+				.into()
+			}
+		}
+		Item::FootnoteDefinition { label, items } => row![
+			text(format!("[{label}]"))
+				.font(JET_BRAINS_MONO_FONT)
+				.size(SMALL_TEXT_SIZE)
+				.color(style.link_color),
+			markdown_with_jetbrainsmono_font(items.clone(), settings, style)
+		]
+		.spacing(spacing)
+		.into(),
 	});
 
-	Element::new(column(blocks).width(Length::Fill).spacing(text_size))
+	Element::new(column(parents).width(Length::Fill).spacing(text_size))
 }
 
 pub fn parse_markdown(markdown: &str) -> Arc<[Item]> {
@@ -324,13 +452,21 @@ pub fn parse_markdown(markdown: &str) -> Arc<[Item]> {
 }
 
 // copied from iced_widget-0.13.4/src/markdown.rs:190..491
-// TODO: add gfm support with blocknotes --> see 'pulldown_cmark::Options::ENABLE_GFM'
+// modifications: tables, block quotes, rules, images and footnotes
 /// Parse the given Markdown content.
 #[allow(clippy::expect_used, clippy::unwrap_used)]
 fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
-	struct List {
-		start: Option<u64>,
-		items: Vec<DynamicListItems>,
+	/// the parents other items are nested in
+	enum Parent {
+		List {
+			start: Option<u64>,
+			items: Vec<DynamicListItems>,
+		},
+		BlockQuote(Vec<DynamicItem>),
+		FootnoteDefinition {
+			label: String,
+			items: Vec<DynamicItem>,
+		},
 	}
 
 	let mut spans = Vec::new();
@@ -338,9 +474,11 @@ fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
 	let mut emphasis = false;
 	let mut strikethrough = false;
 	let mut metadata = false;
-	let mut table = false;
+	let mut table: Option<Table> = None;
+	let mut table_head = false;
+	let mut image_url: Option<String> = None;
 	let mut link = None;
-	let mut lists: Vec<List> = Vec::new();
+	let mut parents: Vec<Parent> = Vec::new();
 	let mut task_hint: bool = false;
 
 	let mut highlighter = None;
@@ -351,22 +489,18 @@ fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
 			| pulldown_cmark::Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS
 			| pulldown_cmark::Options::ENABLE_TABLES
 			| pulldown_cmark::Options::ENABLE_STRIKETHROUGH
-			| pulldown_cmark::Options::ENABLE_TASKLISTS,
+			| pulldown_cmark::Options::ENABLE_TASKLISTS
+			| pulldown_cmark::Options::ENABLE_FOOTNOTES,
 	);
 
-	let produce = |lists: &mut Vec<List>, item: DynamicItem| {
-		if lists.is_empty() {
-			Some(item)
-		} else {
-			lists
-				.last_mut()
-				.expect("list context")
-				.items
-				.last_mut()
-				.expect("item context")
-				.items
-				.push(item);
-
+	let produce = |parents: &mut Vec<Parent>, item: DynamicItem| match parents.last_mut() {
+		None => Some(item),
+		Some(Parent::List { items, .. }) => {
+			items.last_mut().expect("item context").items.push(item);
+			None
+		}
+		Some(Parent::BlockQuote(items) | Parent::FootnoteDefinition { items, .. }) => {
+			items.push(item);
 			None
 		}
 	};
@@ -377,19 +511,19 @@ fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
 		.into_offset_iter()
 		.filter_map(move |(event, range)| match event {
 			pulldown_cmark::Event::Start(tag) => match tag {
-				pulldown_cmark::Tag::Strong if !metadata && !table => {
+				pulldown_cmark::Tag::Strong if !metadata => {
 					strong = true;
 					None
 				}
-				pulldown_cmark::Tag::Emphasis if !metadata && !table => {
+				pulldown_cmark::Tag::Emphasis if !metadata => {
 					emphasis = true;
 					None
 				}
-				pulldown_cmark::Tag::Strikethrough if !metadata && !table => {
+				pulldown_cmark::Tag::Strikethrough if !metadata => {
 					strikethrough = true;
 					None
 				}
-				pulldown_cmark::Tag::Link { dest_url, .. } if !metadata && !table => {
+				pulldown_cmark::Tag::Link { dest_url, .. } if !metadata => {
 					match markdown::Url::parse(&dest_url) {
 						Ok(url) if url.scheme() == "http" || url.scheme() == "https" => {
 							link = Some(url);
@@ -399,17 +533,30 @@ fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
 
 					None
 				}
-				pulldown_cmark::Tag::List(first_item) if !metadata && !table => {
+				pulldown_cmark::Tag::Image { dest_url, .. } if !metadata && table.is_none() => {
+					image_url = Some(dest_url.into_string());
+
+					// the alt text is collected into the spans
+					if spans.is_empty() {
+						None
+					} else {
+						produce(
+							&mut parents,
+							DynamicItem::Paragraph(Text::new(spans.drain(..).collect())),
+						)
+					}
+				}
+				pulldown_cmark::Tag::List(first_item) if !metadata && table.is_none() => {
 					let output = if spans.is_empty() {
 						None
 					} else {
 						produce(
-							&mut lists,
+							&mut parents,
 							DynamicItem::Paragraph(Text::new(spans.drain(..).collect())),
 						)
 					};
 
-					lists.push(List {
+					parents.push(Parent::List {
 						start: first_item,
 						items: Vec::new(),
 					});
@@ -417,19 +564,38 @@ fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
 					output
 				}
 				pulldown_cmark::Tag::Item => {
-					lists
-						.last_mut()
-						.expect("list context")
-						.items
-						.push(DynamicListItems {
+					if let Some(Parent::List { items, .. }) = parents.last_mut() {
+						items.push(DynamicListItems {
 							beginner: ListItemBeginner::default(),
 							items: Vec::new(),
 						});
+					}
+					None
+				}
+				pulldown_cmark::Tag::BlockQuote(_) if !metadata && table.is_none() => {
+					let output = if spans.is_empty() {
+						None
+					} else {
+						produce(
+							&mut parents,
+							DynamicItem::Paragraph(Text::new(spans.drain(..).collect())),
+						)
+					};
+
+					parents.push(Parent::BlockQuote(Vec::new()));
+
+					output
+				}
+				pulldown_cmark::Tag::FootnoteDefinition(label) if !metadata => {
+					parents.push(Parent::FootnoteDefinition {
+						label: label.into_string(),
+						items: Vec::new(),
+					});
 					None
 				}
 				pulldown_cmark::Tag::CodeBlock(pulldown_cmark::CodeBlockKind::Fenced(
 					_language,
-				)) if !metadata && !table => {
+				)) if !metadata && table.is_none() => {
 					{
 						use iced::highlighter::Highlighter;
 						use text::Highlighter as _;
@@ -446,67 +612,123 @@ fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
 					metadata = true;
 					None
 				}
-				pulldown_cmark::Tag::Table(_) => {
-					table = true;
+				pulldown_cmark::Tag::Table(alignments) if !metadata => {
+					table = Some(Table {
+						alignments,
+						head: Vec::new(),
+						rows: Vec::new(),
+					});
+					None
+				}
+				pulldown_cmark::Tag::TableHead => {
+					table_head = true;
+					None
+				}
+				pulldown_cmark::Tag::TableRow => {
+					if let Some(table) = &mut table {
+						table.rows.push(Vec::new());
+					}
 					None
 				}
 				_ => None,
 			},
 			pulldown_cmark::Event::End(tag) => match tag {
-				pulldown_cmark::TagEnd::Heading(level) if !metadata && !table => produce(
-					&mut lists,
+				pulldown_cmark::TagEnd::Heading(level) if !metadata => produce(
+					&mut parents,
 					DynamicItem::Heading(level, Text::new(spans.drain(..).collect())),
 				),
-				pulldown_cmark::TagEnd::Strong if !metadata && !table => {
+				pulldown_cmark::TagEnd::Strong if !metadata => {
 					strong = false;
 					None
 				}
-				pulldown_cmark::TagEnd::Emphasis if !metadata && !table => {
+				pulldown_cmark::TagEnd::Emphasis if !metadata => {
 					emphasis = false;
 					None
 				}
-				pulldown_cmark::TagEnd::Strikethrough if !metadata && !table => {
+				pulldown_cmark::TagEnd::Strikethrough if !metadata => {
 					strikethrough = false;
 					None
 				}
-				pulldown_cmark::TagEnd::Link if !metadata && !table => {
+				pulldown_cmark::TagEnd::Link if !metadata => {
 					link = None;
 					None
 				}
-				pulldown_cmark::TagEnd::Paragraph if !metadata && !table => {
+				pulldown_cmark::TagEnd::Image if !metadata && table.is_none() => {
+					let url = image_url.take().unwrap_or_default();
+					let alt: Vec<Span> = spans.drain(..).collect();
+
+					let item = match load_image(&url) {
+						Some(handle) => DynamicItem::Image {
+							handle,
+							alt: alt.iter().map(Span::text).collect(),
+						},
+						// images from the web aren't downloaded, they are linked instead
+						None => {
+							let link = markdown::Url::parse(&url)
+								.ok()
+								.filter(|url| url.scheme() == "http" || url.scheme() == "https");
+							DynamicItem::Paragraph(Text::new(vec![Span::Standard {
+								text: if alt.is_empty() {
+									url
+								} else {
+									alt.iter().map(Span::text).collect()
+								},
+								strikethrough: false,
+								link,
+								strong: false,
+								emphasis: true,
+								code: false,
+							}]))
+						}
+					};
+					produce(&mut parents, item)
+				}
+				pulldown_cmark::TagEnd::Paragraph if !metadata => {
 					let spans: Vec<_> = spans.drain(..).collect();
 					if spans.is_empty() {
 						None
 					} else {
-						produce(&mut lists, DynamicItem::Paragraph(Text::new(spans)))
+						produce(&mut parents, DynamicItem::Paragraph(Text::new(spans)))
 					}
 				}
-				pulldown_cmark::TagEnd::Item if !metadata && !table => {
+				pulldown_cmark::TagEnd::Item if !metadata => {
 					if spans.is_empty() {
 						None
 					} else {
 						produce(
-							&mut lists,
+							&mut parents,
 							DynamicItem::Paragraph(Text::new(spans.drain(..).collect())),
 						)
 					}
 				}
-				pulldown_cmark::TagEnd::List(_) if !metadata && !table => {
-					let list = lists.pop().expect("list context");
-
-					produce(
-						&mut lists,
-						DynamicItem::List {
-							start: list.start,
-							items: list.items,
-						},
-					)
+				pulldown_cmark::TagEnd::List(_) if !metadata && table.is_none() => {
+					match parents.pop() {
+						Some(Parent::List { start, items }) => {
+							produce(&mut parents, DynamicItem::List { start, items })
+						}
+						_ => None,
+					}
+				}
+				pulldown_cmark::TagEnd::BlockQuote(_) if !metadata && table.is_none() => {
+					match parents.pop() {
+						Some(Parent::BlockQuote(items)) => {
+							produce(&mut parents, DynamicItem::BlockQuote(items))
+						}
+						_ => None,
+					}
 				}
-				pulldown_cmark::TagEnd::CodeBlock if !metadata && !table => {
+				pulldown_cmark::TagEnd::FootnoteDefinition if !metadata => match parents.pop() {
+					Some(Parent::FootnoteDefinition { label, items }) => produce(
+						&mut parents,
+						DynamicItem::FootnoteDefinition { label, items },
+					),
+					_ => None,
+				},
+				pulldown_cmark::TagEnd::CodeBlock if !metadata && table.is_none() => {
 					highlighter = None;
 
 					produce(
-						&mut lists,
+						&mut parents,
 						DynamicItem::CodeBlock(Text::new(spans.drain(..).collect())),
 					)
 				}
@@ -514,13 +736,26 @@ fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
 					metadata = false;
 					None
 				}
-				pulldown_cmark::TagEnd::Table => {
-					table = false;
+				pulldown_cmark::TagEnd::TableHead => {
+					table_head = false;
 					None
 				}
+				pulldown_cmark::TagEnd::TableCell => {
+					let cell = Text::new(spans.drain(..).collect());
+					if let Some(table) = &mut table {
+						match table.rows.last_mut() {
+							Some(row) if !table_head => row.push(cell),
+							_ => table.head.push(cell),
+						}
+					}
+					None
+				}
+				pulldown_cmark::TagEnd::Table => table
+					.take()
+					.and_then(|table| produce(&mut parents, DynamicItem::Table(table))),
 				_ => None,
 			},
-			pulldown_cmark::Event::Text(text) if !metadata && !table => {
+			pulldown_cmark::Event::Text(text) if !metadata => {
 				if let Some(highlighter) = &mut highlighter {
 					use text::Highlighter as _;
 
@@ -539,7 +774,7 @@ fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
 
 				let span = Span::Standard {
 					text: text.into_string(),
-					strong,
+					strong: strong || table_head,
 					emphasis,
 					strikethrough,
 					link: link.clone(),
@@ -552,14 +787,14 @@ fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
 					true => {
 						task_hint = false;
 						produce(
-							&mut lists,
+							&mut parents,
 							DynamicItem::Paragraph(Text::new(spans.drain(..).collect())),
 						)
 					}
 					false => None,
 				}
 			}
-			pulldown_cmark::Event::Code(code) if !metadata && !table => {
+			pulldown_cmark::Event::Code(code) if !metadata => {
 				let span = Span::Standard {
 					text: code.into_string(),
 					strong,
@@ -572,7 +807,7 @@ fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
 				spans.push(span);
 				None
 			}
-			pulldown_cmark::Event::SoftBreak if !metadata && !table => {
+			pulldown_cmark::Event::SoftBreak if !metadata => {
 				spans.push(Span::Standard {
 					text: String::from(" "),
 					strikethrough,
@@ -583,7 +818,7 @@ fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
 				});
 				None
 			}
-			pulldown_cmark::Event::HardBreak if !metadata && !table => {
+			pulldown_cmark::Event::HardBreak if !metadata => {
 				spans.push(Span::Standard {
 					text: String::from("\n"),
 					strikethrough,
@@ -594,9 +829,16 @@ fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
 				});
 				None
 			}
-			pulldown_cmark::Event::TaskListMarker(checked) if !metadata && !table => {
-				if let Some(last_list) = lists.last_mut() {
-					if let Some(last_item_list) = last_list.items.last_mut() {
+			pulldown_cmark::Event::Rule if !metadata => produce(&mut parents, DynamicItem::Rule),
+			pulldown_cmark::Event::FootnoteReference(label) if !metadata => {
+				spans.push(Span::FootnoteReference {
+					label: label.into_string(),
+				});
+				None
+			}
+			pulldown_cmark::Event::TaskListMarker(checked) if !metadata => {
+				if let Some(Parent::List { items, .. }) = parents.last_mut() {
+					if let Some(last_item_list) = items.last_mut() {
 						last_item_list.beginner = ListItemBeginner::Checkbox {
 							checked,
 							range: range.clone(),
@@ -611,9 +853,26 @@ fn advanced_parse(markdown: &str) -> impl Iterator<Item = DynamicItem> + '_ {
 		})
 }
 
+/// supports embedded base64 data urls, file urls and absolute paths
+fn load_image(url: &str) -> Option<image::Handle> {
+	if let Some(data_url) = url.strip_prefix("data:") {
+		let (_media_type, data) = data_url.split_once(";base64,")?;
+		return BASE64
+			.decode(data.trim())
+			.ok()
+			.map(image::Handle::from_bytes);
+	}
+
+	let filepath = match markdown::Url::parse(url) {
+		Ok(url) if url.scheme() == "file" => url.to_file_path().ok()?,
+		_ => PathBuf::from(url),
+	};
+	(filepath.is_absolute() && filepath.is_file()).then(|| image::Handle::from_path(filepath))
+}
+
 #[cfg(test)]
 mod tests {
-	use super::{advanced_parse, Span, Text};
+	use super::{advanced_parse, Span, Table, Text};
 	use crate::components::markdown::{DynamicItem, DynamicListItems, ListItemBeginner};
 	use std::{
 		cell::{Cell, RefCell},
@@ -733,4 +992,106 @@ mod tests {
 			}]
 		)
 	}
+
+	fn styled_text(text: &'static str, strong: bool, strikethrough: bool) -> Text {
+		Text::new(vec![Span::Standard {
+			text: text.to_string(),
+			strikethrough,
+			link: None,
+			strong,
+			emphasis: false,
+			code: false,
+		}])
+	}
+
+	#[test]
+	fn test_table_markdown_parsing() {
+		let markdown_str = r"
+| Name | Done |
+|:-----|-----:|
+| A    | ~~no~~ |";
+		let markdown_items: Vec<DynamicItem> = advanced_parse(markdown_str).collect();
+
+		assert_eq!(
+			markdown_items,
+			vec![DynamicItem::Table(Table {
+				alignments: vec![
+					pulldown_cmark::Alignment::Left,
+					pulldown_cmark::Alignment::Right
+				],
+				head: vec![
+					styled_text("Name", true, false),
+					styled_text("Done", true, false)
+				],
+				rows: vec![vec![
+					styled_text("A", false, false),
+					styled_text("no", false, true)
+				]],
+			})]
+		)
+	}
+
+	#[test]
+	fn test_block_quote_rule_and_footnote_markdown_parsing() {
+		let markdown_str = r"
+> quote[^1]
+> - A
+
+---
+
+[^1]: note";
+		let markdown_items: Vec<DynamicItem> = advanced_parse(markdown_str).collect();
+
+		assert_eq!(
+			markdown_items,
+			vec![
+				DynamicItem::BlockQuote(vec![
+					DynamicItem::Paragraph(Text::new(vec![
+						Span::Standard {
+							text: "quote".to_string(),
+							strikethrough: false,
+							link: None,
+							strong: false,
+							emphasis: false,
+							code: false,
+						},
+						Span::FootnoteReference {
+							label: "1".to_string()
+						}
+					])),
+					DynamicItem::List {
+						start: None,
+						items: vec![DynamicListItems {
+							beginner: ListItemBeginner::Default,
+							items: vec![paragraph("A")]
+						}]
+					}
+				]),
+				DynamicItem::Rule,
+				DynamicItem::FootnoteDefinition {
+					label: "1".to_string(),
+					items: vec![paragraph("note")]
+				}
+			]
+		)
+	}
+
+	#[test]
+	fn test_image_markdown_parsing() {
+		let markdown_str = "![logo](https://example.com/logo.png)";
+		let markdown_items: Vec<DynamicItem> = advanced_parse(markdown_str).collect();
+		assert!(matches!(
+			markdown_items.as_slice(),
+			[DynamicItem::Paragraph(Text { spans, .. })]
+				if matches!(&spans[..], [Span::Standard { text, link: Some(_), .. }] if text == "logo")
+		));
+
+		// embedded 1x1 png
+		let markdown_str = "![pixel](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=)";
+		let markdown_items: Vec<DynamicItem> = advanced_parse(markdown_str).collect();
+		assert!(matches!(
+			markdown_items.as_slice(),
+			[DynamicItem::Image { alt, .. }] if alt == "pixel"
+		));
+	}
 }
@@ -3,27 +3,42 @@ pub use completion_bar::completion_bar;
 
 mod buttons;
 pub use buttons::{
-	calendar_navigation_button, calendar_today_button, calendar_view_button,
-	cancel_create_new_task_tag_button, cancel_create_project_button, cancel_search_tasks_button,
-	close_create_new_task_modal_button, code_editor_dropdown_button, color_palette_item_button,
-	complete_task_timer_button, confirm_cancel_button, confirm_ok_button, copy_to_clipboard_button,
-	create_empty_database_button, create_new_project_button, create_new_task_modal_button,
-	create_new_task_tags_button, dangerous_button, date_formatting_button,
-	delete_all_done_tasks_button, delete_task_button, delete_task_tag_button, due_date_button,
-	edit_color_palette_button, edit_needed_time_button, error_msg_ok_button,
-	export_as_json_database_button, export_database_as_markdown_button, export_database_button,
+	add_task_comment_button, calendar_day_overflow_button, calendar_navigation_button,
+	calendar_task_button, calendar_today_button, calendar_view_button,
+	cancel_create_new_task_tag_button, cancel_create_project_button,
+	cancel_editing_task_comment_button, cancel_search_tasks_button, carry_over_day_plan_button,
+	clear_task_selection_button, close_create_new_task_modal_button, code_editor_dropdown_button,
+	color_palette_item_button, complete_task_timer_button, confirm_cancel_button,
+	confirm_ok_button, copy_to_clipboard_button, create_empty_database_button,
+	create_new_project_button, create_new_task_modal_button, create_new_task_tags_button,
+	dangerous_button, date_formatting_button, delete_all_done_tasks_button,
+	delete_selected_tasks_button, delete_task_button, delete_task_comment_button,
+	delete_task_tag_button, due_date_button, due_time_button, edit_color_palette_button,
+	edit_needed_time_button, edit_task_comment_button, error_msg_ok_button,
+	export_as_json_database_button, export_database_as_icalendar_button,
+	export_database_as_markdown_button, export_database_as_todo_txt_button, export_database_button,
 	first_weekday_button, force_close_anyways_button, hide_password_button, import_database_button,
-	import_google_tasks_button, import_json_database_button, open_create_task_modal_button,
-	open_folder_location_button, open_in_code_editor_button, open_project_button,
-	open_task_by_name_link_button, overview_button, pause_timer_button,
-	project_context_menu_button, reimport_source_code_todos_button, resume_timer_button,
-	retry_loading_database_button, retry_synchronization_button, search_tasks_button,
-	select_synchronization_filepath_button, settings_button, settings_tab_button,
-	show_done_tasks_button, show_error_popup_button, show_password_button,
-	show_source_code_todos_button, sort_dropdown_button, start_task_timer_button,
+	import_google_tasks_button, import_ics_button, import_issues_button,
+	import_json_database_button, import_markdown_button, import_summary_ok_button,
+	import_todo_txt_button, mark_task_done_button, move_planned_task_down_button,
+	move_planned_task_up_button, move_selected_tasks_dropdown_button, open_attachment_button,
+	open_create_task_modal_button, open_folder_location_button, open_in_code_editor_button,
+	open_issue_button, open_project_button, open_task_by_name_link_button, overview_button,
+	pause_timer_button, plan_task_button, plan_task_for_today_button, project_context_menu_button,
+	promote_source_code_todo_button, reimport_source_code_todos_button,
+	remove_source_code_todo_keyword_button, remove_task_attachment_button,
+	remove_task_reminder_button, resume_timer_button, retry_loading_database_button,
+	retry_synchronization_button, save_task_comment_button, search_tasks_button,
+	select_synchronization_filepath_button, set_selected_tasks_done_button,
+	set_selected_tasks_todo_button, settings_button, settings_tab_button, show_done_tasks_button,
+	show_error_popup_button, show_password_button, show_source_code_todos_button,
+	show_unscheduled_tasks_button, skip_pomodoro_phase_button, sort_dropdown_button,
+	source_code_todo_author_dropdown_button, start_pomodoro_button, start_task_timer_button,
 	stop_timer_button, stopwatch_button, synchronization_settings_button, take_break_button,
-	task_tag_button, task_tag_name_button, theme_mode_button, toggle_sidebar_button,
-	toggle_view_edit_task_description_button, track_time_button, ICON_BUTTON_WIDTH, ICON_FONT_SIZE,
+	task_priority_dropdown_button, task_tag_button, task_tag_name_button, theme_mode_button,
+	time_block_button, today_button, toggle_sidebar_button,
+	toggle_view_edit_task_description_button, track_time_button, unplan_task_button,
+	ICON_BUTTON_WIDTH, ICON_FONT_SIZE,
 };
 
 mod task_list;
@@ -33,7 +48,7 @@ mod task_widget;
 pub use task_widget::{task_tag_list, task_widget};
 
 mod task_description;
-pub use task_description::{task_description, task_description_editor};
+pub use task_description::{task_comment_body, task_description, task_description_editor};
 
 mod duration_widget;
 pub use duration_widget::{duration_text, duration_widget};
@@ -66,7 +81,7 @@ pub use scrollable::{
 };
 
 mod dropzone;
-pub use dropzone::in_between_dropzone;
+pub use dropzone::{file_dropzone, in_between_dropzone};
 
 mod stopwatch_clock;
 pub use stopwatch_clock::StopwatchClock;
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc};
 
 use crate::{
 	components::markdown::{markdown_with_jetbrainsmono_font, Item, MarkdownMessage},
@@ -20,7 +20,7 @@ use iced::{
 	Length::Fill,
 	Pixels,
 };
-use project_tracker_core::{DatabaseMessage, ProjectId, TaskId};
+use project_tracker_core::{DatabaseMessage, ProjectId, TaskCommentId, TaskId};
 
 pub fn task_description(
 	project_id: ProjectId,
@@ -30,27 +30,16 @@ pub fn task_description(
 ) -> Element<Message> {
 	container(match task_description_markdown_items {
 		Some(task_description_markdown_items) if !task_description_markdown_items.is_empty() => {
-			markdown_with_jetbrainsmono_font(
+			markdown_view(
 				task_description_markdown_items,
-				markdown::Settings {
-					// default text_size = 16.0, Settings::default() sets this to text_size * 0.75
-					code_size: Pixels(16.0 * 0.85),
-					..Default::default()
+				app,
+				move |checked, range| DatabaseMessage::ToggleTaskDescriptionMarkdownTask {
+					project_id,
+					task_id,
+					checked,
+					range,
 				},
-				markdown_style(app),
 			)
-			.map(move |markdown_message| match markdown_message {
-				MarkdownMessage::OpenUrl(url) => Message::OpenUrl(url),
-				MarkdownMessage::ToggleCheckbox { checked, range } => {
-					DatabaseMessage::ToggleTaskDescriptionMarkdownTask {
-						project_id,
-						task_id,
-						checked,
-						range,
-					}
-					.into()
-				}
-			})
 		}
 		_ => text("No description").width(Fill).into(),
 	})
@@ -59,6 +48,46 @@ pub fn task_description(
 	.into()
 }
 
+pub fn task_comment_body(
+	project_id: ProjectId,
+	task_id: TaskId,
+	comment_id: TaskCommentId,
+	comment_markdown_items: Arc<[Item]>,
+	app: &ProjectTrackerApp,
+) -> Element<'_, Message> {
+	markdown_view(comment_markdown_items, app, move |checked, range| {
+		DatabaseMessage::ToggleTaskCommentMarkdownTask {
+			project_id,
+			task_id,
+			comment_id,
+			range,
+			checked,
+		}
+	})
+}
+
+fn markdown_view(
+	markdown_items: Arc<[Item]>,
+	app: &ProjectTrackerApp,
+	on_toggle_checkbox: impl Fn(bool, Range<usize>) -> DatabaseMessage + 'static,
+) -> Element<'_, Message> {
+	markdown_with_jetbrainsmono_font(
+		markdown_items,
+		markdown::Settings {
+			// default text_size = 16.0, Settings::default() sets this to text_size * 0.75
+			code_size: Pixels(16.0 * 0.85),
+			..Default::default()
+		},
+		markdown_style(app),
+	)
+	.map(move |markdown_message| match markdown_message {
+		MarkdownMessage::OpenUrl(url) => Message::OpenUrl(url),
+		MarkdownMessage::ToggleCheckbox { checked, range } => {
+			on_toggle_checkbox(checked, range).into()
+		}
+	})
+}
+
 pub fn task_description_editor<'a, Message: 'a + Clone>(
 	task_description_content: &'a Content,
 	on_action: impl Fn(Action) -> Message + 'a,
@@ -1,13 +1,14 @@
 use crate::{
 	components::{
 		delete_all_done_tasks_button, in_between_dropzone, reimport_source_code_todos_button,
-		show_done_tasks_button, show_source_code_todos_button, task_widget, vertical_scrollable,
+		show_done_tasks_button, show_source_code_todos_button,
+		source_code_todo_author_dropdown_button, task_widget, vertical_scrollable,
 	},
 	core::TaskUiIdMap,
 	integrations::CodeEditor,
 	pages::{CachedTaskList, TaskDropzone, BOTTOM_TODO_TASK_DROPZONE_ID},
 	project_tracker::Message,
-	styles::PADDING_AMOUNT,
+	styles::{PADDING_AMOUNT, SPACING_AMOUNT},
 };
 use iced::widget::Space;
 use iced::{
@@ -18,7 +19,10 @@ use iced::{
 	Padding,
 };
 use project_tracker_core::{Project, ProjectId, Task, TaskId, TaskType};
-use std::sync::LazyLock;
+use std::{
+	collections::{BTreeSet, HashSet},
+	sync::LazyLock,
+};
 
 pub static TASK_LIST_ID: LazyLock<scrollable::Id> = LazyLock::new(scrollable::Id::unique);
 
@@ -32,8 +36,11 @@ pub fn task_list<'a>(
 	dragged_task: Option<TaskId>,
 	just_minimal_dragging: bool,
 	hovered_task_dropzone: Option<TaskDropzone>,
+	selected_tasks: &'a HashSet<TaskId>,
 	show_done_tasks: bool,
 	show_source_code_todos: bool,
+	source_code_todo_author_filter: Option<&'a str>,
+	show_source_code_todo_author_dropdown: bool,
 	importing_source_code_todos: bool,
 ) -> Element<'a, Message> {
 	let mut todo_task_elements = Vec::new();
@@ -62,6 +69,7 @@ pub fn task_list<'a>(
 			just_minimal_dragging,
 			true,
 			highlight,
+			selected_tasks.contains(&task_id),
 			true,
 			false,
 		)
@@ -95,22 +103,44 @@ pub fn task_list<'a>(
 		highlight_bottom_todo_task_dropzone,
 	));
 
+	let source_code_todo_authors: BTreeSet<String> = project
+		.source_code_todos
+		.values()
+		.filter_map(|task| {
+			task.source_code_blame
+				.as_ref()
+				.map(|blame| blame.author.clone())
+		})
+		.collect();
+
+	// keep showing the header while filtering, so the filter can be changed again
 	let show_source_code_todos_button: Element<Message> =
-		if cached_task_list.source_code_todo.is_empty() {
+		if cached_task_list.source_code_todo.is_empty() && source_code_todo_author_filter.is_none()
+		{
 			Space::new(0.0, 0.0).into()
 		} else {
-			container(row![
-				show_source_code_todos_button(
+			container(
+				row![show_source_code_todos_button(
 					show_source_code_todos,
 					cached_task_list.source_code_todo.len()
-				),
-				container(reimport_source_code_todos_button(
-					importing_source_code_todos,
-					project.source_code_directory.is_some()
-				))
-				.width(Fill)
-				.align_x(Horizontal::Right)
-			])
+				)]
+				.push_maybe((!source_code_todo_authors.is_empty()).then(|| {
+					source_code_todo_author_dropdown_button(
+						source_code_todo_authors,
+						source_code_todo_author_filter,
+						show_source_code_todo_author_dropdown,
+					)
+				}))
+				.push(
+					container(reimport_source_code_todos_button(
+						importing_source_code_todos,
+						project.source_code_directory.is_some(),
+					))
+					.width(Fill)
+					.align_x(Horizontal::Right),
+				)
+				.spacing(SPACING_AMOUNT),
+			)
 			.padding(Padding {
 				top: PADDING_AMOUNT,
 				bottom: if show_source_code_todos {
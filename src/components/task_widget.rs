@@ -3,7 +3,10 @@ use crate::{
 		days_left_widget, duration_widget, in_between_dropzone, open_in_code_editor_button,
 		task_tag_button,
 	},
-	core::{View, TASK_TAG_QUAD_HEIGHT},
+	core::{
+		is_linked_source_code_todo_removed, source_code_todo_location, time_ago_str, View,
+		TASK_TAG_QUAD_HEIGHT,
+	},
 	icons::{icon_to_text, Bootstrap},
 	integrations::CodeEditor,
 	pages::sidebar_page,
@@ -11,7 +14,8 @@ use crate::{
 	styles::{
 		checkbox_style, default_text_style, grey_text_style, rounded_container_style,
 		task_background_container_style, task_button_style, PADDING_AMOUNT,
-		SMALL_HORIZONTAL_PADDING, SMALL_PADDING_AMOUNT, SMALL_TEXT_SIZE, TINY_SPACING_AMOUNT,
+		SMALL_HORIZONTAL_PADDING, SMALL_PADDING_AMOUNT, SMALL_SPACING_AMOUNT, SMALL_TEXT_SIZE,
+		TINY_SPACING_AMOUNT,
 	},
 };
 use iced::{
@@ -43,6 +47,7 @@ pub fn task_widget<'a>(
 	just_minimal_dragging: bool,
 	draggable: bool,
 	highlight_dropzone: bool,
+	selected: bool,
 	show_due_date: bool,
 	smaller_font: bool,
 ) -> Element<'a, Message> {
@@ -61,7 +66,10 @@ pub fn task_widget<'a>(
 			Some(code_editor) if matches!(task_type, TaskType::SourceCodeTodo) => row![
 				icon_to_text(Bootstrap::GripVertical),
 				Space::new(Fill, 0.0),
-				open_in_code_editor_button(task.description.clone(), code_editor),
+				open_in_code_editor_button(
+					source_code_todo_location(task).to_string(),
+					code_editor
+				),
 			]
 			.align_y(Vertical::Center)
 			.padding(Padding::default().right(SMALL_PADDING_AMOUNT))
@@ -90,6 +98,14 @@ pub fn task_widget<'a>(
 			inner_text
 		}
 		.into();
+		let inner_text_element: Element<'a, Message> = if task.priority.is_none() {
+			inner_text_element
+		} else {
+			row![task.priority.view(), inner_text_element]
+				.spacing(SMALL_SPACING_AMOUNT)
+				.align_y(Vertical::Center)
+				.into()
+		};
 
 		let tags_element = Row::with_children(
 			project
@@ -124,22 +140,27 @@ pub fn task_widget<'a>(
 				grip_icon_dummy,
 				row![
 					container(
+						// source code todos are done once their comment is removed from the code
 						checkbox("", matches!(task_type, TaskType::Done))
-							.on_toggle(move |checked| {
-								if checked {
-									DatabaseMessage::SetTaskDone {
-										project_id,
-										task_id,
-									}
-									.into()
-								} else {
-									DatabaseMessage::SetTaskTodo {
-										project_id,
-										task_id,
+							.on_toggle_maybe(
+								(!matches!(task_type, TaskType::SourceCodeTodo)).then_some(
+									move |checked: bool| -> Message {
+										if checked {
+											DatabaseMessage::SetTaskDone {
+												project_id,
+												task_id,
+											}
+											.into()
+										} else {
+											DatabaseMessage::SetTaskTodo {
+												project_id,
+												task_id,
+											}
+											.into()
+										}
 									}
-									.into()
-								}
-							})
+								)
+							)
 							.style(checkbox_style)
 					)
 					.padding(Padding {
@@ -166,6 +187,24 @@ pub fn task_widget<'a>(
 									.into(),
 							)
 						})
+						.push_maybe(task.source_code_blame.as_ref().map(|blame| {
+							text(format!(
+								"added {} by {}",
+								time_ago_str(blame.added),
+								blame.author
+							))
+							.size(SMALL_TEXT_SIZE)
+							.style(grey_text_style)
+						}))
+						.push_maybe(
+							(matches!(task_type, TaskType::Todo)
+								&& is_linked_source_code_todo_removed(project, task))
+							.then(|| {
+								text("removed from code")
+									.size(SMALL_TEXT_SIZE)
+									.style(grey_text_style)
+							}),
+						)
 						.spacing(TINY_SPACING_AMOUNT),
 					Column::new().push_maybe(
 						if task.needed_time_minutes.is_some()
@@ -236,6 +275,7 @@ pub fn task_widget<'a>(
 				t,
 				draggable && dragging && !just_minimal_dragging,
 				drag_overlay,
+				selected,
 			)
 		})
 		.into()
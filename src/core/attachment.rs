@@ -0,0 +1,44 @@
+use project_tracker_core::{store_attachment_blob, Attachment};
+use std::{
+	io,
+	path::{Path, PathBuf},
+};
+
+/// attachments are sent in a single websocket message when synchronizing with the server,
+/// which must stay well below the default 16 MiB frame size limit of tungstenite
+const MAX_ATTACHMENT_SIZE_BYTES: u64 = 8 * 1024 * 1024;
+
+/// reads the file and stores its content in the attachments directory
+pub async fn store_attachment(
+	attachments_directory: PathBuf,
+	filepath: PathBuf,
+) -> io::Result<(Attachment, Vec<u8>)> {
+	if tokio::fs::metadata(&filepath).await?.len() > MAX_ATTACHMENT_SIZE_BYTES {
+		return Err(io::Error::new(
+			io::ErrorKind::InvalidInput,
+			format!(
+				"'{}' is too large to be attached, attachments can be at most {} MB",
+				filepath.display(),
+				MAX_ATTACHMENT_SIZE_BYTES / 1024 / 1024
+			),
+		));
+	}
+
+	let content = tokio::fs::read(&filepath).await?;
+	let name = filepath
+		.file_name()
+		.map(|file_name| file_name.to_string_lossy().into_owned())
+		.unwrap_or_default();
+	let attachment = Attachment::new(name, &content);
+	store_attachment_blob(
+		&attachments_directory,
+		&attachment.blob_file_name(),
+		&content,
+	)
+	.await?;
+	Ok((attachment, content))
+}
+
+pub fn attachment_filepath(attachments_directory: &Path, attachment: &Attachment) -> PathBuf {
+	attachments_directory.join(attachment.blob_file_name())
+}
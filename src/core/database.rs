@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 use crate::project_tracker::Message;
-use project_tracker_core::{Database, DatabaseMessage};
+use project_tracker_core::{Database, DatabaseMessage, ImportMarkdownError, Project};
 
 impl From<DatabaseMessage> for Message {
 	fn from(value: DatabaseMessage) -> Self {
@@ -51,6 +51,32 @@ pub async fn import_json_database_file_dialog() -> Option<PathBuf> {
 	file_dialog_result.map(|file_handle| file_handle.path().to_path_buf())
 }
 
+pub async fn export_database_as_icalendar_file_dialog() -> Option<PathBuf> {
+	let file_dialog_result = rfd::AsyncFileDialog::new()
+		.set_title("Export ProjectTracker Database as Calendar")
+		.set_file_name(Database::ICALENDAR_FILE_NAME)
+		.add_filter("Calendar (.ics)", &["ics"])
+		.save_file()
+		.await;
+
+	file_dialog_result.map(|file_handle| file_handle.path().to_path_buf())
+}
+
+pub async fn import_markdown_folder_dialog() -> Option<Result<Vec<Project>, ImportMarkdownError>> {
+	let file_dialog_result = rfd::AsyncFileDialog::new()
+		.set_title("Import Markdown Folder")
+		.pick_folder()
+		.await;
+
+	match file_dialog_result {
+		Some(file_handle) => {
+			let folder_path = file_handle.path().to_path_buf();
+			Some(Database::import_markdown(folder_path).await)
+		}
+		None => None,
+	}
+}
+
 pub async fn export_database_as_markdown_file_dialog() -> Option<PathBuf> {
 	let file_dialog_result = rfd::AsyncFileDialog::new()
 		.set_title("Export ProjectTracker Database as Markdown")
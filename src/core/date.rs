@@ -1,4 +1,4 @@
-use chrono::{Datelike, Local, Timelike};
+use chrono::{DateTime, Datelike, Local, Timelike, Utc};
 use iced_date_picker::Date;
 use project_tracker_core::SerializableDate;
 
@@ -22,6 +22,24 @@ impl SerializableDateConversion for SerializableDate {
 	}
 }
 
+/// rough age like '8 months ago'
+pub fn time_ago_str(date_time: DateTime<Utc>) -> String {
+	let elapsed = Utc::now().signed_duration_since(date_time);
+	let (amount, unit) = match elapsed.num_days() {
+		days if days >= 365 => (days / 365, "year"),
+		days if days >= 30 => (days / 30, "month"),
+		days if days >= 1 => (days, "day"),
+		_ if elapsed.num_hours() >= 1 => (elapsed.num_hours(), "hour"),
+		_ if elapsed.num_minutes() >= 1 => (elapsed.num_minutes(), "minute"),
+		_ => return "just now".to_string(),
+	};
+	if amount == 1 {
+		format!("1 {unit} ago")
+	} else {
+		format!("{amount} {unit}s ago")
+	}
+}
+
 pub fn formatted_date_time(date_formatting: DateFormatting) -> String {
 	let now = Local::now();
 	match date_formatting {
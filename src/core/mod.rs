@@ -5,7 +5,7 @@ use project_tracker_core::{ProjectId, TaskId};
 pub use task_tag::TASK_TAG_QUAD_HEIGHT;
 
 mod date;
-pub use date::{formatted_date_time, SerializableDateConversion};
+pub use date::{formatted_date_time, time_ago_str, SerializableDateConversion};
 
 mod project;
 pub use project::{IcedColorConversion, SortModeUI};
@@ -13,14 +13,28 @@ pub use project::{IcedColorConversion, SortModeUI};
 mod task;
 pub use task::TaskDescriptionMarkdownStorage;
 
+mod task_priority;
+pub use task_priority::TaskPriorityUI;
+
 mod database;
 pub use database::{
-	export_database_as_json_file_dialog, export_database_as_markdown_file_dialog,
-	export_database_file_dialog, import_database_file_dialog, import_json_database_file_dialog,
+	export_database_as_icalendar_file_dialog, export_database_as_json_file_dialog,
+	export_database_as_markdown_file_dialog, export_database_file_dialog,
+	import_database_file_dialog, import_json_database_file_dialog, import_markdown_folder_dialog,
 };
 
 mod source_code_todo;
-pub use source_code_todo::import_source_code_todos;
+pub use source_code_todo::{
+	import_source_code_todos, is_linked_source_code_todo_removed, is_source_code_file,
+	is_source_code_todo_promoted, linked_source_code_todo, merge_source_code_todos,
+	rescan_source_code_files, source_code_todo_location, source_code_todo_origin,
+};
+
+mod source_code_todo_watcher;
+pub use source_code_todo_watcher::source_code_todo_watcher_subscription;
+
+mod attachment;
+pub use attachment::{attachment_filepath, store_attachment};
 
 use crate::project_tracker::Message;
 use iced::{advanced::widget, widget::container::Id, Element};
@@ -11,20 +11,29 @@ pub trait SortModeUI {
 }
 
 impl SortModeUI for SortMode {
-	const ALL: &'static [SortMode] = &[SortMode::Manual, SortMode::DueDate, SortMode::NeededTime];
+	const ALL: &'static [SortMode] = &[
+		SortMode::Manual,
+		SortMode::DueDate,
+		SortMode::NeededTime,
+		SortMode::Priority,
+		SortMode::Age,
+	];
 
 	fn as_str(&self) -> &'static str {
 		match self {
 			Self::Manual => "Manual",
 			Self::DueDate => "Due Date",
 			Self::NeededTime => "Needed Time",
+			Self::Priority => "Priority",
+			Self::Age => "Age",
 		}
 	}
 
 	fn icon(&self) -> Bootstrap {
 		match self {
 			Self::Manual => Bootstrap::SortDown,
-			Self::DueDate | Self::NeededTime => Bootstrap::SortNumericDown,
+			Self::DueDate | Self::NeededTime | Self::Age => Bootstrap::SortNumericDown,
+			Self::Priority => Bootstrap::SortUp,
 		}
 	}
 
@@ -41,7 +50,9 @@ impl SortModeUI for SortMode {
 					match (project.get_task(task_id_a), project.get_task(task_id_b)) {
 						(Some(task_a), Some(task_b)) => {
 							match (&task_a.due_date, &task_b.due_date) {
-								(Some(due_date_a), Some(due_date_b)) => due_date_a.cmp(due_date_b),
+								(Some(due_date_a), Some(due_date_b)) => due_date_a
+									.cmp(due_date_b)
+									.then(task_a.due_time.cmp(&task_b.due_time)),
 								(Some(_due_date_a), None) => {
 									if sort_unspecified_tasks_at_bottom {
 										Ordering::Less
@@ -92,6 +103,60 @@ impl SortModeUI for SortMode {
 					}
 				});
 			}
+			Self::Priority => {
+				tasks.sort_by(|task_id_a, task_id_b| {
+					match (project.get_task(task_id_a), project.get_task(task_id_b)) {
+						(Some(task_a), Some(task_b)) => {
+							match (task_a.priority.is_none(), task_b.priority.is_none()) {
+								(false, false) => task_b.priority.cmp(&task_a.priority),
+								(false, true) => {
+									if sort_unspecified_tasks_at_bottom {
+										Ordering::Less
+									} else {
+										Ordering::Greater
+									}
+								}
+								(true, false) => {
+									if sort_unspecified_tasks_at_bottom {
+										Ordering::Greater
+									} else {
+										Ordering::Less
+									}
+								}
+								(true, true) => Ordering::Equal,
+							}
+						}
+						_ => Ordering::Equal,
+					}
+				});
+			}
+			Self::Age => {
+				tasks.sort_by(|task_id_a, task_id_b| {
+					match (project.get_task(task_id_a), project.get_task(task_id_b)) {
+						(Some(task_a), Some(task_b)) => {
+							match (&task_a.source_code_blame, &task_b.source_code_blame) {
+								(Some(blame_a), Some(blame_b)) => blame_a.added.cmp(&blame_b.added),
+								(Some(_blame_a), None) => {
+									if sort_unspecified_tasks_at_bottom {
+										Ordering::Less
+									} else {
+										Ordering::Greater
+									}
+								}
+								(None, Some(_blame_b)) => {
+									if sort_unspecified_tasks_at_bottom {
+										Ordering::Greater
+									} else {
+										Ordering::Less
+									}
+								}
+								(None, None) => Ordering::Equal,
+							}
+						}
+						_ => Ordering::Equal,
+					}
+				});
+			}
 		}
 	}
 }
@@ -1,27 +1,246 @@
-use crate::Task;
-use project_tracker_core::{OrderedHashMap, TaskId};
+use crate::{integrations::git_blame, Task};
+use ignore::{
+	gitignore::{Gitignore, GitignoreBuilder},
+	overrides::{Override, OverrideBuilder},
+	Match, Walk, WalkBuilder,
+};
+use project_tracker_core::{
+	ImportSource, OrderedHashMap, Project, SourceCodeTodoKeyword, SourceCodeTodoSettings, TaskId,
+	TaskOrigin, TaskTagId,
+};
 use rayon::prelude::*;
 use std::{
-	collections::BTreeSet,
-	fs::File,
-	io::{self, BufRead},
+	collections::{BTreeSet, HashMap, HashSet},
 	path::{Path, PathBuf},
 };
-use tracing::debug;
-use walkdir::{DirEntry, WalkDir};
+use tracing::{debug, warn};
 
-pub fn import_source_code_todos(root_directory: PathBuf) -> OrderedHashMap<TaskId, Task> {
-	let todos: Vec<OrderedHashMap<TaskId, Task>> = WalkDir::new(&root_directory)
-		.into_iter()
-		.par_bridge()
-		.filter_map(|e| e.ok())
-		.map(|entry| {
-			if should_import_source_code_todos_from_file(entry.path()) {
-				import_source_code_todos_from_file(entry)
-			} else {
-				OrderedHashMap::new()
+pub fn import_source_code_todos(
+	root_directory: PathBuf,
+	settings: &SourceCodeTodoSettings,
+) -> OrderedHashMap<TaskId, Task> {
+	let source_code_files: Vec<PathBuf> = source_code_files(&root_directory, settings).collect();
+	import_source_code_todos_from_files(&source_code_files, settings)
+}
+
+/// only reads the changed files that would also be imported by 'import_source_code_todos'
+pub fn rescan_source_code_files(
+	root_directory: &Path,
+	settings: &SourceCodeTodoSettings,
+	changed_filepaths: &HashSet<PathBuf>,
+) -> OrderedHashMap<TaskId, Task> {
+	let mut source_code_file_filter = SourceCodeFileFilter::new(root_directory, settings);
+	let changed_source_code_files: Vec<PathBuf> = changed_filepaths
+		.iter()
+		.filter(|filepath| source_code_file_filter.includes(filepath))
+		.cloned()
+		.collect();
+	import_source_code_todos_from_files(&changed_source_code_files, settings)
+}
+
+/// merges the new todos of the rescanned files into the already imported ones.
+/// todos that only moved keep their id and local changes (like the time spend),
+/// they are matched by their identity first and by file and line second
+pub fn merge_source_code_todos(
+	old_todos: &OrderedHashMap<TaskId, Task>,
+	new_todos: OrderedHashMap<TaskId, Task>,
+	is_rescanned: impl Fn(&str) -> bool,
+) -> OrderedHashMap<TaskId, Task> {
+	let old_candidates: Vec<(TaskId, &Task)> = old_todos
+		.iter()
+		.filter(|(_, old_todo)| is_rescanned(source_code_todo_filepath(old_todo)))
+		.collect();
+	let mut reused_candidates = vec![false; old_candidates.len()];
+	let mut matched_candidates: Vec<Option<usize>> = vec![None; new_todos.len()];
+
+	// both got scanned from the same directory, so their absolute paths can be compared
+	let same_todo = |old_todo: &Task, new_todo: &Task| {
+		source_code_todo_identity(old_todo, None) == source_code_todo_identity(new_todo, None)
+	};
+	let same_line = |old_todo: &Task, new_todo: &Task| {
+		let (old_filepath, old_line) = source_code_todo_filepath_and_line(old_todo);
+		let (new_filepath, new_line) = source_code_todo_filepath_and_line(new_todo);
+		old_filepath == new_filepath && old_line.is_some() && old_line == new_line
+	};
+	for matches in [&same_todo as &dyn Fn(&Task, &Task) -> bool, &same_line] {
+		for (new_index, (_, new_todo)) in new_todos.iter().enumerate() {
+			if matched_candidates[new_index].is_some() {
+				continue;
+			}
+			let candidate_index =
+				old_candidates
+					.iter()
+					.enumerate()
+					.position(|(candidate_index, (_, old_todo))| {
+						!reused_candidates[candidate_index] && matches(old_todo, new_todo)
+					});
+			if let Some(candidate_index) = candidate_index {
+				reused_candidates[candidate_index] = true;
+				matched_candidates[new_index] = Some(candidate_index);
+			}
+		}
+	}
+
+	let mut merged_new_todos: Vec<Option<(TaskId, Task)>> = new_todos
+		.iter()
+		.zip(matched_candidates)
+		.map(|((new_task_id, new_todo), candidate_index)| {
+			Some(match candidate_index {
+				Some(candidate_index) => {
+					let (old_task_id, old_todo) = old_candidates[candidate_index];
+					let mut todo = old_todo.clone();
+					todo.name = new_todo.name.clone();
+					todo.description = new_todo.description.clone();
+					todo.tags = new_todo.tags.clone();
+					todo.source_code_blame = new_todo.source_code_blame.clone();
+					(old_task_id, todo)
+				}
+				None => (new_task_id, new_todo.clone()),
+			})
+		})
+		.collect();
+
+	// todos of a rescanned file take the place of its old ones, todos of new files go to the end
+	let mut merged_todos = OrderedHashMap::with_capacity(old_todos.len());
+	let mut insert_todos_of_file = |merged_todos: &mut OrderedHashMap<TaskId, Task>,
+	                                filepath: Option<&str>| {
+		for merged_new_todo in merged_new_todos.iter_mut() {
+			let of_file = merged_new_todo.as_ref().is_some_and(|(_, todo)| {
+				filepath.is_none_or(|filepath| source_code_todo_filepath(todo) == filepath)
+			});
+			if of_file {
+				if let Some((task_id, todo)) = merged_new_todo.take() {
+					merged_todos.insert(task_id, todo);
+				}
 			}
+		}
+	};
+	for (old_task_id, old_todo) in old_todos.iter() {
+		let filepath = source_code_todo_filepath(old_todo);
+		if is_rescanned(filepath) {
+			insert_todos_of_file(&mut merged_todos, Some(filepath));
+		} else {
+			merged_todos.insert(old_task_id, old_todo.clone());
+		}
+	}
+	insert_todos_of_file(&mut merged_todos, None);
+
+	merged_todos
+}
+
+/// the description of a source code todo starts with its 'path:line:column',
+/// followed by the rest of a multiline todo comment
+pub fn source_code_todo_location(task: &Task) -> &str {
+	task.description.lines().next().unwrap_or_default()
+}
+
+/// a source code todo is identified by its file relative to the 'source_code_directory'
+/// and its whitespace and case normalized text,
+/// so it stays the same todo when it moves inside of the file or the directory gets moved
+pub fn source_code_todo_identity(task: &Task, source_code_directory: Option<&Path>) -> String {
+	let text = task
+		.name
+		.split_whitespace()
+		.chain(source_code_todo_body(task).split_whitespace());
+	let filepath = Path::new(source_code_todo_filepath(task));
+	let relative_filepath = source_code_directory
+		.and_then(|source_code_directory| filepath.strip_prefix(source_code_directory).ok())
+		.unwrap_or(filepath);
+	let mut identity = relative_filepath.to_string_lossy().into_owned();
+	identity.push('\n');
+	for (i, word) in text.enumerate() {
+		if i > 0 {
+			identity.push(' ');
+		}
+		identity.push_str(&word.to_lowercase());
+	}
+	identity
+}
+
+pub fn source_code_todo_origin(project: &Project, source_code_todo: &Task) -> TaskOrigin {
+	TaskOrigin::new(
+		ImportSource::SourceCode,
+		source_code_todo_identity(source_code_todo, project.source_code_directory.as_deref()),
+	)
+}
+
+/// the source code todo a task was promoted from, if its comment still exists
+pub fn linked_source_code_todo<'a>(project: &'a Project, task: &Task) -> Option<&'a Task> {
+	let origin = task
+		.origin
+		.as_ref()
+		.filter(|origin| origin.source == ImportSource::SourceCode)?;
+	project.source_code_todos.values().find(|source_code_todo| {
+		source_code_todo_identity(source_code_todo, project.source_code_directory.as_deref())
+			== origin.external_id
+	})
+}
+
+/// the task was promoted from a source code todo whose comment got removed from the code
+pub fn is_linked_source_code_todo_removed(project: &Project, task: &Task) -> bool {
+	project.source_code_directory.is_some()
+		&& task
+			.origin
+			.as_ref()
+			.is_some_and(|origin| origin.source == ImportSource::SourceCode)
+		&& linked_source_code_todo(project, task).is_none()
+}
+
+pub fn is_source_code_todo_promoted(project: &Project, source_code_todo: &Task) -> bool {
+	let origin = source_code_todo_origin(project, source_code_todo);
+	project
+		.todo_tasks
+		.values()
+		.chain(project.done_tasks.values())
+		.any(|task| task.origin.as_ref() == Some(&origin))
+}
+
+/// whether the todos of this file can be imported, regardless of the project settings
+pub fn is_source_code_file(filepath: &Path) -> bool {
+	CommentSyntax::of_file(filepath).is_some()
+}
+
+fn source_code_todo_filepath_and_line(task: &Task) -> (&str, Option<usize>) {
+	let location = source_code_todo_location(task);
+	let mut parts = location.rsplitn(3, ':');
+	let _column = parts.next();
+	match (parts.next(), parts.next()) {
+		(Some(line), Some(filepath)) => (filepath, line.parse().ok()),
+		_ => (location, None),
+	}
+}
+
+fn source_code_todo_filepath(task: &Task) -> &str {
+	source_code_todo_filepath_and_line(task).0
+}
+
+fn source_code_todo_body(task: &Task) -> &str {
+	task.description
+		.split_once('\n')
+		.map_or("", |(_location, body)| body.trim_start_matches('\n'))
+}
+
+fn source_code_files(
+	root_directory: &Path,
+	settings: &SourceCodeTodoSettings,
+) -> impl Iterator<Item = PathBuf> {
+	source_code_files_walker(root_directory, settings)
+		.filter_map(|e| e.ok())
+		.filter(|entry| {
+			entry
+				.file_type()
+				.is_some_and(|file_type| file_type.is_file())
 		})
+		.map(|entry| entry.into_path())
+}
+
+fn import_source_code_todos_from_files(
+	filepaths: &[PathBuf],
+	settings: &SourceCodeTodoSettings,
+) -> OrderedHashMap<TaskId, Task> {
+	let todos: Vec<OrderedHashMap<TaskId, Task>> = filepaths
+		.par_iter()
+		.map(|filepath| import_source_code_todos_from_file(filepath, settings))
 		.collect();
 
 	let mut capacity = 0;
@@ -35,73 +254,208 @@ pub fn import_source_code_todos(root_directory: PathBuf) -> OrderedHashMap<TaskI
 	source_code_todos
 }
 
-fn should_import_source_code_todos_from_folder(folder_path: &Path) -> bool {
-	if !folder_path.is_dir() {
-		return false;
+/// skips hidden files and folders (like '.git'), files that are too big
+/// and everything excluded by the globs or ignore files
+fn source_code_files_walker(root_directory: &Path, settings: &SourceCodeTodoSettings) -> Walk {
+	let mut walk_builder = WalkBuilder::new(root_directory);
+	walk_builder
+		.hidden(true)
+		.ignore(settings.respect_ignore_files)
+		.git_ignore(settings.respect_ignore_files)
+		.git_global(settings.respect_ignore_files)
+		.git_exclude(settings.respect_ignore_files)
+		.parents(settings.respect_ignore_files)
+		.require_git(false)
+		.max_filesize(settings.max_file_size_bytes);
+	if let Some(overrides) = source_code_todo_overrides(root_directory, settings) {
+		walk_builder.overrides(overrides);
 	}
+	walk_builder.build()
+}
 
-	for ancestor in folder_path.ancestors() {
-		if !ancestor.is_dir() {
-			continue;
+fn source_code_todo_overrides(
+	root_directory: &Path,
+	settings: &SourceCodeTodoSettings,
+) -> Option<Override> {
+	let mut overrides = OverrideBuilder::new(root_directory);
+	let globs = settings.include_globs.iter().cloned().chain(
+		settings
+			.exclude_globs
+			.iter()
+			.map(|exclude_glob| format!("!{exclude_glob}")),
+	);
+	for glob in globs {
+		if let Err(e) = overrides.add(&glob) {
+			warn!("invalid source code todo glob '{glob}': {e}");
 		}
-
-		if let Some(folder_name) = ancestor.file_name() {
-			let folder_name_str = folder_name.to_string_lossy();
-			if folder_name_str.starts_with(".") || folder_name_str == "target" {
-				return false;
-			}
+	}
+	match overrides.build() {
+		Ok(overrides) => Some(overrides),
+		Err(e) => {
+			warn!("failed to build source code todo globs: {e}");
+			None
 		}
 	}
+}
 
-	true
+/// decides for single files whether 'source_code_files_walker' would yield them,
+/// so that rescanning a few changed files doesnt need to walk the whole directory
+struct SourceCodeFileFilter<'a> {
+	root_directory: &'a Path,
+	settings: &'a SourceCodeTodoSettings,
+	overrides: Option<Override>,
+	global_gitignore: Gitignore,
+	/// '.gitignore', '.ignore' and '.git/info/exclude' rules of every visited directory
+	directory_gitignores: HashMap<PathBuf, Gitignore>,
 }
 
-fn should_import_source_code_todos_from_file(filepath: &Path) -> bool {
-	if filepath.is_dir() {
-		return false;
+impl<'a> SourceCodeFileFilter<'a> {
+	fn new(root_directory: &'a Path, settings: &'a SourceCodeTodoSettings) -> Self {
+		Self {
+			root_directory,
+			settings,
+			overrides: source_code_todo_overrides(root_directory, settings),
+			global_gitignore: if settings.respect_ignore_files {
+				Gitignore::global().0
+			} else {
+				Gitignore::empty()
+			},
+			directory_gitignores: HashMap::new(),
+		}
+	}
+
+	fn includes(&mut self, filepath: &Path) -> bool {
+		let Ok(relative_filepath) = filepath.strip_prefix(self.root_directory) else {
+			return false;
+		};
+		let is_file_in_size_limit = std::fs::metadata(filepath).is_ok_and(|metadata| {
+			metadata.is_file()
+				&& self
+					.settings
+					.max_file_size_bytes
+					.is_none_or(|max_file_size_bytes| metadata.len() <= max_file_size_bytes)
+		});
+		if !is_file_in_size_limit {
+			return false;
+		}
+
+		// the walker never enters skipped directories
+		let skipped_directory = relative_filepath
+			.ancestors()
+			.skip(1)
+			.filter(|relative_directory| !relative_directory.as_os_str().is_empty())
+			.any(|relative_directory| {
+				let directory = self.root_directory.join(relative_directory);
+				self.is_skipped(&directory, true)
+			});
+		!skipped_directory && !self.is_skipped(filepath, false)
+	}
+
+	/// globs take precedence over ignore files, which take precedence over hiding hidden files
+	fn is_skipped(&mut self, path: &Path, is_dir: bool) -> bool {
+		if let Some(overrides) = &self.overrides {
+			match overrides.matched(path, is_dir) {
+				Match::Ignore(_) => return true,
+				Match::Whitelist(_) => return false,
+				Match::None => {}
+			}
+		}
+		if self.settings.respect_ignore_files {
+			match self.ignore_files_match(path, is_dir) {
+				Match::Ignore(()) => return true,
+				Match::Whitelist(()) => return false,
+				Match::None => {}
+			}
+		}
+		path.file_name()
+			.is_some_and(|file_name| file_name.to_string_lossy().starts_with('.'))
+	}
+
+	/// ignore files of deeper directories take precedence
+	fn ignore_files_match(&mut self, path: &Path, is_dir: bool) -> Match<()> {
+		for directory in path.ancestors().skip(1) {
+			let gitignore = self
+				.directory_gitignores
+				.entry(directory.to_path_buf())
+				.or_insert_with(|| directory_gitignore(directory));
+			let ignore_match = gitignore.matched(path, is_dir).map(|_| ());
+			if !ignore_match.is_none() {
+				return ignore_match;
+			}
+		}
+		self.global_gitignore.matched(path, is_dir).map(|_| ())
 	}
+}
 
-	match filepath.parent() {
-		Some(parent_path) => should_import_source_code_todos_from_folder(parent_path),
-		None => true,
+fn directory_gitignore(directory: &Path) -> Gitignore {
+	let mut gitignore_builder = GitignoreBuilder::new(directory);
+	// later files take precedence
+	for ignore_filename in [".git/info/exclude", ".gitignore", ".ignore"] {
+		let ignore_filepath = directory.join(ignore_filename);
+		if ignore_filepath.is_file() {
+			if let Some(e) = gitignore_builder.add(&ignore_filepath) {
+				warn!("invalid ignore file '{}': {e}", ignore_filepath.display());
+			}
+		}
 	}
+	gitignore_builder.build().unwrap_or_else(|e| {
+		warn!(
+			"failed to build ignore rules of '{}': {e}",
+			directory.display()
+		);
+		Gitignore::empty()
+	})
 }
 
-// TODO: support multiline comments (--> 2.. lines into description?)
-fn import_source_code_todos_from_file(entry: DirEntry) -> OrderedHashMap<TaskId, Task> {
-	let filepath = entry.path();
+fn import_source_code_todos_from_file(
+	filepath: &Path,
+	settings: &SourceCodeTodoSettings,
+) -> OrderedHashMap<TaskId, Task> {
+	let Some(syntax) = CommentSyntax::of_file(filepath) else {
+		return OrderedHashMap::new();
+	};
 
-	match File::open(filepath) {
-		Ok(file) => {
+	// non utf-8 files are most likely not source code
+	match std::fs::read_to_string(filepath) {
+		Ok(content) => {
+			let source = filepath.display();
 			let mut todos = OrderedHashMap::new();
 
-			for (line_index, line) in io::BufReader::new(file)
-				.lines()
-				.map_while(Result::ok)
-				.enumerate()
-			{
-				if let Some((name, column_number)) = import_source_code_comment_from_line(line) {
-					let source = entry.path().display();
-					let line_number = line_index + 1;
-					todos.insert(
-						TaskId::generate(),
-						Task::new(
-							name,
-							format!("{source}:{line_number}:{column_number}"),
-							None,
-							None,
-							None,
-							BTreeSet::new(),
-						),
-					);
+			let source_code_todos = scan_source_code_todos(&content, syntax, &settings.keywords);
+			let blames = if settings.git_blame && !source_code_todos.is_empty() {
+				git_blame(filepath).unwrap_or_else(|e| {
+					debug!("no git blame of '{}': {e}", filepath.display());
+					HashMap::new()
+				})
+			} else {
+				HashMap::new()
+			};
+
+			for todo in source_code_todos {
+				let source_code_blame = blames.get(&todo.line_number).cloned();
+				let mut description =
+					format!("{source}:{}:{}", todo.line_number, todo.column_number);
+				if !todo.description.is_empty() {
+					description.push_str("\n\n");
+					description.push_str(&todo.description);
 				}
+				let mut task = Task::new(
+					todo.name,
+					description,
+					None,
+					None,
+					None,
+					todo.task_tag.into_iter().collect::<BTreeSet<_>>(),
+				);
+				task.source_code_blame = source_code_blame;
+				todos.insert(TaskId::generate(), task);
 			}
 
 			todos
 		}
 		Err(_) => {
 			debug!(
-				"could not open source code file in '{}'",
+				"could not read source code file in '{}'",
 				filepath.display()
 			);
 			OrderedHashMap::new()
@@ -109,63 +463,861 @@ fn import_source_code_todos_from_file(entry: DirEntry) -> OrderedHashMap<TaskId,
 	}
 }
 
-// (todo_name, column_number)
-fn import_source_code_comment_from_line(line: String) -> Option<(String, usize)> {
-	let line_lowercase = line.to_lowercase();
+/// how comments and string literals look like in a language
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CommentSyntax {
+	line_comments: &'static [&'static str],
+	/// (start, end), docstrings are treated like block comments
+	block_comments: &'static [(&'static str, &'static str)],
+	nested_block_comments: bool,
+	string_delimiters: &'static [&'static str],
+	/// lifetimes, char and raw string literals of rust
+	rust_literals: bool,
+	/// rusts 'todo!("...")' macro
+	todo_macro: bool,
+}
+
+impl CommentSyntax {
+	const C_LIKE: Self = Self {
+		line_comments: &["//"],
+		block_comments: &[("/*", "*/")],
+		nested_block_comments: false,
+		string_delimiters: &["\"", "'"],
+		rust_literals: false,
+		todo_macro: false,
+	};
+
+	const JAVASCRIPT_LIKE: Self = Self {
+		string_delimiters: &["\"", "'", "`"],
+		..Self::C_LIKE
+	};
+
+	const RUST: Self = Self {
+		line_comments: &["//"],
+		block_comments: &[("/*", "*/")],
+		nested_block_comments: true,
+		string_delimiters: &["\""],
+		rust_literals: true,
+		todo_macro: true,
+	};
+
+	const PYTHON: Self = Self {
+		line_comments: &["#"],
+		block_comments: &[("\"\"\"", "\"\"\""), ("'''", "'''")],
+		nested_block_comments: false,
+		string_delimiters: &["\"", "'"],
+		rust_literals: false,
+		todo_macro: false,
+	};
+
+	const HASH: Self = Self {
+		line_comments: &["#"],
+		block_comments: &[],
+		nested_block_comments: false,
+		string_delimiters: &["\"", "'"],
+		rust_literals: false,
+		todo_macro: false,
+	};
+
+	const MARKUP: Self = Self {
+		line_comments: &[],
+		block_comments: &[("<!--", "-->")],
+		nested_block_comments: false,
+		string_delimiters: &[],
+		rust_literals: false,
+		todo_macro: false,
+	};
+
+	const CSS: Self = Self {
+		line_comments: &[],
+		..Self::C_LIKE
+	};
+
+	const SQL: Self = Self {
+		line_comments: &["--"],
+		block_comments: &[("/*", "*/")],
+		nested_block_comments: false,
+		string_delimiters: &["'"],
+		rust_literals: false,
+		todo_macro: false,
+	};
+
+	const LUA: Self = Self {
+		line_comments: &["--"],
+		block_comments: &[("--[[", "]]")],
+		nested_block_comments: false,
+		string_delimiters: &["\"", "'"],
+		rust_literals: false,
+		todo_macro: false,
+	};
+
+	const HASKELL: Self = Self {
+		line_comments: &["--"],
+		block_comments: &[("{-", "-}")],
+		nested_block_comments: true,
+		string_delimiters: &["\""],
+		rust_literals: false,
+		todo_macro: false,
+	};
+
+	const LISP: Self = Self {
+		line_comments: &[";"],
+		block_comments: &[],
+		nested_block_comments: false,
+		string_delimiters: &["\""],
+		rust_literals: false,
+		todo_macro: false,
+	};
+
+	fn of_file(filepath: &Path) -> Option<Self> {
+		let file_name = filepath.file_name()?.to_string_lossy();
+		match file_name.as_ref() {
+			"Makefile" | "makefile" | "Dockerfile" | "CMakeLists.txt" | "Justfile" | "justfile" => {
+				return Some(Self::HASH)
+			}
+			_ => {}
+		}
+
+		let extension = filepath.extension()?.to_string_lossy().to_lowercase();
+		match extension.as_str() {
+			"rs" => Some(Self::RUST),
+			"c" | "h" | "cc" | "cpp" | "cxx" | "hpp" | "hh" | "hxx" | "cs" | "java" | "kt"
+			| "kts" | "swift" | "scala" | "dart" | "zig" | "glsl" | "wgsl" | "hlsl" | "proto"
+			| "scss" | "less" | "sass" => Some(Self::C_LIKE),
+			"js" | "mjs" | "cjs" | "jsx" | "ts" | "mts" | "cts" | "tsx" | "go" => {
+				Some(Self::JAVASCRIPT_LIKE)
+			}
+			"php" => Some(Self {
+				line_comments: &["//", "#"],
+				..Self::C_LIKE
+			}),
+			"py" | "pyi" => Some(Self::PYTHON),
+			"sh" | "bash" | "zsh" | "fish" | "rb" | "pl" | "pm" | "r" | "yml" | "yaml" | "toml"
+			| "cmake" | "mk" | "ex" | "exs" | "nim" | "ps1" | "tf" | "conf" => Some(Self::HASH),
+			"nix" => Some(Self {
+				block_comments: &[("/*", "*/")],
+				..Self::HASH
+			}),
+			"html" | "htm" | "xml" | "svg" | "vue" | "svelte" | "md" | "markdown" => {
+				Some(Self::MARKUP)
+			}
+			"css" => Some(Self::CSS),
+			"sql" => Some(Self::SQL),
+			"lua" => Some(Self::LUA),
+			"hs" | "elm" => Some(Self::HASKELL),
+			"lisp" | "el" | "clj" | "cljs" | "scm" | "rkt" | "asm" | "s" | "ini" => {
+				Some(Self::LISP)
+			}
+			_ => None,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SourceCodeTodo {
+	name: String,
+	/// the following lines of a multiline todo comment
+	description: String,
+	line_number: usize,
+	column_number: usize,
+	task_tag: Option<TaskTagId>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CommentKind {
+	Line {
+		marker: &'static str,
+		/// no code in front of the comment
+		whole_line: bool,
+	},
+	/// lines of the same block comment have the same index
+	Block(usize),
+}
+
+/// one line of a comment, without its start and end markers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CommentLine<'a> {
+	text: &'a str,
+	line_number: usize,
+	column_number: usize,
+	kind: CommentKind,
+}
+
+struct Scanner<'a> {
+	content: &'a str,
+	syntax: CommentSyntax,
+	index: usize,
+	line_number: usize,
+	line_start: usize,
+	block_comment_count: usize,
+	comment_lines: Vec<CommentLine<'a>>,
+	todo_macros: Vec<SourceCodeTodo>,
+}
+
+impl<'a> Scanner<'a> {
+	fn new(content: &'a str, syntax: CommentSyntax) -> Self {
+		Self {
+			content,
+			syntax,
+			index: 0,
+			line_number: 1,
+			line_start: 0,
+			block_comment_count: 0,
+			comment_lines: Vec::new(),
+			todo_macros: Vec::new(),
+		}
+	}
+
+	fn rest(&self) -> &'a str {
+		&self.content[self.index..]
+	}
+
+	fn column_number(&self, index: usize) -> usize {
+		self.content[self.line_start..index].chars().count() + 1
+	}
+
+	fn previous_char_is_identifier(&self) -> bool {
+		self.content[..self.index]
+			.chars()
+			.next_back()
+			.is_some_and(|c| c.is_alphanumeric() || c == '_')
+	}
+
+	/// advances by one char, keeping track of the line
+	fn advance(&mut self) {
+		if let Some(c) = self.rest().chars().next() {
+			self.index += c.len_utf8();
+			if c == '\n' {
+				self.line_number += 1;
+				self.line_start = self.index;
+			}
+		}
+	}
+
+	fn scan(mut self) -> (Vec<CommentLine<'a>>, Vec<SourceCodeTodo>) {
+		while self.index < self.content.len() {
+			let rest = self.rest();
+
+			// block comments first: lua's '--[[' and python's '"""' would otherwise start a line comment or string
+			if let Some((start, end)) = self
+				.syntax
+				.block_comments
+				.iter()
+				.find(|(start, _)| rest.starts_with(start))
+			{
+				self.scan_block_comment(start, end);
+			} else if let Some(marker) = self
+				.syntax
+				.line_comments
+				.iter()
+				.find(|marker| rest.starts_with(*marker))
+			{
+				self.scan_line_comment(marker);
+			} else if self.syntax.rust_literals && self.scan_rust_literal() {
+				continue;
+			} else if let Some(delimiter) = self
+				.syntax
+				.string_delimiters
+				.iter()
+				.find(|delimiter| rest.starts_with(*delimiter))
+			{
+				self.skip_string(delimiter);
+			} else if self.syntax.todo_macro
+				&& rest.starts_with(TODO_MACRO_START)
+				&& !self.previous_char_is_identifier()
+			{
+				self.scan_todo_macro();
+			} else {
+				self.advance();
+			}
+		}
+		(self.comment_lines, self.todo_macros)
+	}
 
-	let search_todo_comment = |keyword: &'static str| {
-		if let Some(index) = line_lowercase.find(&keyword.to_lowercase()) {
-			let mut string_quotes_counter = 0;
-			for c in line[0..index].chars() {
-				if c == '\"' || c == '\'' {
-					string_quotes_counter += 1;
+	fn scan_line_comment(&mut self, marker: &'static str) {
+		let text_start = self.index + marker.len();
+		let text_end = self.content[text_start..]
+			.find('\n')
+			.map_or(self.content.len(), |i| text_start + i);
+		let text = self.content[text_start..text_end].trim_end_matches('\r');
+
+		self.comment_lines.push(CommentLine {
+			text,
+			line_number: self.line_number,
+			column_number: self.column_number(text_start),
+			kind: CommentKind::Line {
+				marker,
+				whole_line: self.content[self.line_start..self.index].trim().is_empty(),
+			},
+		});
+		self.index = text_end;
+	}
+
+	fn scan_block_comment(&mut self, start: &str, end: &str) {
+		let block_index = self.block_comment_count;
+		self.block_comment_count += 1;
+
+		self.index += start.len();
+		let mut depth = 1;
+		let mut line_text_start = self.index;
+
+		let push_line = |scanner: &mut Self, text_start: usize, text_end: usize| {
+			scanner.comment_lines.push(CommentLine {
+				text: scanner.content[text_start..text_end].trim_end_matches('\r'),
+				line_number: scanner.line_number,
+				column_number: scanner.column_number(text_start),
+				kind: CommentKind::Block(block_index),
+			});
+		};
+
+		while self.index < self.content.len() {
+			let rest = self.rest();
+			if rest.starts_with(end) {
+				depth -= 1;
+				if depth == 0 {
+					push_line(self, line_text_start, self.index);
+					self.index += end.len();
+					return;
 				}
+				self.index += end.len();
+			} else if self.syntax.nested_block_comments && rest.starts_with(start) {
+				depth += 1;
+				self.index += start.len();
+			} else if rest.starts_with('\n') {
+				push_line(self, line_text_start, self.index);
+				self.advance();
+				line_text_start = self.index;
+			} else {
+				self.advance();
 			}
+		}
 
-			if string_quotes_counter % 2 == 0 {
-				let todo_name = line[index + keyword.len() + 1..].to_string();
-				let todo_name = todo_name.strip_prefix(':').unwrap_or(&todo_name);
-				let todo_name = todo_name.strip_prefix(' ').unwrap_or(todo_name);
-				let column_number = index + 1;
-				return Some((todo_name.to_string(), column_number));
+		// unterminated block comment
+		push_line(self, line_text_start, self.index);
+	}
+
+	fn skip_string(&mut self, delimiter: &str) {
+		self.index += delimiter.len();
+		while self.index < self.content.len() {
+			let rest = self.rest();
+			if rest.starts_with('\\') {
+				self.advance();
+				self.advance();
+			} else if rest.starts_with(delimiter) {
+				self.index += delimiter.len();
+				return;
+			} else if rest.starts_with('\n') && delimiter != "`" && !self.syntax.rust_literals {
+				// an unterminated string literal shouldn't hide the rest of the file
+				return;
+			} else {
+				self.advance();
 			}
 		}
-		None
-	};
+	}
+
+	/// skips raw strings and char literals, returns whether something was skipped
+	fn scan_rust_literal(&mut self) -> bool {
+		let rest = self.rest();
+
+		if !self.previous_char_is_identifier() {
+			let raw_string = rest
+				.strip_prefix("br")
+				.or_else(|| rest.strip_prefix('r'))
+				.map(|raw_string| {
+					let hashes = raw_string.len() - raw_string.trim_start_matches('#').len();
+					(raw_string, hashes)
+				})
+				.filter(|(raw_string, hashes)| raw_string[*hashes..].starts_with('"'));
 
-	let search_todo_macro = {
-		const TODO_MACRO_KEYWORD_START: &str = "todo!(";
-		if let Some(index) = line_lowercase.find(TODO_MACRO_KEYWORD_START) {
-			let mut string_quotes_counter = 0;
-			for c in line[0..index].chars() {
-				if c == '\"' || c == '\'' {
-					string_quotes_counter += 1;
+			if let Some((raw_string, hashes)) = raw_string {
+				let content_start = self.index + (rest.len() - raw_string.len()) + hashes + 1;
+				let terminator = format!("\"{}", "#".repeat(hashes));
+				let content_end = self.content[content_start..]
+					.find(&terminator)
+					.map_or(self.content.len(), |i| content_start + i + terminator.len());
+				while self.index < content_end {
+					self.advance();
 				}
+				return true;
 			}
-			if string_quotes_counter % 2 == 0 {
-				let todo_macro_arg_start_index = index + TODO_MACRO_KEYWORD_START.len();
-				let todo_name = match line.chars().nth(todo_macro_arg_start_index) {
-					Some('\"') => {
-						let todo_name_start_index = todo_macro_arg_start_index + 1;
-						let mut todo_name_end_index = todo_name_start_index;
-						if let Some(i) = line[todo_name_start_index..].find('\"') {
-							todo_name_end_index = todo_name_start_index + i;
+		}
+
+		if let Some(char_literal) = rest.strip_prefix('\'') {
+			let mut chars = char_literal.chars();
+			let literal_len = match (chars.next(), chars.next()) {
+				// '\n', '\'', '\u{1F600}'
+				(Some('\\'), _) => char_literal[1..]
+					.find('\'')
+					.map(|i| 1 + 1 + i + 1)
+					.unwrap_or(1),
+				// 'a'
+				(Some(c), Some('\'')) => 1 + c.len_utf8() + 1,
+				// lifetime
+				_ => 1,
+			};
+			for _ in 0..self.content[self.index..self.index + literal_len]
+				.chars()
+				.count()
+			{
+				self.advance();
+			}
+			return true;
+		}
+
+		false
+	}
+
+	fn scan_todo_macro(&mut self) {
+		let line_number = self.line_number;
+		let column_number = self.column_number(self.index);
+		self.index += TODO_MACRO_START.len();
+
+		let argument = self.rest().trim_start();
+		let name = match argument.strip_prefix('"') {
+			Some(string) => {
+				let mut name = String::new();
+				let mut chars = string.chars();
+				while let Some(c) = chars.next() {
+					match c {
+						'"' => break,
+						'\\' => {
+							if let Some(escaped) = chars.next() {
+								name.push(escaped);
+							}
 						}
-						line[todo_name_start_index..todo_name_end_index].to_string()
+						c => name.push(c),
 					}
-					_ => String::new(),
+				}
+				name
+			}
+			None => String::new(),
+		};
+
+		self.todo_macros.push(SourceCodeTodo {
+			name,
+			description: String::new(),
+			line_number,
+			column_number,
+			task_tag: None,
+		});
+	}
+}
+
+const TODO_MACRO_START: &str = "todo!(";
+
+/// leading whitespace and comment markers like in '///', '//!', ' * ' or ';;;'
+fn comment_decoration_len(text: &str) -> usize {
+	text.len()
+		- text
+			.trim_start_matches(|c: char| c.is_whitespace() || "/!*#;".contains(c))
+			.len()
+}
+
+/// returns the matched keyword, the name of the todo and its byte offset in the comment line, case insensitive
+/// supports 'TODO', 'TODO: name', 'todo name' and 'TODO(author): name'
+fn find_todo_keyword<'a>(
+	text: &str,
+	keywords: &'a [SourceCodeTodoKeyword],
+) -> Option<(&'a SourceCodeTodoKeyword, String, usize)> {
+	let offset = comment_decoration_len(text);
+	let candidate = &text[offset..];
+
+	let (keyword, after_keyword) = keywords.iter().find_map(|keyword| {
+		let keyword_len = keyword.keyword.len();
+		if keyword_len == 0
+			|| !candidate
+				.get(..keyword_len)?
+				.eq_ignore_ascii_case(&keyword.keyword)
+		{
+			return None;
+		}
+		let after_keyword = &candidate[keyword_len..];
+		if after_keyword
+			.chars()
+			.next()
+			.is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '!')
+		{
+			return None;
+		}
+		Some((keyword, after_keyword))
+	})?;
+
+	let after_keyword = match after_keyword.strip_prefix('(') {
+		Some(author) => author.find(')').map_or(after_keyword, |i| &author[i + 1..]),
+		None => after_keyword,
+	};
+	let name = after_keyword
+		.trim_start()
+		.strip_prefix(':')
+		.unwrap_or(after_keyword)
+		.trim();
+
+	Some((keyword, name.to_string(), offset))
+}
+
+/// a todo in a line comment continues in the following line comments, as long as they are indented deeper:
+/// ```text
+/// // TODO: first line
+/// //   second line
+/// ```
+/// a todo in a block comment continues until an empty line or the end of the comment
+fn scan_source_code_todos(
+	content: &str,
+	syntax: CommentSyntax,
+	keywords: &[SourceCodeTodoKeyword],
+) -> Vec<SourceCodeTodo> {
+	// 'todo!()' only counts when 'TODO' is one of the keywords
+	let todo_macro_keyword = keywords
+		.iter()
+		.find(|keyword| keyword.keyword.eq_ignore_ascii_case("todo"));
+	let syntax = CommentSyntax {
+		todo_macro: syntax.todo_macro && todo_macro_keyword.is_some(),
+		..syntax
+	};
+
+	let (comment_lines, mut todos) = Scanner::new(content, syntax).scan();
+	for todo in todos.iter_mut() {
+		todo.task_tag = todo_macro_keyword.and_then(|keyword| keyword.task_tag);
+	}
+
+	let mut index = 0;
+	while index < comment_lines.len() {
+		let comment_line = comment_lines[index];
+		index += 1;
+
+		let Some((keyword, name, offset)) = find_todo_keyword(comment_line.text, keywords) else {
+			continue;
+		};
+
+		let mut description_lines = Vec::new();
+		let mut previous_line_number = comment_line.line_number;
+		while let Some(next_line) = comment_lines.get(index) {
+			if next_line.line_number != previous_line_number + 1
+				|| find_todo_keyword(next_line.text, keywords).is_some()
+			{
+				break;
+			}
+			let decoration_len = comment_decoration_len(next_line.text);
+			let next_text = next_line.text[decoration_len..].trim_end();
+			let continues = !next_text.is_empty()
+				&& match (comment_line.kind, next_line.kind) {
+					(CommentKind::Block(block), CommentKind::Block(next_block)) => {
+						block == next_block
+					}
+					(
+						CommentKind::Line { marker, .. },
+						CommentKind::Line {
+							marker: next_marker,
+							whole_line: true,
+						},
+					) => marker == next_marker && decoration_len > offset,
+					_ => false,
 				};
-				let column_number = index + 1;
-				return Some((todo_name.to_string(), column_number));
+			if !continues {
+				break;
 			}
+			description_lines.push(next_text);
+			previous_line_number = next_line.line_number;
+			index += 1;
 		}
-		None
+
+		todos.push(SourceCodeTodo {
+			name,
+			description: description_lines.join("\n"),
+			line_number: comment_line.line_number,
+			column_number: comment_line.column_number + comment_line.text[..offset].chars().count(),
+			task_tag: keyword.task_tag,
+		});
+	}
+
+	todos.sort_by_key(|todo| (todo.line_number, todo.column_number));
+	todos
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use super::{
+		merge_source_code_todos, scan_source_code_todos, source_code_files,
+		source_code_todo_identity, CommentSyntax, SourceCodeFileFilter, SourceCodeTodo,
+	};
+	use project_tracker_core::{
+		OrderedHashMap, SourceCodeTodoKeyword, SourceCodeTodoSettings, Task, TaskId, TaskTagId,
 	};
+	use std::{
+		collections::{BTreeSet, HashSet},
+		path::{Path, PathBuf},
+	};
+
+	fn default_keywords() -> Vec<SourceCodeTodoKeyword> {
+		SourceCodeTodoSettings::default().keywords
+	}
 
-	// case insensitive!
-	search_todo_comment("// todo")
-		.or(search_todo_comment("//todo"))
-		.or(search_todo_comment("# todo"))
-		.or(search_todo_comment("#todo"))
-		.or(search_todo_macro)
+	fn todo(
+		name: &str,
+		description: &str,
+		line_number: usize,
+		column_number: usize,
+	) -> SourceCodeTodo {
+		SourceCodeTodo {
+			name: name.to_string(),
+			description: description.to_string(),
+			line_number,
+			column_number,
+			task_tag: None,
+		}
+	}
+
+	#[test]
+	fn test_rust_todos() {
+		let content = r##"fn main() {
+	// TODO: first line
+	//   second line
+	// not part of the todo anymore
+	let s = "// todo: inside of a string";
+	let r = r#"/* todo: inside of a raw string */"#;
+	let c = '"'; // todo(alice) after a char literal
+	fn f<'a>(x: &'a str) {} // TODO lifetimes are no strings
+	/* outer /* nested */ still a comment
+	   todo: in a nested block comment */
+	/// TODO documentation
+	let todo_list = Vec::new();
+	todo!("implement \"this\"");
+	todo!();
+}
+"##;
+		let todos = scan_source_code_todos(content, CommentSyntax::RUST, &default_keywords());
+		assert_eq!(
+			todos,
+			vec![
+				todo("first line", "second line", 2, 5),
+				todo("after a char literal", "", 7, 18),
+				todo("lifetimes are no strings", "", 8, 29),
+				todo("in a nested block comment", "", 10, 5),
+				todo("documentation", "", 11, 6),
+				todo("implement \"this\"", "", 13, 2),
+				todo("", "", 14, 2),
+			]
+		);
+	}
+
+	#[test]
+	fn test_block_comment_and_docstring_todos() {
+		let c_content = "/*\n * TODO: refactor\n * the whole thing\n *\n * unrelated\n */\nint main() { return 0; }\n";
+		assert_eq!(
+			scan_source_code_todos(c_content, CommentSyntax::C_LIKE, &default_keywords()),
+			vec![todo("refactor", "the whole thing", 2, 4)]
+		);
+
+		let python_content = "def f():\n    \"\"\"TODO: docstring todo\n    more details\n    \"\"\"\n    s = '# todo not a comment'\n    # FIXME: not a todo\n    #todo: comment\n";
+		assert_eq!(
+			scan_source_code_todos(python_content, CommentSyntax::PYTHON, &default_keywords()),
+			vec![
+				todo("docstring todo", "more details", 2, 8),
+				todo("comment", "", 7, 6),
+			]
+		);
+
+		let html_content = "<p>todo: not a comment</p>\n<!-- TODO: fix layout -->\n";
+		assert_eq!(
+			scan_source_code_todos(html_content, CommentSyntax::MARKUP, &default_keywords()),
+			vec![todo("fix layout", "", 2, 6)]
+		);
+
+		let sql_content = "SELECT '-- todo: string' FROM t; -- todo: add index\n";
+		assert_eq!(
+			scan_source_code_todos(sql_content, CommentSyntax::SQL, &default_keywords()),
+			vec![todo("add index", "", 1, 37)]
+		);
+	}
+
+	#[test]
+	fn test_custom_keywords() {
+		let fixme_tag_id = TaskTagId::generate();
+		let keywords = vec![
+			SourceCodeTodoKeyword::new("FIXME".to_string(), Some(fixme_tag_id)),
+			SourceCodeTodoKeyword::new("hack".to_string(), None),
+		];
+		let content = "// FIXME: overflow\n// HACK until upstream is fixed\n// TODO: not a keyword here\n// fixmeplease\ntodo!(\"no todo keyword\");\n";
+		let todos = scan_source_code_todos(content, CommentSyntax::RUST, &keywords);
+		assert_eq!(
+			todos,
+			vec![
+				SourceCodeTodo {
+					task_tag: Some(fixme_tag_id),
+					..todo("overflow", "", 1, 4)
+				},
+				todo("until upstream is fixed", "", 2, 4),
+			]
+		);
+	}
+
+	#[test]
+	fn test_merge_keeps_ids_of_moved_todos() {
+		let source_code_todo = |name: &str, location: &str| {
+			Task::new(
+				name.to_string(),
+				location.to_string(),
+				None,
+				None,
+				None,
+				BTreeSet::new(),
+			)
+		};
+
+		let first_id = TaskId::generate();
+		let second_id = TaskId::generate();
+		let other_id = TaskId::generate();
+		let mut old_todos = OrderedHashMap::new();
+		old_todos.insert(first_id, source_code_todo("first", "/src/a.rs:1:4"));
+		let mut second = source_code_todo("second", "/src/a.rs:5:4");
+		second.needed_time_minutes = Some(30);
+		old_todos.insert(second_id, second);
+		old_todos.insert(other_id, source_code_todo("other", "/src/b.rs:1:4"));
+
+		let third_id = TaskId::generate();
+		let mut new_todos = OrderedHashMap::new();
+		new_todos.insert(
+			TaskId::generate(),
+			source_code_todo("first renamed", "/src/a.rs:1:4"),
+		);
+		new_todos.insert(
+			TaskId::generate(),
+			source_code_todo("second", "/src/a.rs:7:4"),
+		);
+		new_todos.insert(third_id, source_code_todo("third", "/src/a.rs:9:4"));
+
+		let merged_todos =
+			merge_source_code_todos(&old_todos, new_todos, |filepath| filepath == "/src/a.rs");
+
+		let merged: Vec<(TaskId, &str)> = merged_todos
+			.iter()
+			.map(|(task_id, todo)| (task_id, todo.description.as_str()))
+			.collect();
+		assert_eq!(
+			merged,
+			vec![
+				(first_id, "/src/a.rs:1:4"),
+				(second_id, "/src/a.rs:7:4"),
+				(third_id, "/src/a.rs:9:4"),
+				(other_id, "/src/b.rs:1:4"),
+			]
+		);
+		let second = merged_todos.get(&second_id).unwrap();
+		assert_eq!(second.needed_time_minutes, Some(30));
+		assert_eq!(merged_todos.get(&first_id).unwrap().name, "first renamed");
+	}
+
+	#[test]
+	fn test_filter_matches_walked_source_code_files() {
+		let root_directory = std::env::temp_dir().join("tmp_test_source_code_file_filter");
+		let _ = std::fs::remove_dir_all(&root_directory);
+		let files = [
+			("src/main.rs", "// TODO: main"),
+			("src/generated/mod.rs", ""),
+			("src/generated/keep.rs", ""),
+			(
+				"src/big.rs",
+				"// this file is bigger than the max file size",
+			),
+			("src/.hidden.rs", ""),
+			("target/debug/build.rs", ""),
+			("notes/todo.md", ""),
+			(".gitignore", "generated/\n"),
+			("src/.ignore", "!generated/\ngenerated/mod.rs\n"),
+		];
+		for (relative_filepath, content) in files {
+			let filepath = root_directory.join(relative_filepath);
+			std::fs::create_dir_all(filepath.parent().unwrap()).unwrap();
+			std::fs::write(filepath, content).unwrap();
+		}
+
+		let mut settings = SourceCodeTodoSettings {
+			max_file_size_bytes: Some(20),
+			..Default::default()
+		};
+		for include_globs in [Vec::new(), vec!["*.rs".to_string()]] {
+			settings.include_globs = include_globs;
+			let walked_filepaths: HashSet<PathBuf> =
+				source_code_files(&root_directory, &settings).collect();
+			let mut filter = SourceCodeFileFilter::new(&root_directory, &settings);
+			let filtered_filepaths: HashSet<PathBuf> = files
+				.iter()
+				.map(|(relative_filepath, _)| root_directory.join(relative_filepath))
+				.filter(|filepath| filter.includes(filepath))
+				.collect();
+			assert_eq!(filtered_filepaths, walked_filepaths);
+			assert!(filtered_filepaths.contains(&root_directory.join("src/generated/keep.rs")));
+		}
+
+		std::fs::remove_dir_all(&root_directory).unwrap();
+	}
+
+	#[test]
+	fn test_source_code_todo_identity() {
+		let source_code_todo = |name: &str, description: &str| {
+			Task::new(
+				name.to_string(),
+				description.to_string(),
+				None,
+				None,
+				None,
+				BTreeSet::new(),
+			)
+		};
+
+		let source_code_directory = Some(Path::new("/home/user/project"));
+		let identity = source_code_todo_identity(
+			&source_code_todo(
+				"Handle  errors",
+				"/home/user/project/src/a.rs:3:4\n\nof the parser",
+			),
+			source_code_directory,
+		);
+		assert_eq!(identity, "src/a.rs\nhandle errors of the parser");
+		assert_eq!(
+			source_code_todo_identity(
+				&source_code_todo(
+					"handle errors",
+					"/home/user/project/src/a.rs:42:8\n\nof the  Parser"
+				),
+				source_code_directory
+			),
+			identity
+		);
+		assert_ne!(
+			source_code_todo_identity(
+				&source_code_todo(
+					"handle errors",
+					"/home/user/project/src/b.rs:3:4\n\nof the parser"
+				),
+				source_code_directory
+			),
+			identity
+		);
+		// the same todo after the directory got moved
+		assert_eq!(
+			source_code_todo_identity(
+				&source_code_todo(
+					"handle errors",
+					"/mnt/project/src/a.rs:3:4\n\nof the parser"
+				),
+				Some(Path::new("/mnt/project"))
+			),
+			identity
+		);
+	}
+
+	#[test]
+	fn test_comment_syntax_of_file() {
+		assert_eq!(
+			CommentSyntax::of_file(Path::new("src/main.rs")),
+			Some(CommentSyntax::RUST)
+		);
+		assert_eq!(
+			CommentSyntax::of_file(Path::new("Makefile")),
+			Some(CommentSyntax::HASH)
+		);
+		assert_eq!(CommentSyntax::of_file(Path::new("image.png")), None);
+	}
 }
@@ -0,0 +1,90 @@
+use crate::{core::is_source_code_file, project_tracker::Message};
+use async_watcher::{notify::RecursiveMode, AsyncDebouncer};
+use iced::{
+	futures::{SinkExt, Stream},
+	stream, Subscription,
+};
+use project_tracker_core::ProjectId;
+use std::{
+	collections::HashSet,
+	path::{Path, PathBuf},
+	time::Duration,
+};
+use tracing::error;
+
+/// rescans the changed source code files of the project, see 'Message::SourceCodeFilesChanged'
+pub fn source_code_todo_watcher_subscription(
+	project_id: ProjectId,
+	source_code_directory: PathBuf,
+) -> Subscription<Message> {
+	Subscription::run_with_id(
+		(project_id, source_code_directory.clone()),
+		watch_source_code_directory(project_id, source_code_directory),
+	)
+}
+
+fn watch_source_code_directory(
+	project_id: ProjectId,
+	source_code_directory: PathBuf,
+) -> impl Stream<Item = Message> {
+	stream::channel(100, move |mut output| async move {
+		let result = AsyncDebouncer::new_with_channel(
+			Duration::from_millis(500),
+			Some(Duration::from_millis(500)),
+		)
+		.await;
+
+		match result {
+			Ok((mut debouncer, mut file_events)) => {
+				if let Err(e) = debouncer
+					.watcher()
+					.watch(source_code_directory.as_path(), RecursiveMode::Recursive)
+				{
+					error!(
+						"failed to start watching source code directory '{}': {e}",
+						source_code_directory.display()
+					);
+					return;
+				}
+				while let Some(Ok(events)) = file_events.recv().await {
+					let changed_filepaths: HashSet<PathBuf> = events
+						.into_iter()
+						.flat_map(|event| event.event.paths)
+						.filter(|filepath| is_watched_file(&source_code_directory, filepath))
+						.collect();
+
+					if !changed_filepaths.is_empty()
+						&& output
+							.send(Message::SourceCodeFilesChanged {
+								project_id,
+								changed_filepaths,
+							})
+							.await
+							.is_err()
+					{
+						return;
+					}
+				}
+				error!(
+					"source code directory watcher of '{}' quit unexpectedly",
+					source_code_directory.display()
+				);
+			}
+			Err(e) => error!("failed to create source code directory watcher: {e}"),
+		}
+	})
+}
+
+/// ignores hidden files and folders (like '.git') the same way the import does
+fn is_watched_file(source_code_directory: &Path, filepath: &Path) -> bool {
+	let is_hidden = filepath
+		.strip_prefix(source_code_directory)
+		.map(|relative_filepath| {
+			relative_filepath
+				.components()
+				.any(|component| component.as_os_str().to_string_lossy().starts_with('.'))
+		})
+		.unwrap_or(true);
+
+	!is_hidden && is_source_code_file(filepath)
+}
@@ -1,5 +1,5 @@
 use crate::components::markdown::{self, parse_markdown};
-use project_tracker_core::{Database, ProjectId, Task, TaskId};
+use project_tracker_core::{Database, ProjectId, Task, TaskComment, TaskCommentId, TaskId};
 use std::{
 	cell::RefCell,
 	collections::HashMap,
@@ -8,14 +8,15 @@ use std::{
 };
 
 #[derive(Debug, Clone)]
-struct CachedTaskDescriptionMarkdownItems {
-	task_description_hash: u64,
+struct CachedMarkdownItems {
+	markdown_hash: u64,
 	items: Arc<[markdown::Item]>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct TaskDescriptionMarkdownStorage {
-	storage: RefCell<HashMap<TaskId, CachedTaskDescriptionMarkdownItems>>,
+	storage: RefCell<HashMap<TaskId, CachedMarkdownItems>>,
+	comment_storage: RefCell<HashMap<TaskCommentId, CachedMarkdownItems>>,
 }
 
 impl TaskDescriptionMarkdownStorage {
@@ -36,26 +37,37 @@ impl TaskDescriptionMarkdownStorage {
 	}
 
 	pub fn get_from_str(&self, task_id: TaskId, task_description: &str) -> Arc<[markdown::Item]> {
-		let mut hasher = DefaultHasher::new();
-		task_description.hash(&mut hasher);
-		let task_description_hash = hasher.finish();
-
-		self.storage
-			.borrow_mut()
-			.entry(task_id)
-			.and_modify(|cached| {
-				if cached.task_description_hash != task_description_hash {
-					cached.items = parse_markdown(task_description);
-				}
-			})
-			.or_insert_with(|| {
-				let items = parse_markdown(task_description);
-				CachedTaskDescriptionMarkdownItems {
-					task_description_hash,
-					items,
-				}
-			})
-			.items
-			.clone()
+		cached_markdown_items(&self.storage, task_id, task_description)
 	}
+
+	pub fn get_from_comment(&self, comment: &TaskComment) -> Arc<[markdown::Item]> {
+		cached_markdown_items(&self.comment_storage, comment.id, &comment.body)
+	}
+}
+
+/// only parses the markdown again when it changed
+fn cached_markdown_items<K: Eq + Hash>(
+	storage: &RefCell<HashMap<K, CachedMarkdownItems>>,
+	key: K,
+	markdown: &str,
+) -> Arc<[markdown::Item]> {
+	let mut hasher = DefaultHasher::new();
+	markdown.hash(&mut hasher);
+	let markdown_hash = hasher.finish();
+
+	storage
+		.borrow_mut()
+		.entry(key)
+		.and_modify(|cached| {
+			if cached.markdown_hash != markdown_hash {
+				cached.markdown_hash = markdown_hash;
+				cached.items = parse_markdown(markdown);
+			}
+		})
+		.or_insert_with(|| CachedMarkdownItems {
+			markdown_hash,
+			items: parse_markdown(markdown),
+		})
+		.items
+		.clone()
 }
@@ -0,0 +1,51 @@
+use crate::{
+	core::View,
+	icons::{icon_to_text, Bootstrap},
+	project_tracker::Message,
+	styles::{tooltip_container_style, SMALL_TEXT_SIZE},
+};
+use iced::{
+	widget::{text, tooltip, tooltip::Position},
+	Color, Element,
+};
+use project_tracker_core::TaskPriority;
+
+pub trait TaskPriorityUI {
+	fn color(&self) -> Option<Color>;
+	fn icon(&self) -> Bootstrap;
+}
+
+impl TaskPriorityUI for TaskPriority {
+	fn color(&self) -> Option<Color> {
+		match self {
+			Self::None => None,
+			Self::Low => Some(Color::from_rgb8(80, 150, 255)),
+			Self::Medium => Some(Color::from_rgb8(240, 200, 0)),
+			Self::High => Some(Color::from_rgb8(255, 130, 0)),
+			Self::Urgent => Some(Color::from_rgb8(230, 30, 30)),
+		}
+	}
+
+	fn icon(&self) -> Bootstrap {
+		match self {
+			Self::None => Bootstrap::Flag,
+			_ => Bootstrap::FlagFill,
+		}
+	}
+}
+
+impl View for TaskPriority {
+	fn view(&self) -> Element<'_, Message> {
+		let color = self.color();
+		tooltip(
+			icon_to_text(self.icon())
+				.size(SMALL_TEXT_SIZE)
+				.style(move |_theme| text::Style { color }),
+			text(format!("{} Priority", self.as_str())).size(SMALL_TEXT_SIZE),
+			Position::Top,
+		)
+		.gap(5)
+		.style(tooltip_container_style)
+		.into()
+	}
+}
@@ -5,21 +5,132 @@ use crate::{
 };
 use iced::{widget::image, Element};
 use serde::{Deserialize, Serialize};
-use std::{path::Path, process::Command};
+use std::{
+	io,
+	path::{Path, PathBuf},
+	process::Command,
+	sync::LazyLock,
+};
+use thiserror::Error;
+
+/// terminal emulators and the arguments that make them run the command after them
+const TERMINAL_EMULATORS: [(&str, &[&str]); 9] = [
+	("x-terminal-emulator", &["-e"]),
+	("kitty", &[]),
+	("alacritty", &["-e"]),
+	("wezterm", &["start", "--"]),
+	("gnome-terminal", &["--"]),
+	("konsole", &["-e"]),
+	("xfce4-terminal", &["-x"]),
+	("foot", &[]),
+	("xterm", &["-e"]),
+];
+
+const FILE_PLACEHOLDER: &str = "{file}";
+const LINE_PLACEHOLDER: &str = "{line}";
+const COLUMN_PLACEHOLDER: &str = "{column}";
+
+static INSTALLED_CODE_EDITORS: LazyLock<Vec<CodeEditor>> = LazyLock::new(|| {
+	CodeEditor::builtin()
+		.into_iter()
+		.filter(CodeEditor::is_installed)
+		.collect()
+});
+
+#[derive(Debug, Error)]
+pub enum CodeEditorError {
+	#[error("invalid custom editor command: {0}")]
+	InvalidCommand(#[from] shell_words::ParseError),
+	#[error("the custom editor command is empty")]
+	EmptyCommand,
+	#[error("no terminal emulator found to run the editor in, try setting $TERMINAL")]
+	NoTerminalEmulator,
+	#[error("failed to run the editor: {0}")]
+	FailedToRun(#[from] io::Error),
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum JetBrainsIde {
+	IntelliJIdea,
+	PyCharm,
+	CLion,
+	RustRover,
+	WebStorm,
+	GoLand,
+}
+
+impl JetBrainsIde {
+	const ALL: [Self; 6] = [
+		Self::IntelliJIdea,
+		Self::PyCharm,
+		Self::CLion,
+		Self::RustRover,
+		Self::WebStorm,
+		Self::GoLand,
+	];
+
+	fn name(&self) -> &'static str {
+		match self {
+			Self::IntelliJIdea => "IntelliJ IDEA",
+			Self::PyCharm => "PyCharm",
+			Self::CLion => "CLion",
+			Self::RustRover => "RustRover",
+			Self::WebStorm => "WebStorm",
+			Self::GoLand => "GoLand",
+		}
+	}
+
+	/// the launcher script name, also used by the JetBrains Toolbox
+	fn program(&self) -> &'static str {
+		match self {
+			Self::IntelliJIdea => "idea",
+			Self::PyCharm => "pycharm",
+			Self::CLion => "clion",
+			Self::RustRover => "rustrover",
+			Self::WebStorm => "webstorm",
+			Self::GoLand => "goland",
+		}
+	}
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum CodeEditor {
 	VSCode,
 	Zed,
+	SublimeText,
+	Neovim,
+	Helix,
+	JetBrains(JetBrainsIde),
+	Kate,
+	Emacs,
 	Custom { name: String, command: String },
 }
 
 impl CodeEditor {
+	/// all editors that don't need to be configured
+	pub fn builtin() -> Vec<CodeEditor> {
+		[
+			Self::VSCode,
+			Self::Zed,
+			Self::SublimeText,
+			Self::Neovim,
+			Self::Helix,
+		]
+		.into_iter()
+		.chain(JetBrainsIde::ALL.into_iter().map(Self::JetBrains))
+		.chain([Self::Kate, Self::Emacs])
+		.collect()
+	}
+
+	/// the builtin editors found on this system, only detected once
+	pub fn installed() -> &'static [CodeEditor] {
+		&INSTALLED_CODE_EDITORS
+	}
+
 	pub fn label(&self) -> &str {
 		match self {
-			Self::VSCode => "VS Code",
-			Self::Zed => "Zed",
 			Self::Custom { .. } => "Custom",
+			_ => self.name(),
 		}
 	}
 
@@ -27,6 +138,12 @@ impl CodeEditor {
 		match self {
 			Self::VSCode => "VS Code",
 			Self::Zed => "Zed",
+			Self::SublimeText => "Sublime Text",
+			Self::Neovim => "Neovim",
+			Self::Helix => "Helix",
+			Self::JetBrains(ide) => ide.name(),
+			Self::Kate => "Kate",
+			Self::Emacs => "Emacs",
 			Self::Custom { name, .. } => name,
 		}
 	}
@@ -39,70 +156,331 @@ impl CodeEditor {
 			Self::Zed => image(ZED_ICON_IMAGE_HANDLE.clone())
 				.height(ICON_FONT_SIZE)
 				.into(),
-			Self::Custom { .. } => icon_to_text(Bootstrap::CodeSlash)
+			Self::Neovim | Self::Helix => icon_to_text(Bootstrap::Terminal)
+				.size(ICON_FONT_SIZE)
+				.into(),
+			_ => icon_to_text(Bootstrap::CodeSlash)
 				.size(ICON_FONT_SIZE)
 				.into(),
 		}
 	}
 
-	pub fn generate_command(&self, file_location: &str) -> Command {
+	pub fn is_installed(&self) -> bool {
 		match self {
 			Self::VSCode => {
-				let mut command;
-				let mut args = Vec::new();
-				// if vscode is installed natively -> run code
-				// if installed with flatpak -> run flatpak
-				match which::which("code") {
-					Ok(code_filepath) => command = Command::new(code_filepath),
-					Err(_) => {
-						command = Command::new("flatpak");
-						args.push("run");
-						args.push("com.visualstudio.code");
-					}
-				}
-				args.push("--goto");
-				args.push(file_location);
-				command.args(args);
-				command
+				which::which("code").is_ok() || is_flatpak_installed("com.visualstudio.code")
 			}
 			Self::Zed => {
-				// checks if zed is included in $PATH, installed locally or installed with flatpak
-
-				let mut command;
-				let mut args = Vec::new();
-				let local_zed_filepath = std::env::var("HOME")
-					.map(|home_filepath| {
-						Path::new(&home_filepath)
-							.join(".local")
-							.join("bin")
-							.join("zed")
-					})
-					.ok()
-					.and_then(|path| if path.exists() { Some(path) } else { None });
-				match which::which("zed") {
-					Ok(zed_filepath) => command = Command::new(zed_filepath),
-					Err(_) => match local_zed_filepath {
-						Some(local_zed_filepath) => command = Command::new(local_zed_filepath),
-						None => {
-							command = Command::new("flatpak");
-							args.push("run");
-							args.push("dev.zed.Zed");
+				which::which("zed").is_ok()
+					|| local_zed_filepath().is_some()
+					|| is_flatpak_installed("dev.zed.Zed")
+			}
+			Self::SublimeText => which::which("subl").is_ok(),
+			Self::Neovim => which::which("nvim").is_ok(),
+			Self::Helix => which::which("hx").is_ok(),
+			Self::JetBrains(ide) => which::which(ide.program()).is_ok(),
+			Self::Kate => which::which("kate").is_ok(),
+			Self::Emacs => which::which("emacs").is_ok(),
+			Self::Custom { .. } => true,
+		}
+	}
+
+	/// opens the file location formatted as "filepath:line:column"
+	pub fn open(&self, file_location: &str) -> Result<(), CodeEditorError> {
+		let command_line = self.command_line(file_location)?;
+		let (program, args) = command_line
+			.split_first()
+			.ok_or(CodeEditorError::EmptyCommand)?;
+		Command::new(program).args(args).spawn()?;
+		Ok(())
+	}
+
+	/// the program followed by its arguments that open the file location
+	pub fn command_line(&self, file_location: &str) -> Result<Vec<String>, CodeEditorError> {
+		let location = FileLocation::parse(file_location);
+		let line = location.line.unwrap_or("1");
+		let column = location.column.unwrap_or("1");
+
+		Ok(match self {
+			// if vscode is installed natively -> run code
+			// if installed with flatpak -> run flatpak
+			Self::VSCode => {
+				let mut command_line = match which::which("code") {
+					Ok(_) => vec!["code".to_string()],
+					Err(_) => flatpak_command_line("com.visualstudio.code"),
+				};
+				command_line.extend(["--goto".to_string(), file_location.to_string()]);
+				command_line
+			}
+			// checks if zed is included in $PATH, installed locally or installed with flatpak
+			Self::Zed => {
+				let mut command_line = match which::which("zed") {
+					Ok(_) => vec!["zed".to_string()],
+					Err(_) => match local_zed_filepath() {
+						Some(local_zed_filepath) => {
+							vec![local_zed_filepath.to_string_lossy().into_owned()]
 						}
+						None => flatpak_command_line("dev.zed.Zed"),
 					},
-				}
-				args.push(file_location);
-				command.args(args);
-				command
+				};
+				command_line.push(file_location.to_string());
+				command_line
+			}
+			Self::SublimeText => vec!["subl".to_string(), file_location.to_string()],
+			Self::Neovim => in_terminal_emulator([
+				"nvim".to_string(),
+				format!("+call cursor({line}, {column})"),
+				location.filepath.to_string(),
+			])?,
+			Self::Helix => in_terminal_emulator([
+				"hx".to_string(),
+				format!("{}:{line}:{column}", location.filepath),
+			])?,
+			Self::JetBrains(ide) => vec![
+				ide.program().to_string(),
+				"--line".to_string(),
+				line.to_string(),
+				"--column".to_string(),
+				column.to_string(),
+				location.filepath.to_string(),
+			],
+			Self::Kate => vec![
+				"kate".to_string(),
+				"--line".to_string(),
+				line.to_string(),
+				"--column".to_string(),
+				column.to_string(),
+				location.filepath.to_string(),
+			],
+			Self::Emacs => vec![
+				"emacs".to_string(),
+				format!("+{line}:{column}"),
+				location.filepath.to_string(),
+			],
+			Self::Custom { command, .. } => custom_command_line(command, file_location)?,
+		})
+	}
+}
+
+/// "filepath:line:column", line and column are optional.
+/// only trailing numbers are split off, so windows drive letters stay in the filepath
+#[derive(Debug, PartialEq, Eq)]
+struct FileLocation<'a> {
+	filepath: &'a str,
+	line: Option<&'a str>,
+	column: Option<&'a str>,
+}
+
+impl<'a> FileLocation<'a> {
+	fn parse(file_location: &'a str) -> Self {
+		let is_number = |str: &str| !str.is_empty() && str.chars().all(|c| c.is_ascii_digit());
+		let split_number = |str: &'a str| {
+			str.rsplit_once(':')
+				.filter(|(_rest, number)| is_number(number))
+		};
+
+		match split_number(file_location) {
+			Some((rest, last_number)) => match split_number(rest) {
+				Some((filepath, line)) => Self {
+					filepath,
+					line: Some(line),
+					column: Some(last_number),
+				},
+				None => Self {
+					filepath: rest,
+					line: Some(last_number),
+					column: None,
+				},
+			},
+			None => Self {
+				filepath: file_location,
+				line: None,
+				column: None,
+			},
+		}
+	}
+}
+
+/// splits the command into shell words and fills in the '{file}', '{line}' and '{column}' placeholders.
+/// without any placeholder the whole file location is appended
+fn custom_command_line(command: &str, file_location: &str) -> Result<Vec<String>, CodeEditorError> {
+	let mut words = shell_words::split(command)?;
+	if words.is_empty() {
+		return Err(CodeEditorError::EmptyCommand);
+	}
+
+	let has_placeholders = words.iter().any(|word| {
+		[FILE_PLACEHOLDER, LINE_PLACEHOLDER, COLUMN_PLACEHOLDER]
+			.iter()
+			.any(|placeholder| word.contains(placeholder))
+	});
+
+	if has_placeholders {
+		let location = FileLocation::parse(file_location);
+		Ok(words
+			.iter()
+			.map(|word| fill_placeholders(word, &location))
+			.collect())
+	} else {
+		words.push(file_location.to_string());
+		Ok(words)
+	}
+}
+
+fn fill_placeholders(word: &str, location: &FileLocation) -> String {
+	let mut filled_word = String::new();
+	let mut rest = word;
+
+	while let Some(index) = rest.find('{') {
+		filled_word.push_str(&rest[..index]);
+		rest = &rest[index..];
+
+		let placeholder_value = [
+			(FILE_PLACEHOLDER, location.filepath),
+			(LINE_PLACEHOLDER, location.line.unwrap_or("1")),
+			(COLUMN_PLACEHOLDER, location.column.unwrap_or("1")),
+		]
+		.into_iter()
+		.find(|(placeholder, _value)| rest.starts_with(placeholder));
+
+		match placeholder_value {
+			Some((placeholder, value)) => {
+				filled_word.push_str(value);
+				rest = &rest[placeholder.len()..];
 			}
-			Self::Custom { command, .. } => {
-				let mut split_command = command.split(' ');
-				let program = split_command.next().unwrap_or("");
-				let mut command = Command::new(program);
-				let mut args: Vec<&str> = split_command.collect();
-				args.push(file_location);
-				command.args(args);
-				command
+			None => {
+				filled_word.push('{');
+				rest = &rest[1..];
 			}
 		}
 	}
+	filled_word.push_str(rest);
+
+	filled_word
+}
+
+/// prefers the terminal in $TERMINAL, otherwise the first installed known one
+fn in_terminal_emulator(
+	command_line: impl IntoIterator<Item = String>,
+) -> Result<Vec<String>, CodeEditorError> {
+	let terminal_emulator = std::env::var("TERMINAL")
+		.ok()
+		.filter(|terminal| !terminal.is_empty())
+		.map(|terminal| {
+			let args = TERMINAL_EMULATORS
+				.iter()
+				.find(|(name, _args)| {
+					Path::new(&terminal)
+						.file_name()
+						.is_some_and(|file_name| file_name == *name)
+				})
+				.map(|(_name, args)| *args)
+				.unwrap_or(&["-e"]);
+			(terminal, args)
+		})
+		.or_else(|| {
+			TERMINAL_EMULATORS
+				.iter()
+				.find(|(name, _args)| which::which(name).is_ok())
+				.map(|(name, args)| (name.to_string(), *args))
+		});
+
+	let (terminal, args) = terminal_emulator.ok_or(CodeEditorError::NoTerminalEmulator)?;
+	Ok(std::iter::once(terminal)
+		.chain(args.iter().map(|arg| arg.to_string()))
+		.chain(command_line)
+		.collect())
+}
+
+fn flatpak_command_line(app_id: &str) -> Vec<String> {
+	vec!["flatpak".to_string(), "run".to_string(), app_id.to_string()]
+}
+
+fn is_flatpak_installed(app_id: &str) -> bool {
+	let user_installation = std::env::var("HOME").map(|home_filepath| {
+		Path::new(&home_filepath)
+			.join(".local")
+			.join("share")
+			.join("flatpak")
+	});
+
+	[Ok(PathBuf::from("/var/lib/flatpak")), user_installation]
+		.into_iter()
+		.flatten()
+		.any(|installation| installation.join("app").join(app_id).exists())
+}
+
+fn local_zed_filepath() -> Option<PathBuf> {
+	std::env::var("HOME")
+		.map(|home_filepath| {
+			Path::new(&home_filepath)
+				.join(".local")
+				.join("bin")
+				.join("zed")
+		})
+		.ok()
+		.filter(|path| path.exists())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use super::{custom_command_line, FileLocation};
+
+	#[test]
+	fn test_parse_file_location() {
+		assert_eq!(
+			FileLocation::parse("src/main.rs:42:15"),
+			FileLocation {
+				filepath: "src/main.rs",
+				line: Some("42"),
+				column: Some("15"),
+			}
+		);
+		assert_eq!(
+			FileLocation::parse("C:\\code\\main.rs:7"),
+			FileLocation {
+				filepath: "C:\\code\\main.rs",
+				line: Some("7"),
+				column: None,
+			}
+		);
+		assert_eq!(
+			FileLocation::parse("notes:todo.md"),
+			FileLocation {
+				filepath: "notes:todo.md",
+				line: None,
+				column: None,
+			}
+		);
+	}
+
+	#[test]
+	fn test_custom_command_line() {
+		assert_eq!(
+			custom_command_line(
+				"'/opt/My Editor/editor' --goto \"{file}\" -l{line} -c {column}",
+				"my project/main.rs:42:15"
+			)
+			.unwrap(),
+			[
+				"/opt/My Editor/editor",
+				"--goto",
+				"my project/main.rs",
+				"-l42",
+				"-c",
+				"15"
+			]
+		);
+		assert_eq!(
+			custom_command_line("editor --open", "main.rs:42:15").unwrap(),
+			["editor", "--open", "main.rs:42:15"]
+		);
+		assert_eq!(
+			custom_command_line("editor {file}", "{line}.rs:3:1").unwrap(),
+			["editor", "{line}.rs"]
+		);
+		assert!(custom_command_line("editor 'unclosed", "main.rs:1:1").is_err());
+		assert!(custom_command_line("  ", "main.rs:1:1").is_err());
+	}
 }
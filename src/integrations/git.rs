@@ -0,0 +1,251 @@
+use chrono::DateTime;
+use project_tracker_core::{GitCommit, ScannedGitCommit, SourceCodeTodoBlame};
+use std::{
+	collections::{HashMap, HashSet},
+	io,
+	path::Path,
+	process::Command,
+};
+use thiserror::Error;
+
+/// how many of the newest commits are scanned for task references
+const MAX_SCANNED_COMMITS: usize = 1000;
+
+#[derive(Debug, Error)]
+pub enum GitError {
+	#[error("failed to run git: {0}")]
+	FailedToRun(#[from] io::Error),
+	#[error("'git {command}' failed: {stderr}")]
+	CommandFailed { command: String, stderr: String },
+}
+
+/// author and date of every committed line of the file, keyed by line number starting at 1.
+/// only reads the local repository the file is in
+pub fn git_blame(filepath: &Path) -> Result<HashMap<usize, SourceCodeTodoBlame>, GitError> {
+	let directory = filepath.parent().unwrap_or(Path::new("."));
+	let filepath = filepath.to_string_lossy();
+	let porcelain = run_git(directory, &["blame", "--porcelain", "--", &filepath])?;
+	Ok(parse_git_blame_porcelain(&porcelain))
+}
+
+/// the newest commits of all branches that could reference a task,
+/// see 'ScannedGitCommit::references'
+pub fn scan_git_log(directory: &Path) -> Result<Vec<ScannedGitCommit>, GitError> {
+	let max_count = format!("--max-count={MAX_SCANNED_COMMITS}");
+
+	let default_branch_commits: HashSet<String> = match default_branch(directory) {
+		Some(default_branch) => run_git(directory, &["rev-list", &max_count, &default_branch])?
+			.lines()
+			.map(str::to_string)
+			.collect(),
+		None => HashSet::new(),
+	};
+
+	let log = run_git(
+		directory,
+		&[
+			"log",
+			"--all",
+			&max_count,
+			"--format=%H%x1f%an%x1f%at%x1f%B%x1e",
+		],
+	)?;
+	Ok(parse_git_log(&log, &default_branch_commits))
+}
+
+/// the branch 'origin/HEAD' points to, otherwise a local 'main' or 'master'
+fn default_branch(directory: &Path) -> Option<String> {
+	if let Ok(remote_head) = run_git(
+		directory,
+		&[
+			"symbolic-ref",
+			"--quiet",
+			"--short",
+			"refs/remotes/origin/HEAD",
+		],
+	) {
+		return Some(remote_head.trim().to_string());
+	}
+	["main", "master"]
+		.into_iter()
+		.find(|branch| {
+			run_git(
+				directory,
+				&[
+					"rev-parse",
+					"--verify",
+					"--quiet",
+					&format!("refs/heads/{branch}"),
+				],
+			)
+			.is_ok()
+		})
+		.map(str::to_string)
+}
+
+fn run_git(directory: &Path, args: &[&str]) -> Result<String, GitError> {
+	let output = Command::new("git")
+		.args(args)
+		.current_dir(directory)
+		.output()?;
+
+	if output.status.success() {
+		Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+	} else {
+		Err(GitError::CommandFailed {
+			command: args.join(" "),
+			stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+		})
+	}
+}
+
+/// commits are separated by '\x1e', their fields by '\x1f'.
+/// skips commits that can't reference a task to keep the database message small
+fn parse_git_log(log: &str, default_branch_commits: &HashSet<String>) -> Vec<ScannedGitCommit> {
+	log.split('\x1e')
+		.filter_map(|commit| {
+			let mut fields = commit.trim_start_matches('\n').splitn(4, '\x1f');
+			let hash = fields.next()?.to_string();
+			let author = fields.next()?.to_string();
+			let date = DateTime::from_timestamp(fields.next()?.parse().ok()?, 0)?;
+			let message = fields.next()?.trim().to_string();
+
+			let lowercase_message = message.to_lowercase();
+			if !lowercase_message.contains("pt-") && !lowercase_message.contains("fixes:") {
+				return None;
+			}
+
+			Some(ScannedGitCommit {
+				commit: GitCommit {
+					summary: message.lines().next().unwrap_or_default().to_string(),
+					on_default_branch: default_branch_commits.contains(&hash),
+					hash,
+					author,
+					date,
+				},
+				message,
+			})
+		})
+		.collect()
+}
+
+/// the commit infos are only listed for the first line of each commit,
+/// not yet committed lines have an all zero commit hash and are skipped
+fn parse_git_blame_porcelain(porcelain: &str) -> HashMap<usize, SourceCodeTodoBlame> {
+	let mut commits: HashMap<&str, (Option<&str>, Option<i64>)> = HashMap::new();
+	let mut line_commits = Vec::new();
+	let mut current_commit = None;
+
+	for line in porcelain.lines() {
+		if line.starts_with('\t') {
+			continue;
+		}
+		let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+		if is_commit_hash(key) {
+			let final_line_number = value
+				.split(' ')
+				.nth(1)
+				.and_then(|line_number| line_number.parse::<usize>().ok());
+			if let Some(final_line_number) = final_line_number {
+				line_commits.push((final_line_number, key));
+				current_commit = Some(key);
+			}
+			continue;
+		}
+		if let Some(commit) = current_commit {
+			match key {
+				"author" => commits.entry(commit).or_default().0 = Some(value),
+				"author-time" => commits.entry(commit).or_default().1 = value.parse().ok(),
+				_ => {}
+			}
+		}
+	}
+
+	line_commits
+		.into_iter()
+		.filter(|(_line_number, commit)| commit.chars().any(|c| c != '0'))
+		.filter_map(|(line_number, commit)| {
+			let (author, author_time) = *commits.get(commit)?;
+			let added = DateTime::from_timestamp(author_time?, 0)?;
+			Some((
+				line_number,
+				SourceCodeTodoBlame {
+					author: author?.to_string(),
+					added,
+				},
+			))
+		})
+		.collect()
+}
+
+fn is_commit_hash(str: &str) -> bool {
+	matches!(str.len(), 40 | 64) && str.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use super::{parse_git_blame_porcelain, parse_git_log};
+	use chrono::DateTime;
+	use std::collections::HashSet;
+
+	#[test]
+	fn test_parse_git_blame_porcelain() {
+		let porcelain = "\
+4b825dc642cb6eb9a060e54bf8d69288fbee4904 1 1 2
+author Jane Doe
+author-mail <jane@example.com>
+author-time 1700000000
+author-tz +0100
+committer Jane Doe
+committer-time 1700000000
+summary add main
+filename src/main.rs
+\tfn main() {
+4b825dc642cb6eb9a060e54bf8d69288fbee4904 2 2
+\t\t// TODO: print something
+0000000000000000000000000000000000000000 3 3 1
+author Not Committed Yet
+author-time 1800000000
+summary Version of src/main.rs from src/main.rs
+filename src/main.rs
+\t\t// TODO: not committed
+";
+		let blames = parse_git_blame_porcelain(porcelain);
+
+		assert_eq!(blames.len(), 2);
+		let blame = blames.get(&2).unwrap();
+		assert_eq!(blame.author, "Jane Doe");
+		assert_eq!(
+			blame.added,
+			DateTime::from_timestamp(1700000000, 0).unwrap()
+		);
+		assert!(!blames.contains_key(&3));
+	}
+
+	#[test]
+	fn test_parse_git_log() {
+		let log = "\
+aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\x1fJane Doe\x1f1700000000\x1fadd login (pt-0123abcd)\n\x1e
+bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb\x1fJohn Doe\x1f1700000100\x1fcheck config\n\nFixes: Crash on startup\n\x1e
+cccccccccccccccccccccccccccccccccccccccc\x1fJane Doe\x1f1700000200\x1funrelated\n\x1e
+";
+		let default_branch_commits =
+			HashSet::from(["bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb".to_string()]);
+		let commits = parse_git_log(log, &default_branch_commits);
+
+		assert_eq!(commits.len(), 2);
+		assert_eq!(commits[0].commit.summary, "add login (pt-0123abcd)");
+		assert_eq!(commits[0].commit.author, "Jane Doe");
+		assert!(!commits[0].commit.on_default_branch);
+		assert_eq!(
+			commits[1].message,
+			"check config\n\nFixes: Crash on startup"
+		);
+		assert!(commits[1].commit.on_default_branch);
+		assert_eq!(
+			commits[1].commit.date,
+			DateTime::from_timestamp(1700000100, 0).unwrap()
+		);
+	}
+}
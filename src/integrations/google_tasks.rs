@@ -2,7 +2,8 @@ use crate::core::IcedColorConversion;
 use chrono::{DateTime, Datelike, Local, TimeZone};
 use iced::Color;
 use project_tracker_core::{
-	OrderedHashMap, Project, SerializableColor, SerializableDate, SortMode, Task, TaskId,
+	ImportSource, OrderedHashMap, Project, SerializableColor, SerializableDate, SortMode, Task,
+	TaskId, TaskOrigin,
 };
 use serde::{Deserialize, Serialize};
 use std::{collections::BTreeSet, path::PathBuf};
@@ -101,7 +102,7 @@ pub fn import_google_tasks_json(json: &str) -> Result<Vec<Project>, serde_json::
 						.ok()
 				});
 
-				let task = Task::new(
+				let mut task = Task::new(
 					task_name,
 					task_description,
 					None,
@@ -109,6 +110,10 @@ pub fn import_google_tasks_json(json: &str) -> Result<Vec<Project>, serde_json::
 					task_due_date,
 					BTreeSet::new(),
 				);
+				task.origin = Some(TaskOrigin::new(
+					ImportSource::GoogleTasks,
+					google_tasks_task.id,
+				));
 
 				let task_id = TaskId::generate();
 
@@ -0,0 +1,456 @@
+use crate::core::IcedColorConversion;
+use chrono::{Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use iced::Color;
+use project_tracker_core::{
+	ImportSource, OrderedHashMap, Project, SerializableColor, SerializableDate, SerializableTime,
+	SortMode, Task, TaskId, TaskOrigin, TaskPriority,
+};
+use std::{collections::BTreeSet, path::PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ImportIcsError {
+	#[error("failed to open ics file: {filepath}, error: {error}")]
+	FailedToOpenFile {
+		filepath: PathBuf,
+		error: std::io::Error,
+	},
+	#[error("failed to parse ics file: {filepath}, error: {error}")]
+	ParseError {
+		filepath: PathBuf,
+		error: IcsParseError,
+	},
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum IcsParseError {
+	#[error("no calendar (BEGIN:VCALENDAR) found")]
+	NoCalendar,
+	#[error("line {line}: 'END:{component}' without a matching 'BEGIN'")]
+	UnexpectedEnd { line: usize, component: String },
+	#[error("'BEGIN:{component}' is never ended")]
+	Unterminated { component: String },
+}
+
+#[derive(Debug, Default)]
+struct IcsTodo {
+	uid: Option<String>,
+	summary: String,
+	description: String,
+	due: Option<(SerializableDate, Option<SerializableTime>)>,
+	completed: bool,
+	categories: Vec<String>,
+	priority: TaskPriority,
+}
+
+struct ContentLine<'a> {
+	name: String,
+	params: Vec<(String, &'a str)>,
+	value: &'a str,
+}
+
+impl ContentLine<'_> {
+	fn param(&self, name: &str) -> Option<&str> {
+		self.params
+			.iter()
+			.find(|(param_name, _)| param_name == name)
+			.map(|(_, value)| *value)
+	}
+}
+
+pub async fn import_ics(filepath: PathBuf) -> Result<Vec<Project>, ImportIcsError> {
+	let ics = tokio::fs::read_to_string(&filepath)
+		.await
+		.map_err(|error| ImportIcsError::FailedToOpenFile {
+			filepath: filepath.clone(),
+			error,
+		})?;
+
+	let fallback_project_name = filepath
+		.file_stem()
+		.map(|file_stem| file_stem.to_string_lossy().to_string())
+		.unwrap_or("Imported Tasks".to_string());
+
+	import_ics_str(&ics, &fallback_project_name)
+		.map_err(|error| ImportIcsError::ParseError { filepath, error })
+}
+
+/// every calendar becomes a project, named after 'X-WR-CALNAME' if present.
+/// only VTODO components are imported, everything else (events, timezones, alarms) is skipped
+pub fn import_ics_str(
+	ics: &str,
+	fallback_project_name: &str,
+) -> Result<Vec<Project>, IcsParseError> {
+	let mut projects = Vec::new();
+	let mut components: Vec<String> = Vec::new();
+	let mut project: Option<Project> = None;
+	let mut todo: Option<IcsTodo> = None;
+
+	for (line_index, line) in unfold_lines(ics).iter().enumerate() {
+		let Some(content_line) = parse_content_line(line) else {
+			continue;
+		};
+
+		match content_line.name.as_str() {
+			"BEGIN" => {
+				let component = content_line.value.to_ascii_uppercase();
+				match component.as_str() {
+					"VCALENDAR" => {
+						project = Some(Project::new(
+							fallback_project_name.to_string(),
+							SerializableColor::from_iced_color(Color::WHITE),
+							OrderedHashMap::new(),
+							SortMode::default(),
+						));
+					}
+					// alarms are nested inside of todos
+					"VTODO" if components.last().map(String::as_str) == Some("VCALENDAR") => {
+						todo = Some(IcsTodo::default());
+					}
+					_ => {}
+				}
+				components.push(component);
+			}
+			"END" => {
+				let component = content_line.value.to_ascii_uppercase();
+				if components.last() != Some(&component) {
+					return Err(IcsParseError::UnexpectedEnd {
+						line: line_index + 1,
+						component,
+					});
+				}
+				components.pop();
+				match component.as_str() {
+					"VCALENDAR" => projects.extend(project.take()),
+					"VTODO" if components.last().map(String::as_str) == Some("VCALENDAR") => {
+						if let (Some(project), Some(todo)) = (&mut project, todo.take()) {
+							insert_todo(project, todo);
+						}
+					}
+					_ => {}
+				}
+			}
+			"X-WR-CALNAME" if components.last().map(String::as_str) == Some("VCALENDAR") => {
+				if let Some(project) = &mut project {
+					project.name = unescape_text(content_line.value);
+				}
+			}
+			property => {
+				// only direct properties of the todo, not of nested alarms
+				if components.last().map(String::as_str) == Some("VTODO") {
+					if let Some(todo) = &mut todo {
+						parse_todo_property(todo, property, &content_line);
+					}
+				}
+			}
+		}
+	}
+
+	if let Some(component) = components.pop() {
+		return Err(IcsParseError::Unterminated { component });
+	}
+	if projects.is_empty() {
+		return Err(IcsParseError::NoCalendar);
+	}
+	Ok(projects)
+}
+
+fn parse_todo_property(todo: &mut IcsTodo, property: &str, content_line: &ContentLine) {
+	match property {
+		"UID" => todo.uid = Some(content_line.value.to_string()),
+		"SUMMARY" => todo.summary = unescape_text(content_line.value),
+		"DESCRIPTION" => todo.description = unescape_text(content_line.value),
+		"DUE" => todo.due = parse_due(content_line),
+		"STATUS" => {
+			todo.completed = matches!(
+				content_line.value.to_ascii_uppercase().as_str(),
+				"COMPLETED" | "CANCELLED"
+			)
+		}
+		"COMPLETED" => todo.completed = true,
+		"CATEGORIES" => todo.categories.extend(
+			split_unescaped_commas(content_line.value)
+				.into_iter()
+				.map(|category| unescape_text(category.trim()))
+				.filter(|category| !category.is_empty()),
+		),
+		"PRIORITY" => {
+			todo.priority = match content_line.value.trim().parse::<u8>() {
+				Ok(1..=2) => TaskPriority::Urgent,
+				Ok(3..=4) => TaskPriority::High,
+				Ok(5) => TaskPriority::Medium,
+				Ok(6..=9) => TaskPriority::Low,
+				_ => TaskPriority::None,
+			}
+		}
+		_ => {}
+	}
+}
+
+fn insert_todo(project: &mut Project, todo: IcsTodo) {
+	let mut tags = BTreeSet::new();
+	for category in todo.categories {
+		tags.insert(
+			project
+				.get_or_insert_task_tag(category, SerializableColor::from_iced_color(Color::WHITE)),
+		);
+	}
+
+	let mut task = Task::new(
+		todo.summary,
+		todo.description,
+		None,
+		None,
+		todo.due.map(|(due_date, _)| due_date),
+		tags,
+	);
+	task.due_time = todo.due.and_then(|(_, due_time)| due_time);
+	task.priority = todo.priority;
+	task.origin = todo
+		.uid
+		.map(|uid| TaskOrigin::new(ImportSource::ICalendar, uid));
+
+	if todo.completed {
+		project.done_tasks.insert(TaskId::generate(), task);
+	} else {
+		project.todo_tasks.insert(TaskId::generate(), task);
+	}
+}
+
+/// 'DUE;VALUE=DATE:20250314', 'DUE:20250314T093000' (floating), 'DUE:20250314T093000Z' (utc)
+/// or 'DUE;TZID=Europe/Berlin:20250314T093000', which is treated as local time
+fn parse_due(content_line: &ContentLine) -> Option<(SerializableDate, Option<SerializableTime>)> {
+	let value = content_line.value.trim();
+
+	if content_line.param("VALUE") == Some("DATE") || value.len() == 8 {
+		let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+		return Some((date.into(), None));
+	}
+
+	let local_date_time = match value.strip_suffix('Z') {
+		Some(utc_value) => {
+			let utc_date_time = NaiveDateTime::parse_from_str(utc_value, "%Y%m%dT%H%M%S").ok()?;
+			Utc.from_utc_datetime(&utc_date_time)
+				.with_timezone(&Local)
+				.naive_local()
+		}
+		None => NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?,
+	};
+
+	Some((
+		local_date_time.date().into(),
+		Some(local_date_time.time().into()),
+	))
+}
+
+/// lines starting with a space or tab continue the previous line
+fn unfold_lines(ics: &str) -> Vec<String> {
+	let mut lines: Vec<String> = Vec::new();
+	for line in ics.lines() {
+		let line = line.trim_end_matches('\r');
+		match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+			(Some(continuation), Some(previous_line)) => previous_line.push_str(continuation),
+			_ => lines.push(line.to_string()),
+		}
+	}
+	lines
+}
+
+/// 'NAME;PARAM=VALUE;PARAM="QUOTED:VALUE":VALUE'
+fn parse_content_line(line: &str) -> Option<ContentLine<'_>> {
+	let mut in_quotes = false;
+	let value_separator = line.char_indices().find_map(|(i, c)| match c {
+		'"' => {
+			in_quotes = !in_quotes;
+			None
+		}
+		':' if !in_quotes => Some(i),
+		_ => None,
+	})?;
+
+	let (name_and_params, value) = line.split_at(value_separator);
+	let mut name_and_params = name_and_params.split(';');
+	let name = name_and_params.next()?.trim().to_ascii_uppercase();
+	let params = name_and_params
+		.filter_map(|param| {
+			let (param_name, param_value) = param.split_once('=')?;
+			Some((
+				param_name.trim().to_ascii_uppercase(),
+				param_value.trim_matches('"'),
+			))
+		})
+		.collect();
+
+	Some(ContentLine {
+		name,
+		params,
+		value: &value[1..],
+	})
+}
+
+fn split_unescaped_commas(value: &str) -> Vec<&str> {
+	let mut parts = Vec::new();
+	let mut part_start = 0;
+	let mut escaped = false;
+	for (i, c) in value.char_indices() {
+		match c {
+			'\\' if !escaped => escaped = true,
+			',' if !escaped => {
+				parts.push(&value[part_start..i]);
+				part_start = i + 1;
+			}
+			_ => escaped = false,
+		}
+	}
+	parts.push(&value[part_start..]);
+	parts
+}
+
+fn unescape_text(text: &str) -> String {
+	let mut unescaped = String::with_capacity(text.len());
+	let mut chars = text.chars();
+	while let Some(c) = chars.next() {
+		if c == '\\' {
+			match chars.next() {
+				Some('n' | 'N') => unescaped.push('\n'),
+				Some(escaped) => unescaped.push(escaped),
+				None => unescaped.push('\\'),
+			}
+		} else {
+			unescaped.push(c);
+		}
+	}
+	unescaped
+}
+
+pub async fn import_ics_dialog() -> Option<Result<Vec<Project>, ImportIcsError>> {
+	let file_dialog_result = rfd::AsyncFileDialog::new()
+		.set_title("Import iCalendar Tasks")
+		.add_filter("iCalendar (.ics)", &["ics"])
+		.pick_file()
+		.await;
+
+	match file_dialog_result {
+		Some(file_handle) => {
+			let filepath = file_handle.path().to_path_buf();
+			Some(import_ics(filepath).await)
+		}
+		None => None,
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use super::{import_ics_str, IcsParseError};
+	use project_tracker_core::{
+		ImportSource, SerializableDate, SerializableTime, TaskOrigin, TaskPriority,
+	};
+
+	const THUNDERBIRD_SAMPLE: &str = include_str!("ics_samples/thunderbird_tasks.ics");
+	const NEXTCLOUD_SAMPLE: &str = include_str!("ics_samples/nextcloud_tasks.ics");
+
+	#[test]
+	fn test_import_thunderbird_tasks() {
+		let projects = import_ics_str(THUNDERBIRD_SAMPLE, "fallback").unwrap();
+		assert_eq!(projects.len(), 1);
+		let project = &projects[0];
+		assert_eq!(project.name, "Home");
+
+		// events are skipped
+		assert_eq!(project.todo_tasks.len(), 2);
+		assert_eq!(project.done_tasks.len(), 1);
+
+		let (_, groceries) = project.todo_tasks.iter().next().unwrap();
+		assert_eq!(groceries.name, "Buy groceries, milk; eggs");
+		assert_eq!(groceries.description, "from the store\naround the corner");
+		assert_eq!(
+			groceries.due_date,
+			Some(SerializableDate {
+				year: 2025,
+				month: 3,
+				day: 14
+			})
+		);
+		assert_eq!(groceries.due_time, None);
+		assert_eq!(groceries.priority, TaskPriority::High);
+		assert_eq!(
+			groceries.origin,
+			Some(TaskOrigin::new(
+				ImportSource::ICalendar,
+				"0b5b3c1e-3f6a-4d7e-8a0c-5e9d1b2f4c33".to_string()
+			))
+		);
+
+		let tag_names: Vec<&str> = groceries
+			.tags
+			.iter()
+			.map(|tag_id| project.task_tags.get(tag_id).unwrap().name.as_str())
+			.collect();
+		assert_eq!(tag_names.len(), 2);
+		assert!(tag_names.contains(&"Errands"));
+		assert!(tag_names.contains(&"Food, Drinks"));
+
+		let (_, tax_return) = project.todo_tasks.iter().nth(1).unwrap();
+		// the long folded summary gets unfolded again
+		assert_eq!(
+			tax_return.name,
+			"Finish the tax return before the deadline and send all of the documents to the accountant"
+		);
+		assert_eq!(
+			tax_return.due_time,
+			Some(SerializableTime {
+				hour: 17,
+				minute: 30
+			})
+		);
+		// the 'Errands' tag is shared
+		assert_eq!(project.task_tags.len(), 2);
+	}
+
+	#[test]
+	fn test_import_nextcloud_tasks() {
+		let projects = import_ics_str(NEXTCLOUD_SAMPLE, "nextcloud_tasks").unwrap();
+		assert_eq!(projects.len(), 1);
+		let project = &projects[0];
+		// no calendar name -> file name
+		assert_eq!(project.name, "nextcloud_tasks");
+		assert_eq!(project.todo_tasks.len(), 1);
+		assert_eq!(project.done_tasks.len(), 1);
+
+		let (_, task) = project.todo_tasks.iter().next().unwrap();
+		assert_eq!(task.name, "Call the plumber");
+		// the alarm description doesnt override the task description
+		assert_eq!(task.description, "");
+		assert_eq!(task.priority, TaskPriority::None);
+	}
+
+	#[test]
+	fn test_invalid_ics() {
+		assert_eq!(
+			import_ics_str("", "fallback").unwrap_err(),
+			IcsParseError::NoCalendar
+		);
+		assert_eq!(
+			import_ics_str(
+				"BEGIN:VCALENDAR\r\nBEGIN:VTODO\r\nEND:VCALENDAR\r\n",
+				"fallback"
+			)
+			.unwrap_err(),
+			IcsParseError::UnexpectedEnd {
+				line: 3,
+				component: "VCALENDAR".to_string()
+			}
+		);
+		assert_eq!(
+			import_ics_str(
+				"BEGIN:VCALENDAR\r\nBEGIN:VTODO\r\nEND:VTODO\r\n",
+				"fallback"
+			)
+			.unwrap_err(),
+			IcsParseError::Unterminated {
+				component: "VCALENDAR".to_string()
+			}
+		);
+	}
+}
@@ -0,0 +1,333 @@
+use crate::core::IcedColorConversion;
+use chrono::NaiveDate;
+use iced::Color;
+use project_tracker_core::{
+	ImportSource, OrderedHashMap, Project, SerializableColor, SerializableDate, SortMode, Task,
+	TaskId, TaskOrigin,
+};
+use serde::Deserialize;
+use std::{collections::BTreeSet, path::PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ImportIssuesError {
+	#[error("failed to open issues file: {filepath}, error: {error}")]
+	FailedToOpenFile {
+		filepath: PathBuf,
+		error: std::io::Error,
+	},
+	#[error("failed to parse issues: {filepath}, error: {error}")]
+	ParseError {
+		filepath: PathBuf,
+		error: serde_json::Error,
+	},
+}
+
+/// response of 'GET /repos/OWNER/REPO/issues' (github), 'GET /search/issues' (github)
+/// or 'GET /projects/ID/issues' (gitlab)
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum IssuesFormat {
+	List(Vec<Issue>),
+	Search { items: Vec<Issue> },
+}
+
+#[derive(Debug, Deserialize)]
+struct Issue {
+	#[serde(alias = "web_url")]
+	html_url: String,
+	title: String,
+	#[serde(default, alias = "description")]
+	body: Option<String>,
+	/// 'open'/'closed' (github) or 'opened'/'closed' (gitlab)
+	state: String,
+	#[serde(default)]
+	labels: Vec<IssueLabel>,
+	#[serde(default)]
+	milestone: Option<IssueMilestone>,
+	/// only gitlab issues have their own due date
+	#[serde(default)]
+	due_date: Option<String>,
+	/// the github issues api also lists pull requests
+	#[serde(default)]
+	pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum IssueLabel {
+	Name(String),
+	Detailed {
+		name: String,
+		#[serde(default)]
+		color: Option<String>,
+	},
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueMilestone {
+	#[serde(default, alias = "due_date")]
+	due_on: Option<String>,
+}
+
+pub async fn import_issues(filepath: PathBuf) -> Result<Vec<Project>, ImportIssuesError> {
+	let json = tokio::fs::read_to_string(&filepath)
+		.await
+		.map_err(|error| ImportIssuesError::FailedToOpenFile {
+			filepath: filepath.clone(),
+			error,
+		})?;
+
+	let fallback_project_name = filepath
+		.file_stem()
+		.map(|file_stem| file_stem.to_string_lossy().to_string())
+		.unwrap_or("Imported Issues".to_string());
+
+	import_issues_json(&json, &fallback_project_name)
+		.map_err(|error| ImportIssuesError::ParseError { filepath, error })
+}
+
+/// creates one project per repository, named like 'owner/repo'.
+/// the issue url is the origin of the task, so importing the same issues again updates them instead of duplicating them
+pub fn import_issues_json(
+	json: &str,
+	fallback_project_name: &str,
+) -> Result<Vec<Project>, serde_json::Error> {
+	let issues = match serde_json::from_str(json)? {
+		IssuesFormat::List(issues) => issues,
+		IssuesFormat::Search { items } => items,
+	};
+
+	let mut projects: Vec<Project> = Vec::new();
+
+	for issue in issues {
+		if issue.pull_request.is_some() {
+			continue;
+		}
+
+		let project_name =
+			repository_name(&issue.html_url).unwrap_or(fallback_project_name.to_string());
+
+		let project_index = match projects
+			.iter()
+			.position(|project| project.name == project_name)
+		{
+			Some(project_index) => project_index,
+			None => {
+				projects.push(Project::new(
+					project_name,
+					SerializableColor::from_iced_color(Color::WHITE),
+					OrderedHashMap::new(),
+					SortMode::default(),
+				));
+				projects.len() - 1
+			}
+		};
+		let project = &mut projects[project_index];
+
+		let mut tags = BTreeSet::new();
+		for label in issue.labels {
+			let (name, color) = match label {
+				IssueLabel::Name(name) => (name, None),
+				IssueLabel::Detailed { name, color } => (name, color),
+			};
+			let color = color
+				.and_then(|color| parse_hex_color(&color))
+				.unwrap_or_else(|| label_color(&name));
+
+			tags.insert(project.get_or_insert_task_tag(name, color));
+		}
+
+		let due_date = issue
+			.due_date
+			.or(issue.milestone.and_then(|milestone| milestone.due_on))
+			.and_then(|due_date| parse_due_date(&due_date));
+
+		let mut task = Task::new(
+			issue.title,
+			issue.body.unwrap_or_default(),
+			None,
+			None,
+			due_date,
+			tags,
+		);
+		task.origin = Some(TaskOrigin::new(ImportSource::Issues, issue.html_url));
+
+		if issue.state == "closed" {
+			project.done_tasks.insert(TaskId::generate(), task);
+		} else {
+			project.todo_tasks.insert(TaskId::generate(), task);
+		}
+	}
+
+	Ok(projects)
+}
+
+/// 'https://github.com/owner/repo/issues/1' or 'https://gitlab.com/group/project/-/issues/1'
+fn repository_name(issue_url: &str) -> Option<String> {
+	let (_scheme, url) = issue_url.split_once("://")?;
+	let (_host, path) = url.split_once('/')?;
+	let (repository, _issue_number) = path
+		.rsplit_once("/-/issues/")
+		.or_else(|| path.rsplit_once("/issues/"))?;
+	Some(repository.to_string())
+}
+
+/// '2025-03-14' (gitlab) or '2025-03-14T07:00:00Z' (github)
+fn parse_due_date(due_date: &str) -> Option<SerializableDate> {
+	NaiveDate::parse_from_str(due_date.get(..10)?, "%Y-%m-%d")
+		.ok()
+		.map(SerializableDate::from)
+}
+
+/// 'd73a4a' (github) or '#d73a4a' (gitlab)
+fn parse_hex_color(hex: &str) -> Option<SerializableColor> {
+	let hex = hex.trim_start_matches('#');
+	if hex.len() != 6 {
+		return None;
+	}
+	let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+	Some(SerializableColor([channel(0)?, channel(2)?, channel(4)?]))
+}
+
+/// labels without a color still get a distinct one that stays the same between imports
+fn label_color(name: &str) -> SerializableColor {
+	const LABEL_COLORS: [[u8; 3]; 8] = [
+		[215, 58, 74],
+		[0, 117, 202],
+		[14, 138, 22],
+		[251, 202, 4],
+		[162, 238, 239],
+		[216, 118, 227],
+		[255, 140, 0],
+		[112, 87, 255],
+	];
+	let hash = name.bytes().fold(0usize, |hash, byte| {
+		hash.wrapping_mul(31).wrapping_add(byte as usize)
+	});
+	SerializableColor(LABEL_COLORS[hash % LABEL_COLORS.len()])
+}
+
+pub async fn import_issues_dialog() -> Option<Result<Vec<Project>, ImportIssuesError>> {
+	let file_dialog_result = rfd::AsyncFileDialog::new()
+		.set_title("Import GitHub/GitLab Issues")
+		.add_filter("Issues (.json)", &["json"])
+		.pick_file()
+		.await;
+
+	match file_dialog_result {
+		Some(file_handle) => {
+			let filepath = file_handle.path().to_path_buf();
+			Some(import_issues(filepath).await)
+		}
+		None => None,
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use super::import_issues_json;
+	use project_tracker_core::{SerializableColor, SerializableDate, TaskOrigin};
+
+	const GITHUB_ISSUES: &str = r##"[
+		{
+			"url": "https://api.github.com/repos/owner/repo/issues/2",
+			"html_url": "https://github.com/owner/repo/issues/2",
+			"number": 2,
+			"title": "Crash on startup",
+			"body": "steps to reproduce",
+			"state": "open",
+			"labels": [{ "id": 1, "name": "bug", "color": "d73a4a" }],
+			"milestone": { "title": "v1.0", "due_on": "2025-03-14T07:00:00Z" }
+		},
+		{
+			"html_url": "https://github.com/owner/repo/issues/1",
+			"number": 1,
+			"title": "Add dark mode",
+			"body": null,
+			"state": "closed",
+			"labels": [{ "name": "enhancement", "color": "a2eeef" }, { "name": "bug", "color": "d73a4a" }],
+			"milestone": null
+		},
+		{
+			"html_url": "https://github.com/owner/repo/pull/3",
+			"title": "Fix crash",
+			"state": "open",
+			"labels": [],
+			"pull_request": { "url": "https://api.github.com/repos/owner/repo/pulls/3" }
+		}
+	]"##;
+
+	const GITLAB_ISSUES: &str = r##"[
+		{
+			"iid": 7,
+			"web_url": "https://gitlab.com/group/project/-/issues/7",
+			"title": "Update dependencies",
+			"description": "all of them",
+			"state": "opened",
+			"labels": ["maintenance"],
+			"milestone": { "title": "Q1", "due_date": "2025-03-31" },
+			"due_date": "2025-03-20"
+		}
+	]"##;
+
+	#[test]
+	fn test_import_github_issues() {
+		let projects = import_issues_json(GITHUB_ISSUES, "issues").unwrap();
+		assert_eq!(projects.len(), 1);
+		let project = &projects[0];
+		assert_eq!(project.name, "owner/repo");
+		// pull requests are skipped
+		assert_eq!(project.todo_tasks.len(), 1);
+		assert_eq!(project.done_tasks.len(), 1);
+		assert_eq!(project.task_tags.len(), 2);
+
+		let (_, crash) = project.todo_tasks.iter().next().unwrap();
+		assert_eq!(crash.name, "Crash on startup");
+		assert_eq!(crash.description, "steps to reproduce");
+		assert_eq!(
+			crash.origin.as_ref().and_then(TaskOrigin::url),
+			Some("https://github.com/owner/repo/issues/2")
+		);
+		assert_eq!(
+			crash.due_date,
+			Some(SerializableDate {
+				year: 2025,
+				month: 3,
+				day: 14
+			})
+		);
+		let bug_tag = project
+			.task_tags
+			.get(crash.tags.iter().next().unwrap())
+			.unwrap();
+		assert_eq!(bug_tag.name, "bug");
+		assert_eq!(bug_tag.color, SerializableColor([0xd7, 0x3a, 0x4a]));
+
+		let (_, dark_mode) = project.done_tasks.iter().next().unwrap();
+		assert_eq!(dark_mode.description, "");
+		assert_eq!(dark_mode.tags.len(), 2);
+	}
+
+	#[test]
+	fn test_import_gitlab_issues() {
+		let projects = import_issues_json(GITLAB_ISSUES, "issues").unwrap();
+		assert_eq!(projects.len(), 1);
+		let project = &projects[0];
+		assert_eq!(project.name, "group/project");
+
+		let (_, task) = project.todo_tasks.iter().next().unwrap();
+		assert_eq!(task.description, "all of them");
+		// the due date of the issue wins over the one of the milestone
+		assert_eq!(
+			task.due_date,
+			Some(SerializableDate {
+				year: 2025,
+				month: 3,
+				day: 20
+			})
+		);
+		assert_eq!(project.task_tags.len(), 1);
+	}
+}
@@ -4,6 +4,18 @@ pub use google_tasks::{
 	ImportGoogleTasksError,
 };
 
+mod issues;
+pub use issues::{import_issues, import_issues_dialog, import_issues_json, ImportIssuesError};
+
+mod ics;
+pub use ics::{import_ics, import_ics_dialog, import_ics_str, IcsParseError, ImportIcsError};
+
+mod todo_txt;
+pub use todo_txt::{
+	export_todo_txt, export_todo_txt_file_dialog, import_todo_txt, import_todo_txt_dialog,
+	import_todo_txt_str, projects_to_todo_txt, ImportTodoTxtError, TODO_TXT_FILE_NAME,
+};
+
 /*mod server;
 pub use server::{
 	connect_ws, ServerConfig, ServerConnectionStatus, ServerWsError, ServerWsEvent,
@@ -11,4 +23,7 @@ pub use server::{
 };*/
 
 mod code_editor;
-pub use code_editor::CodeEditor;
+pub use code_editor::{CodeEditor, CodeEditorError};
+
+mod git;
+pub use git::{git_blame, scan_git_log, GitError};
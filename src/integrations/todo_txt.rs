@@ -0,0 +1,403 @@
+use crate::core::IcedColorConversion;
+use chrono::NaiveDate;
+use iced::Color;
+use project_tracker_core::{
+	OrderedHashMap, Project, SaveDatabaseError, SaveDatabaseResult, SerializableColor,
+	SerializableDate, SortMode, Task, TaskId, TaskPriority,
+};
+use std::{collections::BTreeSet, path::PathBuf};
+use thiserror::Error;
+
+pub const TODO_TXT_FILE_NAME: &str = "todo.txt";
+
+#[derive(Debug, Error)]
+pub enum ImportTodoTxtError {
+	#[error("failed to open todo.txt file: {filepath}, error: {error}")]
+	FailedToOpenFile {
+		filepath: PathBuf,
+		error: std::io::Error,
+	},
+}
+
+pub async fn import_todo_txt(filepath: PathBuf) -> Result<Vec<Project>, ImportTodoTxtError> {
+	let todo_txt = tokio::fs::read_to_string(&filepath)
+		.await
+		.map_err(|error| ImportTodoTxtError::FailedToOpenFile {
+			filepath: filepath.clone(),
+			error,
+		})?;
+
+	let fallback_project_name = filepath
+		.file_stem()
+		.map(|file_stem| file_stem.to_string_lossy().to_string())
+		.unwrap_or("Imported Tasks".to_string());
+
+	Ok(import_todo_txt_str(&todo_txt, &fallback_project_name))
+}
+
+/// the first '+project' of a line decides the project, tasks without one end up in a project named 'fallback_project_name'.
+/// '@context' and 'key:value' pairs become tags, 'due:' the due date and 'pri:' keeps the priority of done tasks
+pub fn import_todo_txt_str(todo_txt: &str, fallback_project_name: &str) -> Vec<Project> {
+	let mut projects: Vec<Project> = Vec::new();
+
+	for line in todo_txt.lines() {
+		let Some(todo_txt_task) = TodoTxtTask::parse(line) else {
+			continue;
+		};
+
+		let project_name = todo_txt_task
+			.project
+			.as_deref()
+			.unwrap_or(fallback_project_name);
+
+		let project_index = match projects
+			.iter()
+			.position(|project| project.name == project_name)
+		{
+			Some(project_index) => project_index,
+			None => {
+				projects.push(Project::new(
+					project_name.to_string(),
+					SerializableColor::from_iced_color(Color::WHITE),
+					OrderedHashMap::new(),
+					SortMode::default(),
+				));
+				projects.len() - 1
+			}
+		};
+
+		todo_txt_task.insert_into(&mut projects[project_index]);
+	}
+
+	projects
+}
+
+#[derive(Debug, Default)]
+struct TodoTxtTask {
+	done: bool,
+	priority: TaskPriority,
+	name: String,
+	project: Option<String>,
+	tags: Vec<String>,
+	due_date: Option<SerializableDate>,
+}
+
+impl TodoTxtTask {
+	fn parse(line: &str) -> Option<Self> {
+		let mut words = line.split_whitespace().peekable();
+		words.peek()?;
+
+		let mut todo_txt_task = Self::default();
+
+		if words.peek() == Some(&"x") {
+			todo_txt_task.done = true;
+			words.next();
+		}
+		if let Some(priority) = words.peek().and_then(|word| parse_priority_word(word)) {
+			todo_txt_task.priority = priority;
+			words.next();
+		}
+		// completion and creation date
+		while words.peek().and_then(|word| parse_date(word)).is_some() {
+			words.next();
+		}
+
+		let mut name_words = Vec::new();
+		for word in words {
+			if let Some(project) = word.strip_prefix('+').filter(|project| !project.is_empty()) {
+				if todo_txt_task.project.is_none() {
+					todo_txt_task.project = Some(project.to_string());
+					continue;
+				}
+			} else if let Some(context) =
+				word.strip_prefix('@').filter(|context| !context.is_empty())
+			{
+				todo_txt_task.tags.push(context.to_string());
+				continue;
+			} else if let Some((key, value)) = parse_key_value(word) {
+				match key {
+					"due" => match parse_date(value) {
+						Some(due_date) => todo_txt_task.due_date = Some(due_date),
+						None => name_words.push(word),
+					},
+					"pri" => match parse_priority_letter(value) {
+						Some(priority) => todo_txt_task.priority = priority,
+						None => name_words.push(word),
+					},
+					_ => todo_txt_task.tags.push(word.to_string()),
+				}
+				continue;
+			}
+			name_words.push(word);
+		}
+		todo_txt_task.name = name_words.join(" ");
+
+		Some(todo_txt_task)
+	}
+
+	fn insert_into(self, project: &mut Project) {
+		let mut tags = BTreeSet::new();
+		for tag_name in self.tags {
+			tags.insert(project.get_or_insert_task_tag(
+				tag_name,
+				SerializableColor::from_iced_color(Color::WHITE),
+			));
+		}
+
+		let mut task = Task::new(self.name, String::new(), None, None, self.due_date, tags);
+		task.priority = self.priority;
+
+		if self.done {
+			project.done_tasks.insert(TaskId::generate(), task);
+		} else {
+			project.todo_tasks.insert(TaskId::generate(), task);
+		}
+	}
+}
+
+/// todo.txt is line based, so task descriptions, time spend and other task data isnt exported.
+/// whitespace in project and tag names is replaced with '_'
+pub fn projects_to_todo_txt<'a>(projects: impl IntoIterator<Item = &'a Project>) -> String {
+	let mut todo_txt = String::new();
+
+	for project in projects {
+		let project_word = format!("+{}", without_whitespace(&project.name));
+
+		let tasks = project
+			.todo_tasks
+			.iter()
+			.map(|(_, task)| (task, false))
+			.chain(project.done_tasks.iter().map(|(_, task)| (task, true)));
+
+		for (task, done) in tasks {
+			let mut words = Vec::new();
+			let priority_letter = priority_letter(task.priority);
+
+			if done {
+				words.push("x".to_string());
+			} else if let Some(priority_letter) = priority_letter {
+				words.push(format!("({priority_letter})"));
+			}
+			if !task.name.is_empty() {
+				words.push(task.name.replace(['\r', '\n'], " "));
+			}
+			words.push(project_word.clone());
+
+			// in the order of the project tags, so exports dont change between runs
+			for (_, task_tag) in project
+				.task_tags
+				.iter()
+				.filter(|(task_tag_id, _)| task.tags.contains(task_tag_id))
+			{
+				if parse_key_value(&task_tag.name).is_some() {
+					words.push(without_whitespace(&task_tag.name));
+				} else {
+					words.push(format!("@{}", without_whitespace(&task_tag.name)));
+				}
+			}
+
+			if let Some(due_date) = task.due_date {
+				words.push(format!(
+					"due:{:04}-{:02}-{:02}",
+					due_date.year, due_date.month, due_date.day
+				));
+			}
+			// done tasks cant start with a priority
+			if let Some(priority_letter) = priority_letter.filter(|_| done) {
+				words.push(format!("pri:{priority_letter}"));
+			}
+
+			todo_txt.push_str(&words.join(" "));
+			todo_txt.push('\n');
+		}
+	}
+
+	todo_txt
+}
+
+pub async fn export_todo_txt(filepath: PathBuf, todo_txt: String) -> SaveDatabaseResult<()> {
+	tokio::fs::write(filepath.as_path(), todo_txt)
+		.await
+		.map_err(|error| SaveDatabaseError::FailedToWriteToFile { filepath, error })
+}
+
+fn without_whitespace(name: &str) -> String {
+	name.split_whitespace().collect::<Vec<_>>().join("_")
+}
+
+fn priority_letter(priority: TaskPriority) -> Option<char> {
+	match priority {
+		TaskPriority::None => None,
+		TaskPriority::Urgent => Some('A'),
+		TaskPriority::High => Some('B'),
+		TaskPriority::Medium => Some('C'),
+		TaskPriority::Low => Some('D'),
+	}
+}
+
+fn parse_priority_letter(letter: &str) -> Option<TaskPriority> {
+	match letter {
+		"A" => Some(TaskPriority::Urgent),
+		"B" => Some(TaskPriority::High),
+		"C" => Some(TaskPriority::Medium),
+		_ if letter.len() == 1 && letter.chars().all(|c| c.is_ascii_uppercase()) => {
+			Some(TaskPriority::Low)
+		}
+		_ => None,
+	}
+}
+
+/// '(A)'
+fn parse_priority_word(word: &str) -> Option<TaskPriority> {
+	parse_priority_letter(word.strip_prefix('(')?.strip_suffix(')')?)
+}
+
+fn parse_date(date: &str) -> Option<SerializableDate> {
+	NaiveDate::parse_from_str(date, "%Y-%m-%d")
+		.ok()
+		.map(SerializableDate::from)
+}
+
+/// 'key:value', but not urls like 'https://...'
+fn parse_key_value(word: &str) -> Option<(&str, &str)> {
+	let (key, value) = word.split_once(':')?;
+	if key.is_empty() || value.is_empty() || value.contains(':') || value.starts_with("//") {
+		None
+	} else {
+		Some((key, value))
+	}
+}
+
+pub async fn import_todo_txt_dialog() -> Option<Result<Vec<Project>, ImportTodoTxtError>> {
+	let file_dialog_result = rfd::AsyncFileDialog::new()
+		.set_title("Import todo.txt")
+		.add_filter("todo.txt (.txt)", &["txt"])
+		.pick_file()
+		.await;
+
+	match file_dialog_result {
+		Some(file_handle) => {
+			let filepath = file_handle.path().to_path_buf();
+			Some(import_todo_txt(filepath).await)
+		}
+		None => None,
+	}
+}
+
+pub async fn export_todo_txt_file_dialog() -> Option<PathBuf> {
+	let file_dialog_result = rfd::AsyncFileDialog::new()
+		.set_title("Export ProjectTracker Database as todo.txt")
+		.set_file_name(TODO_TXT_FILE_NAME)
+		.add_filter("todo.txt (.txt)", &["txt"])
+		.save_file()
+		.await;
+
+	file_dialog_result.map(|file_handle| file_handle.path().to_path_buf())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+	use super::{import_todo_txt_str, projects_to_todo_txt};
+	use project_tracker_core::{Project, SerializableDate, TaskPriority};
+
+	const TODO_TXT: &str = "\
+(A) 2025-03-01 Call mom +Family @phone due:2025-03-14
+Buy milk @errands @store
+x 2025-03-02 2025-02-20 Renew passport +Admin pri:B
+(B) Pay bills +Admin @computer due:2025-03-31 t:2025-03-25
+(E) Read https://example.com/article +Family
+
+";
+
+	fn tag_names(project: &Project, task_index: usize, done: bool) -> Vec<String> {
+		let tasks = if done {
+			&project.done_tasks
+		} else {
+			&project.todo_tasks
+		};
+		let (_, task) = tasks.iter().nth(task_index).unwrap();
+		task.tags
+			.iter()
+			.map(|tag_id| project.task_tags.get(tag_id).unwrap().name.clone())
+			.collect()
+	}
+
+	#[test]
+	fn test_import_todo_txt() {
+		let projects = import_todo_txt_str(TODO_TXT, "todo");
+		let project_names: Vec<&str> = projects
+			.iter()
+			.map(|project| project.name.as_str())
+			.collect();
+		assert_eq!(project_names, vec!["Family", "todo", "Admin"]);
+
+		let family = &projects[0];
+		assert_eq!(family.todo_tasks.len(), 2);
+		let (_, call_mom) = family.todo_tasks.iter().next().unwrap();
+		assert_eq!(call_mom.name, "Call mom");
+		assert_eq!(call_mom.priority, TaskPriority::Urgent);
+		assert_eq!(
+			call_mom.due_date,
+			Some(SerializableDate {
+				year: 2025,
+				month: 3,
+				day: 14
+			})
+		);
+		assert_eq!(tag_names(family, 0, false), vec!["phone"]);
+
+		// urls arent tags
+		let (_, read_article) = family.todo_tasks.iter().nth(1).unwrap();
+		assert_eq!(read_article.name, "Read https://example.com/article");
+		assert_eq!(read_article.priority, TaskPriority::Low);
+
+		let todo = &projects[1];
+		assert_eq!(todo.todo_tasks.len(), 1);
+		assert_eq!(todo.task_tags.len(), 2);
+
+		let admin = &projects[2];
+		assert_eq!(admin.done_tasks.len(), 1);
+		let (_, passport) = admin.done_tasks.iter().next().unwrap();
+		assert_eq!(passport.name, "Renew passport");
+		assert_eq!(passport.priority, TaskPriority::High);
+		assert_eq!(tag_names(admin, 0, false).len(), 2);
+		assert!(tag_names(admin, 0, false).contains(&"t:2025-03-25".to_string()));
+	}
+
+	#[test]
+	fn test_todo_txt_round_trip() {
+		let projects = import_todo_txt_str(TODO_TXT, "todo");
+		let todo_txt = projects_to_todo_txt(&projects);
+		assert!(todo_txt.contains("(A) Call mom +Family @phone due:2025-03-14\n"));
+		assert!(todo_txt.contains("x Renew passport +Admin pri:B\n"));
+
+		let reimported_projects = import_todo_txt_str(&todo_txt, "todo");
+		assert_eq!(projects.len(), reimported_projects.len());
+
+		for (project, reimported_project) in projects.iter().zip(reimported_projects.iter()) {
+			assert_eq!(project.name, reimported_project.name);
+			assert_eq!(
+				project.todo_tasks.len(),
+				reimported_project.todo_tasks.len()
+			);
+			assert_eq!(
+				project.done_tasks.len(),
+				reimported_project.done_tasks.len()
+			);
+
+			for ((_, task, task_type), (_, reimported_task, reimported_task_type)) in
+				project.iter().zip(reimported_project.iter())
+			{
+				assert_eq!(task.name, reimported_task.name);
+				assert_eq!(task.due_date, reimported_task.due_date);
+				assert_eq!(task.priority, reimported_task.priority);
+				assert_eq!(task_type, reimported_task_type);
+				assert_eq!(task.tags.len(), reimported_task.tags.len());
+			}
+		}
+
+		// exporting again gives the same file
+		assert_eq!(todo_txt, projects_to_todo_txt(&reimported_projects));
+	}
+}
@@ -1,39 +1,48 @@
 use crate::{
 	components::{
-		close_create_new_task_modal_button, create_new_task_modal_button, due_date_button,
-		edit_needed_time_button, horizontal_scrollable, task_description_editor, task_tag_list,
-		vertical_scrollable, SCROLLBAR_WIDTH,
+		close_create_new_task_modal_button, create_new_task_modal_button, date_text,
+		due_date_button, duration_text, edit_needed_time_button, horizontal_scrollable,
+		task_description_editor, task_tag_list, vertical_scrollable, SCROLLBAR_WIDTH,
 	},
-	core::SerializableDateConversion,
+	core::{IcedColorConversion, SerializableDateConversion},
 	project_tracker,
 	styles::{
-		card_style, text_input_style_borderless, unindent_text, HEADING_TEXT_SIZE,
-		LARGE_SPACING_AMOUNT, LARGE_TEXT_SIZE, SMALL_PADDING_AMOUNT, SPACING_AMOUNT,
+		card_style, checkbox_style, grey_text_style, rounded_container_style,
+		task_tag_container_style, text_input_style_borderless, text_input_style_default,
+		unindent_text, HEADING_TEXT_SIZE, LARGE_SPACING_AMOUNT, LARGE_TEXT_SIZE,
+		SMALL_HORIZONTAL_PADDING, SMALL_PADDING_AMOUNT, SMALL_TEXT_SIZE, SPACING_AMOUNT,
 	},
 	OptionalPreference, Preferences,
 };
+use chrono::Local;
 use iced::{
+	alignment::Vertical,
 	font,
-	widget::{column, container, row, text, text_editor, text_input, Row, Space},
-	Element, Font,
+	widget::{checkbox, column, container, row, text, text_editor, text_input, Row, Space},
+	Color, Element, Font,
 	Length::Fill,
 	Padding,
 };
 use iced_aw::card;
 use project_tracker_core::{
-	duration_to_minutes, parse_duration_from_str, Database, ProjectId, SerializableDate, TaskId,
-	TaskTagId, TimeSpend,
+	duration_to_minutes, parse_duration_from_str, Database, Project, ProjectId, QuickAddTask,
+	SerializableColor, SerializableDate, TaskId, TaskTag, TaskTagId, TimeSpend,
 };
 use std::collections::BTreeSet;
 use std::sync::LazyLock;
+use std::time::Duration;
 use tracing::error;
 
+static QUICK_ADD_INPUT_ID: LazyLock<text_input::Id> = LazyLock::new(text_input::Id::unique);
 static TASK_NAME_INPUT_ID: LazyLock<text_input::Id> = LazyLock::new(text_input::Id::unique);
 static EDIT_NEEDED_TIME_INPUT_ID: LazyLock<text_input::Id> = LazyLock::new(text_input::Id::unique);
 
 #[derive(Debug, Clone)]
 pub enum Message {
 	CreateTask,
+	ChangeQuickAddInput(String),
+	QuickAddTask,
+	SetCreateMissingTaskTags(bool),
 	ChangeTaskName(String),
 	TaskDescriptionAction(text_editor::Action),
 	UnindentDescription,
@@ -67,6 +76,7 @@ pub enum Action {
 		needed_time_minutes: Option<usize>,
 		time_spend: Option<TimeSpend>,
 		create_at_top: bool,
+		new_task_tags: Vec<(TaskTagId, TaskTag)>,
 	},
 }
 
@@ -78,6 +88,9 @@ impl From<iced::Task<Message>> for Action {
 
 pub struct Modal {
 	project_id: ProjectId,
+	quick_add_input: String,
+	quick_add_task: Option<QuickAddTask>,
+	create_missing_task_tags: bool,
 	task_name: String,
 	task_description: text_editor::Content,
 	task_tags: BTreeSet<TaskTagId>,
@@ -90,6 +103,9 @@ impl Modal {
 	pub fn new(project_id: ProjectId) -> Self {
 		Self {
 			project_id,
+			quick_add_input: String::new(),
+			quick_add_task: None,
+			create_missing_task_tags: true,
 			task_name: String::new(),
 			task_description: text_editor::Content::new(),
 			task_tags: BTreeSet::new(),
@@ -99,8 +115,31 @@ impl Modal {
 		}
 	}
 
+	pub fn focus_quick_add_input() -> iced::Task<project_tracker::Message> {
+		text_input::focus(QUICK_ADD_INPUT_ID.clone())
+	}
+
+	fn parse_quick_add_input(&mut self, database: Option<&Database>) {
+		self.quick_add_task = database
+			.and_then(|db| db.get_project(&self.project_id))
+			.filter(|_| !self.quick_add_input.trim().is_empty())
+			.map(|project| {
+				QuickAddTask::parse(
+					&self.quick_add_input,
+					&project.task_tags,
+					self.create_missing_task_tags,
+					Local::now().date_naive(),
+				)
+			});
+	}
+
 	#[must_use]
-	pub fn update(&mut self, message: Message, preferences: &Option<Preferences>) -> Action {
+	pub fn update(
+		&mut self,
+		message: Message,
+		database: Option<&Database>,
+		preferences: &Option<Preferences>,
+	) -> Action {
 		match message {
 			Message::CreateTask => Action::CreateTask {
 				project_id: self.project_id,
@@ -114,7 +153,63 @@ impl Modal {
 				}),
 				time_spend: None,
 				create_at_top: preferences.create_new_tasks_at_top(),
+				new_task_tags: Vec::new(),
 			},
+			Message::ChangeQuickAddInput(new_input) => {
+				self.quick_add_input = new_input;
+				self.parse_quick_add_input(database);
+				Action::None
+			}
+			Message::SetCreateMissingTaskTags(create_missing_task_tags) => {
+				self.create_missing_task_tags = create_missing_task_tags;
+				self.parse_quick_add_input(database);
+				Action::None
+			}
+			Message::QuickAddTask => {
+				self.parse_quick_add_input(database);
+				match self.quick_add_task.take() {
+					Some(quick_add_task) if !quick_add_task.name.is_empty() => {
+						let new_task_tags: Vec<(TaskTagId, TaskTag)> = quick_add_task
+							.new_task_tag_names
+							.into_iter()
+							.map(|name| {
+								(
+									TaskTagId::generate(),
+									TaskTag::new(
+										name,
+										SerializableColor::from_iced_color(Color::WHITE),
+									),
+								)
+							})
+							.collect();
+
+						let mut task_tags = self.task_tags.clone();
+						task_tags.extend(quick_add_task.task_tags);
+						task_tags.extend(new_task_tags.iter().map(|(task_tag_id, _)| *task_tag_id));
+
+						Action::CreateTask {
+							project_id: self.project_id,
+							task_id: TaskId::generate(),
+							task_name: quick_add_task.name,
+							task_description: self.task_description.text(),
+							task_tags,
+							due_date: quick_add_task.due_date.or(self.due_date),
+							needed_time_minutes: quick_add_task.needed_time_minutes.or(self
+								.needed_time_minutes
+								.as_ref()
+								.and_then(|needed_time| {
+									parse_duration_from_str(needed_time).map(duration_to_minutes)
+								})),
+							time_spend: None,
+							create_at_top: quick_add_task
+								.create_at_top
+								.unwrap_or(preferences.create_new_tasks_at_top()),
+							new_task_tags,
+						}
+					}
+					_ => Action::None,
+				}
+			}
 			Message::ChangeTaskName(new_task_name) => {
 				self.task_name = new_task_name;
 				Action::None
@@ -206,62 +301,89 @@ impl Modal {
 				None => text("Create Task"),
 			}
 			.size(LARGE_TEXT_SIZE),
-			container(vertical_scrollable(column![
-				match database
-					.as_ref()
-					.and_then(|db| db.get_project(&self.project_id))
-				{
-					Some(project) => {
-						let task_tags_list: Vec<Element<project_tracker::Message>> =
-							task_tag_list(project, &self.task_tags, |task_tag_id| {
-								Message::ToggleTaskTag(task_tag_id).into()
-							});
+			container(vertical_scrollable(
+				column![
+					text_input(
+						"Quick add: Fix login crash #Bug @tomorrow ~2h !top",
+						&self.quick_add_input
+					)
+					.id(QUICK_ADD_INPUT_ID.clone())
+					.on_input(|input| Message::ChangeQuickAddInput(input).into())
+					.on_submit(Message::QuickAddTask.into())
+					.style(text_input_style_default),
+					row![
+						Space::new(Fill, 0.0),
+						checkbox("Create missing tags", self.create_missing_task_tags)
+							.on_toggle(|create| Message::SetCreateMissingTaskTags(create).into())
+							.text_size(SMALL_TEXT_SIZE)
+							.style(checkbox_style),
+					],
+				]
+				.push_maybe(self.quick_add_task.as_ref().and_then(|quick_add_task| {
+					database
+						.and_then(|db| db.get_project(&self.project_id))
+						.map(|project| quick_add_preview(quick_add_task, project, preferences))
+				}))
+				.push(Space::new(0.0, SPACING_AMOUNT))
+				.push(
+					match database
+						.as_ref()
+						.and_then(|db| db.get_project(&self.project_id))
+					{
+						Some(project) => {
+							let task_tags_list: Vec<Element<project_tracker::Message>> =
+								task_tag_list(project, &self.task_tags, |task_tag_id| {
+									Message::ToggleTaskTag(task_tag_id).into()
+								});
 
-						if task_tags_list.is_empty() {
-							Element::new(Space::new(0.0, 0.0))
-						} else {
-							horizontal_scrollable(
-								Row::with_children(task_tags_list).spacing(SPACING_AMOUNT),
-							)
-							.width(Fill)
-							.into()
+							if task_tags_list.is_empty() {
+								Element::new(Space::new(0.0, 0.0))
+							} else {
+								horizontal_scrollable(
+									Row::with_children(task_tags_list).spacing(SPACING_AMOUNT),
+								)
+								.width(Fill)
+								.into()
+							}
 						}
-					}
-					None => {
-						error!("invalid project_id: doesnt exist in database!");
-						Element::new(text("<invalid project id>"))
-					}
-				},
-				text_input("task name", &self.task_name)
-					.id(TASK_NAME_INPUT_ID.clone())
-					.on_input(|name| Message::ChangeTaskName(name).into())
-					.on_submit(Message::CreateTask.into())
-					.style(|t, s| text_input_style_borderless(t, s, true))
-					.size(HEADING_TEXT_SIZE)
-					.font(Font {
-						weight: font::Weight::Bold,
-						..Default::default()
-					}),
-				Space::new(0.0, SPACING_AMOUNT),
-				text("Description:"),
-				task_description_editor(
-					&self.task_description,
-					|action| { Message::TaskDescriptionAction(action) },
-					None,
-					Message::UnindentDescription
+						None => {
+							error!("invalid project_id: doesnt exist in database!");
+							Element::new(text("<invalid project id>"))
+						}
+					},
 				)
-				.map(project_tracker::Message::CreateTaskModalMessage),
-				Space::new(0.0, LARGE_SPACING_AMOUNT),
-				row![
-					edit_needed_time_view,
-					due_date_view,
-					Space::new(Fill, 0.0),
-					create_new_task_modal_button(),
-					close_create_new_task_modal_button()
-				]
-				.spacing(SPACING_AMOUNT)
-				.width(Fill)
-			]))
+				.push(column![
+					text_input("task name", &self.task_name)
+						.id(TASK_NAME_INPUT_ID.clone())
+						.on_input(|name| Message::ChangeTaskName(name).into())
+						.on_submit(Message::CreateTask.into())
+						.style(|t, s| text_input_style_borderless(t, s, true))
+						.size(HEADING_TEXT_SIZE)
+						.font(Font {
+							weight: font::Weight::Bold,
+							..Default::default()
+						}),
+					Space::new(0.0, SPACING_AMOUNT),
+					text("Description:"),
+					task_description_editor(
+						&self.task_description,
+						|action| { Message::TaskDescriptionAction(action) },
+						None,
+						Message::UnindentDescription
+					)
+					.map(project_tracker::Message::CreateTaskModalMessage),
+					Space::new(0.0, LARGE_SPACING_AMOUNT),
+					row![
+						edit_needed_time_view,
+						due_date_view,
+						Space::new(Fill, 0.0),
+						create_new_task_modal_button(),
+						close_create_new_task_modal_button()
+					]
+					.spacing(SPACING_AMOUNT)
+					.width(Fill)
+				]),
+			))
 			.padding(Padding::default().bottom(SCROLLBAR_WIDTH + SMALL_PADDING_AMOUNT)),
 		)
 		.max_width(600.0)
@@ -271,3 +393,77 @@ impl Modal {
 		.into()
 	}
 }
+
+fn quick_add_preview<'a>(
+	quick_add_task: &'a QuickAddTask,
+	project: &'a Project,
+	preferences: &Option<Preferences>,
+) -> Element<'a, project_tracker::Message> {
+	let field =
+		|content: Element<'a, project_tracker::Message>| -> Element<'a, project_tracker::Message> {
+			container(content)
+				.padding(SMALL_HORIZONTAL_PADDING)
+				.style(rounded_container_style)
+				.into()
+		};
+
+	let tags = quick_add_task
+		.task_tags
+		.iter()
+		.filter_map(|task_tag_id| project.task_tags.get(task_tag_id))
+		.map(|task_tag| -> Element<project_tracker::Message> {
+			let color = task_tag.color.to_iced_color();
+			container(text(&task_tag.name).size(SMALL_TEXT_SIZE))
+				.padding(SMALL_HORIZONTAL_PADDING)
+				.style(move |t| task_tag_container_style(t, color))
+				.into()
+		})
+		.chain(
+			quick_add_task
+				.new_task_tag_names
+				.iter()
+				.map(|new_tag_name| {
+					field(
+						text!("+ {new_tag_name}")
+							.size(SMALL_TEXT_SIZE)
+							.style(grey_text_style)
+							.into(),
+					)
+				}),
+		);
+
+	horizontal_scrollable(
+		Row::new()
+			.push(text(&quick_add_task.name).size(SMALL_TEXT_SIZE))
+			.extend(tags)
+			.push_maybe(quick_add_task.due_date.as_ref().map(|due_date| {
+				field(
+					date_text(due_date, preferences.date_formatting())
+						.size(SMALL_TEXT_SIZE)
+						.into(),
+				)
+			}))
+			.push_maybe(
+				quick_add_task
+					.needed_time_minutes
+					.map(|needed_time_minutes| {
+						field(
+							duration_text(Duration::from_secs(needed_time_minutes as u64 * 60))
+								.size(SMALL_TEXT_SIZE)
+								.into(),
+						)
+					}),
+			)
+			.push_maybe(quick_add_task.create_at_top.map(|create_at_top| {
+				field(
+					text(if create_at_top { "top" } else { "bottom" })
+						.size(SMALL_TEXT_SIZE)
+						.into(),
+				)
+			}))
+			.spacing(SPACING_AMOUNT)
+			.align_y(Vertical::Center),
+	)
+	.width(Fill)
+	.into()
+}
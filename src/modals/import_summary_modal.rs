@@ -0,0 +1,53 @@
+use crate::{components::import_summary_ok_button, project_tracker, styles::card_style};
+use iced::{
+	widget::{column, text},
+	Element,
+};
+use iced_aw::card;
+use project_tracker_core::ImportSummary;
+
+pub enum Modal {
+	Open { summary: ImportSummary },
+	Closed,
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+	Open(ImportSummary),
+	Close,
+}
+
+impl From<Message> for project_tracker::Message {
+	fn from(value: Message) -> Self {
+		project_tracker::Message::ImportSummaryModalMessage(value)
+	}
+}
+
+impl Modal {
+	pub fn update(&mut self, message: Message) {
+		match message {
+			Message::Open(summary) => *self = Modal::Open { summary },
+			Message::Close => *self = Modal::Closed,
+		}
+	}
+
+	pub fn view(&self) -> Option<Element<'_, project_tracker::Message>> {
+		match self {
+			Modal::Open { summary } => Some(
+				card(
+					text("Import finished"),
+					column![
+						text(format!("{} tasks created", summary.created)),
+						text(format!("{} tasks updated", summary.updated)),
+						text(format!("{} tasks unchanged", summary.unchanged)),
+						import_summary_ok_button(),
+					],
+				)
+				.max_width(300.0)
+				.style(card_style)
+				.into(),
+			),
+			Modal::Closed => None,
+		}
+	}
+}
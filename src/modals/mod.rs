@@ -1,11 +1,15 @@
 pub mod error_msg_modal;
 
+pub mod import_summary_modal;
+
 pub mod confirm_modal;
 
 pub mod settings_modal;
 
 pub mod manage_task_tags_modal;
 
+pub mod source_code_todo_settings_modal;
+
 pub mod task_modal;
 
 pub mod create_task_modal;
@@ -1,18 +1,19 @@
 use crate::components::{
 	code_editor_dropdown_button, export_as_json_database_button,
-	export_database_as_markdown_button, horizontal_seperator_padded, import_json_database_button,
-	loading_screen, synchronization_settings_button, vertical_scrollable,
+	export_database_as_icalendar_button, export_database_as_markdown_button,
+	export_database_as_todo_txt_button, horizontal_seperator_padded, import_json_database_button,
+	import_todo_txt_button, loading_screen, synchronization_settings_button, vertical_scrollable,
 	vertical_scrollable_no_padding, LARGE_LOADING_SPINNER_SIZE,
 };
 use crate::icons::{icon_to_text, Bootstrap};
-use crate::integrations::CodeEditor;
+use crate::integrations::{CodeEditor, CodeEditorError};
 use crate::project_tracker::{self, ProjectTrackerApp};
 use crate::styles::{
-	command_background_container_style, grey_text_style, link_color, logs_scrollable_style,
-	markdown_background_container_style, rounded_container_style, text_input_style_default,
-	tooltip_container_style, GAP, HEADING_TEXT_SIZE, LARGE_TEXT_SIZE, MONOSPACE_FONT,
-	SMALL_HORIZONTAL_PADDING, SMALL_PADDING_AMOUNT, SMALL_SPACING_AMOUNT, SMALL_TEXT_SIZE,
-	SPACING_AMOUNT,
+	command_background_container_style, danger_text_style, grey_text_style, link_color,
+	logs_scrollable_style, markdown_background_container_style, rounded_container_style,
+	text_input_style_default, tooltip_container_style, GAP, HEADING_TEXT_SIZE, LARGE_TEXT_SIZE,
+	MONOSPACE_FONT, SMALL_HORIZONTAL_PADDING, SMALL_PADDING_AMOUNT, SMALL_SPACING_AMOUNT,
+	SMALL_TEXT_SIZE, SPACING_AMOUNT,
 };
 use crate::synchronization::{
 	browse_filesystem_synchronization_filepath_dialog, BaseSynchronization,
@@ -21,10 +22,14 @@ use crate::synchronization::{ServerConfig, ServerSynchronization, Synchronizatio
 use crate::{
 	components::{
 		dangerous_button, export_database_button, file_location, import_database_button,
-		import_google_tasks_button, settings_tab_button, vertical_seperator,
+		import_google_tasks_button, import_ics_button, import_issues_button,
+		import_markdown_button, settings_tab_button, vertical_seperator,
 		HORIZONTAL_SCROLLABLE_PADDING, ICON_FONT_SIZE,
 	},
-	integrations::import_google_tasks_dialog,
+	core::import_markdown_folder_dialog,
+	integrations::{
+		import_google_tasks_dialog, import_ics_dialog, import_issues_dialog, import_todo_txt_dialog,
+	},
 	modals::error_msg_modal,
 	styles::{card_style, PADDING_AMOUNT},
 	DateFormatting, PreferenceAction, PreferenceMessage, Preferences,
@@ -64,6 +69,14 @@ pub enum Message {
 
 	ImportGoogleTasksFileDialog,
 	ImportGoogleTasksFileDialogCanceled,
+	ImportIcsFileDialog,
+	ImportIcsFileDialogCanceled,
+	ImportIssuesFileDialog,
+	ImportIssuesFileDialogCanceled,
+	ImportTodoTxtFileDialog,
+	ImportTodoTxtFileDialogCanceled,
+	ImportMarkdownFolderDialog,
+	ImportMarkdownFolderDialogCanceled,
 
 	BrowseSynchronizationFilepath,
 	BrowseSynchronizationFilepathCanceled,
@@ -281,7 +294,7 @@ impl Modal {
 			Message::ImportGoogleTasksFileDialog => {
 				Task::perform(import_google_tasks_dialog(), move |result| match result {
 					Some(result) => match result {
-						Ok(projects) => DatabaseMessage::ImportProjects(projects).into(),
+						Ok(projects) => project_tracker::Message::ImportProjects(projects),
 						Err(import_error) => error_msg_modal::Message::open_error(import_error),
 					},
 					None => Message::ImportGoogleTasksFileDialogCanceled.into(),
@@ -290,6 +303,55 @@ impl Modal {
 			}
 			Message::ImportGoogleTasksFileDialogCanceled => PreferenceAction::None,
 
+			Message::ImportIcsFileDialog => {
+				Task::perform(import_ics_dialog(), move |result| match result {
+					Some(result) => match result {
+						Ok(projects) => project_tracker::Message::ImportProjects(projects),
+						Err(import_error) => error_msg_modal::Message::open_error(import_error),
+					},
+					None => Message::ImportIcsFileDialogCanceled.into(),
+				})
+				.into()
+			}
+			Message::ImportIcsFileDialogCanceled => PreferenceAction::None,
+
+			Message::ImportIssuesFileDialog => {
+				Task::perform(import_issues_dialog(), move |result| match result {
+					Some(result) => match result {
+						Ok(projects) => project_tracker::Message::ImportProjects(projects),
+						Err(import_error) => error_msg_modal::Message::open_error(import_error),
+					},
+					None => Message::ImportIssuesFileDialogCanceled.into(),
+				})
+				.into()
+			}
+			Message::ImportIssuesFileDialogCanceled => PreferenceAction::None,
+
+			Message::ImportTodoTxtFileDialog => {
+				Task::perform(import_todo_txt_dialog(), move |result| match result {
+					Some(result) => match result {
+						Ok(projects) => project_tracker::Message::ImportProjects(projects),
+						Err(import_error) => error_msg_modal::Message::open_error(import_error),
+					},
+					None => Message::ImportTodoTxtFileDialogCanceled.into(),
+				})
+				.into()
+			}
+			Message::ImportTodoTxtFileDialogCanceled => PreferenceAction::None,
+
+			Message::ImportMarkdownFolderDialog => Task::perform(
+				import_markdown_folder_dialog(),
+				move |result| match result {
+					Some(result) => match result {
+						Ok(projects) => project_tracker::Message::ImportProjects(projects),
+						Err(import_error) => error_msg_modal::Message::open_error(import_error),
+					},
+					None => Message::ImportMarkdownFolderDialogCanceled.into(),
+				},
+			)
+			.into(),
+			Message::ImportMarkdownFolderDialogCanceled => PreferenceAction::None,
+
 			Message::BrowseSynchronizationFilepath => Task::perform(
 				browse_filesystem_synchronization_filepath_dialog(),
 				|file_synchronization| match file_synchronization {
@@ -460,7 +522,19 @@ fn database_settings_tab_view<'a>(
 					]
 					.spacing(SPACING_AMOUNT),
 
-					export_database_as_markdown_button(app.exporting_database),
+					row![
+						import_markdown_button(),
+						export_database_as_markdown_button(app.exporting_database),
+					]
+					.spacing(SPACING_AMOUNT),
+
+					row![
+						import_todo_txt_button(),
+						export_database_as_todo_txt_button(app.exporting_database),
+					]
+					.spacing(SPACING_AMOUNT),
+
+					export_database_as_icalendar_button(app.exporting_database),
 				]
 				.spacing(SPACING_AMOUNT)
 			]
@@ -537,6 +611,52 @@ fn database_settings_tab_view<'a>(
 			.padding(Padding{ left: PADDING_AMOUNT, ..Padding::ZERO })
 		]
 		.spacing(SPACING_AMOUNT),
+
+		horizontal_seperator_padded(),
+
+		column![
+			row![
+				"Import iCalendar Tasks:",
+
+				container(
+					import_ics_button()
+				)
+				.width(Fill)
+				.align_x(Horizontal::Right),
+			]
+			.spacing(SPACING_AMOUNT)
+			.align_y(Alignment::Center),
+
+			container(
+				text("Export the task list from your calendar app (Thunderbird, Nextcloud, Apple Reminders, ...) as an \".ics\" file.\nEvery calendar becomes a project, categories become tags.")
+					.style(grey_text_style)
+			)
+			.padding(Padding{ left: PADDING_AMOUNT, ..Padding::ZERO })
+		]
+		.spacing(SPACING_AMOUNT),
+
+		horizontal_seperator_padded(),
+
+		column![
+			row![
+				"Import GitHub/GitLab Issues:",
+
+				container(
+					import_issues_button()
+				)
+				.width(Fill)
+				.align_x(Horizontal::Right),
+			]
+			.spacing(SPACING_AMOUNT)
+			.align_y(Alignment::Center),
+
+			container(
+				text("Save the response of \"https://api.github.com/repos/OWNER/REPO/issues?state=all\" or \"https://gitlab.com/api/v4/projects/ID/issues\" as a \".json\" file.\nImporting the same issues again updates the already imported tasks.")
+					.style(grey_text_style)
+			)
+			.padding(Padding{ left: PADDING_AMOUNT, ..Padding::ZERO })
+		]
+		.spacing(SPACING_AMOUNT),
 	]
 	.spacing(SPACING_AMOUNT)
 	.into()
@@ -562,6 +682,8 @@ fn shortcuts_settings_tab_view() -> Element<'static, project_tracker::Message> {
 		shortcut("Switch to lower Project:", "Ctrl + Tab"),
 		shortcut("Switch to upper Project:", "Ctrl + Shift + Tab"),
 		shortcut("New Task:", "Ctrl + N"),
+		shortcut("Select Tasks:", "Ctrl/Shift + Click"),
+		shortcut("Clear Task Selection:", "Esc"),
 		shortcut("Toggle Sidebar:", "Ctrl + B"),
 		shortcut("Start/Pause/Resume Stopwatch:", "Space"),
 		shortcut("Stop Stopwatch:", "Esc"),
@@ -602,7 +724,7 @@ fn code_editor_settings_tab_view(
 								"Custom Editor Command:",
 								tooltip(
 									icon_to_text(Bootstrap::QuestionCircleFill).size(ICON_FONT_SIZE),
-									text("the file location formatted as \"filepath:line:column\" is added to the end of this command")
+									text("use {file}, {line} and {column} as placeholders, quote arguments with spaces like in a shell.\nwithout placeholders the file location formatted as \"filepath:line:column\" is added to the end of this command")
 										.size(SMALL_TEXT_SIZE),
 									tooltip::Position::Bottom,
 								)
@@ -610,7 +732,7 @@ fn code_editor_settings_tab_view(
 								.style(tooltip_container_style),
 							]
 							.spacing(SPACING_AMOUNT),
-							text_input("custom_editor --open", command.as_str())
+							text_input("custom_editor --goto {file}:{line}:{column}", command.as_str())
 								.width(Length::Fixed(350.0))
 								.on_input(|new_command| Message::SetCodeEditor(Some(
 									CodeEditor::Custom {
@@ -626,14 +748,17 @@ fn code_editor_settings_tab_view(
 								.width(Fill)
 								.style(grey_text_style),
 
-							container(
-								rich_text![
-									Span::new(command).color(Color::from_rgb8(154, 196, 248)),
-									Span::new(" \"file.txt:42:15\"").color(Color::from_rgb8(162, 250, 163))
-								]
-							)
-							.padding(Padding::new(SMALL_PADDING_AMOUNT))
-							.style(command_background_container_style),
+							match custom_code_editor_command_line(name, command) {
+								Ok((program, args)) => container(
+									rich_text![
+										Span::new(program).color(Color::from_rgb8(154, 196, 248)),
+										Span::new(args).color(Color::from_rgb8(162, 250, 163))
+									]
+								)
+								.padding(Padding::new(SMALL_PADDING_AMOUNT))
+								.style(command_background_container_style),
+								Err(e) => container(text(e.to_string()).style(danger_text_style)),
+							},
 						]
 						.align_y(Vertical::Center)
 						.spacing(SPACING_AMOUNT)
@@ -649,6 +774,27 @@ fn code_editor_settings_tab_view(
 	.into()
 }
 
+/// the quoted program and arguments that open an example file location
+fn custom_code_editor_command_line(
+	name: &str,
+	command: &str,
+) -> Result<(String, String), CodeEditorError> {
+	let custom_code_editor = CodeEditor::Custom {
+		name: name.to_string(),
+		command: command.to_string(),
+	};
+	let command_line = custom_code_editor.command_line("my file.txt:42:15")?;
+	let (program, args) = command_line
+		.split_first()
+		.ok_or(CodeEditorError::EmptyCommand)?;
+	Ok((
+		shell_words::quote(program).into_owned(),
+		args.iter()
+			.map(|arg| format!(" {}", shell_words::quote(arg)))
+			.collect(),
+	))
+}
+
 fn admin_infos_settings_tab_view(
 	admin_infos: Option<&AdminInfos>,
 ) -> Element<project_tracker::Message> {
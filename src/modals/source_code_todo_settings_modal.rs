@@ -0,0 +1,312 @@
+use crate::{
+	components::{remove_source_code_todo_keyword_button, task_tag_button},
+	project_tracker,
+	styles::{card_style, LARGE_TEXT_SIZE, SMALL_SPACING_AMOUNT, SMALL_TEXT_SIZE, SPACING_AMOUNT},
+	ProjectTrackerApp,
+};
+use iced::{
+	alignment::Vertical,
+	widget::{column, container, row, text, text_input, toggler, Column, Row, Space},
+	Element,
+};
+use iced_aw::card;
+use project_tracker_core::{
+	Database, DatabaseMessage, Project, ProjectId, SourceCodeTodoKeyword, SourceCodeTodoSettings,
+	TaskTagId,
+};
+
+#[derive(Debug, Clone)]
+pub enum Message {
+	ChangeNewKeyword(String),
+	AddKeyword,
+	RemoveKeyword(usize),
+	ToggleKeywordTaskTag {
+		keyword_index: usize,
+		task_tag_id: TaskTagId,
+	},
+	ChangeIncludeGlobs(String),
+	ApplyIncludeGlobs,
+	ChangeExcludeGlobs(String),
+	ApplyExcludeGlobs,
+	SetRespectIgnoreFiles(bool),
+	SetGitBlame(bool),
+	SetMarkDoneByCommits(bool),
+	ChangeMaxFileSize(String),
+	ApplyMaxFileSize,
+}
+
+impl From<Message> for project_tracker::Message {
+	fn from(value: Message) -> Self {
+		project_tracker::Message::SourceCodeTodoSettingsModalMessage(value)
+	}
+}
+
+pub enum Action {
+	None,
+	DatabaseMessage(DatabaseMessage),
+}
+impl From<DatabaseMessage> for Action {
+	fn from(value: DatabaseMessage) -> Self {
+		Self::DatabaseMessage(value)
+	}
+}
+
+pub struct Modal {
+	project_id: ProjectId,
+	new_keyword: String,
+	include_globs: String,
+	exclude_globs: String,
+	max_file_size_kb: String,
+}
+
+impl Modal {
+	pub fn new(project_id: ProjectId, database: Option<&Database>) -> Self {
+		let settings = database
+			.and_then(|db| db.get_project(&project_id))
+			.map(|project| project.source_code_todo_settings.clone())
+			.unwrap_or_default();
+
+		Self {
+			project_id,
+			new_keyword: String::new(),
+			include_globs: settings.include_globs.join(", "),
+			exclude_globs: settings.exclude_globs.join(", "),
+			max_file_size_kb: settings
+				.max_file_size_bytes
+				.map(|max_file_size_bytes| (max_file_size_bytes / 1024).to_string())
+				.unwrap_or_default(),
+		}
+	}
+
+	#[must_use]
+	pub fn update(&mut self, message: Message, database: Option<&Database>) -> Action {
+		match message {
+			Message::ChangeNewKeyword(new_keyword) => {
+				self.new_keyword = new_keyword;
+				Action::None
+			}
+			Message::AddKeyword => {
+				let new_keyword = std::mem::take(&mut self.new_keyword).trim().to_string();
+				if new_keyword.is_empty() {
+					return Action::None;
+				}
+				self.change_settings(database, |settings| {
+					if settings.get_keyword(&new_keyword).is_none() {
+						settings
+							.keywords
+							.push(SourceCodeTodoKeyword::new(new_keyword, None));
+					}
+				})
+			}
+			Message::RemoveKeyword(keyword_index) => self.change_settings(database, |settings| {
+				if keyword_index < settings.keywords.len() {
+					settings.keywords.remove(keyword_index);
+				}
+			}),
+			Message::ToggleKeywordTaskTag {
+				keyword_index,
+				task_tag_id,
+			} => self.change_settings(database, |settings| {
+				if let Some(keyword) = settings.keywords.get_mut(keyword_index) {
+					keyword.task_tag = if keyword.task_tag == Some(task_tag_id) {
+						None
+					} else {
+						Some(task_tag_id)
+					};
+				}
+			}),
+			Message::ChangeIncludeGlobs(include_globs) => {
+				self.include_globs = include_globs;
+				Action::None
+			}
+			Message::ApplyIncludeGlobs => {
+				let include_globs = parse_globs(&self.include_globs);
+				self.change_settings(database, |settings| settings.include_globs = include_globs)
+			}
+			Message::ChangeExcludeGlobs(exclude_globs) => {
+				self.exclude_globs = exclude_globs;
+				Action::None
+			}
+			Message::ApplyExcludeGlobs => {
+				let exclude_globs = parse_globs(&self.exclude_globs);
+				self.change_settings(database, |settings| settings.exclude_globs = exclude_globs)
+			}
+			Message::SetRespectIgnoreFiles(respect_ignore_files) => self
+				.change_settings(database, |settings| {
+					settings.respect_ignore_files = respect_ignore_files
+				}),
+			Message::SetGitBlame(git_blame) => {
+				self.change_settings(database, |settings| settings.git_blame = git_blame)
+			}
+			Message::SetMarkDoneByCommits(mark_done_by_commits) => {
+				self.change_settings(database, |settings| {
+					settings.mark_done_by_commits = mark_done_by_commits
+				})
+			}
+			Message::ChangeMaxFileSize(max_file_size_kb) => {
+				if max_file_size_kb.chars().all(|c| c.is_ascii_digit()) {
+					self.max_file_size_kb = max_file_size_kb;
+				}
+				Action::None
+			}
+			Message::ApplyMaxFileSize => {
+				let max_file_size_bytes = self
+					.max_file_size_kb
+					.parse::<u64>()
+					.ok()
+					.map(|max_file_size_kb| max_file_size_kb * 1024);
+				self.change_settings(database, |settings| {
+					settings.max_file_size_bytes = max_file_size_bytes
+				})
+			}
+		}
+	}
+
+	fn change_settings(
+		&self,
+		database: Option<&Database>,
+		change: impl FnOnce(&mut SourceCodeTodoSettings),
+	) -> Action {
+		match database.and_then(|db| db.get_project(&self.project_id)) {
+			Some(project) => {
+				let mut new_settings = project.source_code_todo_settings.clone();
+				change(&mut new_settings);
+				DatabaseMessage::ChangeSourceCodeTodoSettings {
+					project_id: self.project_id,
+					new_settings,
+				}
+				.into()
+			}
+			None => Action::None,
+		}
+	}
+
+	pub fn view<'a>(&'a self, app: &'a ProjectTrackerApp) -> Element<'a, project_tracker::Message> {
+		match app
+			.database
+			.ok()
+			.and_then(|db| db.get_project(&self.project_id))
+		{
+			Some(project) => card(
+				text(format!("Project '{}' todo import settings", project.name))
+					.size(LARGE_TEXT_SIZE),
+				self.settings_view(project)
+					.map(project_tracker::Message::SourceCodeTodoSettingsModalMessage),
+			)
+			.style(card_style)
+			.max_width(600.0)
+			.close_size(LARGE_TEXT_SIZE)
+			.on_close(project_tracker::Message::CloseSourceCodeTodoSettingsModal)
+			.into(),
+			None => Space::new(0.0, 0.0).into(),
+		}
+	}
+
+	fn settings_view<'a>(&'a self, project: &'a Project) -> Element<'a, Message> {
+		let settings = &project.source_code_todo_settings;
+
+		let keyword_list = settings
+			.keywords
+			.iter()
+			.enumerate()
+			.map(move |(keyword_index, keyword)| {
+				let task_tag_buttons =
+					project
+						.task_tags
+						.iter()
+						.map(move |(task_tag_id, task_tag)| {
+							task_tag_button(task_tag, keyword.task_tag == Some(task_tag_id))
+								.on_press(Message::ToggleKeywordTaskTag {
+									keyword_index,
+									task_tag_id,
+								})
+								.into()
+						});
+
+				row![
+					container(text(&keyword.keyword)).width(100.0),
+					Row::with_children(task_tag_buttons)
+						.spacing(SMALL_SPACING_AMOUNT)
+						.wrap(),
+					Space::with_width(iced::Length::Fill),
+					remove_source_code_todo_keyword_button(keyword_index),
+				]
+				.spacing(SMALL_SPACING_AMOUNT)
+				.align_y(Vertical::Center)
+				.into()
+			});
+
+		let labeled = |label: &'static str, content: Element<'a, Message>| {
+			row![container(text(label)).width(200.0), content]
+				.spacing(SMALL_SPACING_AMOUNT)
+				.align_y(Vertical::Center)
+		};
+
+		column![
+			text("Keywords (imported todos get the selected tag):"),
+			Column::with_children(keyword_list).spacing(SMALL_SPACING_AMOUNT),
+			text_input("Add keyword, e.g. FIXME", &self.new_keyword)
+				.on_input(Message::ChangeNewKeyword)
+				.on_submit(Message::AddKeyword),
+			labeled(
+				"Include globs:",
+				text_input("all files, e.g. src/**, *.rs", &self.include_globs)
+					.on_input(Message::ChangeIncludeGlobs)
+					.on_submit(Message::ApplyIncludeGlobs)
+					.into()
+			),
+			labeled(
+				"Exclude globs:",
+				text_input("e.g. target/, *.min.js", &self.exclude_globs)
+					.on_input(Message::ChangeExcludeGlobs)
+					.on_submit(Message::ApplyExcludeGlobs)
+					.into()
+			),
+			labeled(
+				"Max file size (KB):",
+				text_input("no limit", &self.max_file_size_kb)
+					.on_input(Message::ChangeMaxFileSize)
+					.on_submit(Message::ApplyMaxFileSize)
+					.into()
+			),
+			labeled(
+				"Respect .gitignore/.ignore:",
+				toggler(settings.respect_ignore_files)
+					.on_toggle(Message::SetRespectIgnoreFiles)
+					.size(27.5)
+					.into()
+			),
+			labeled(
+				"Author and age from git:",
+				toggler(settings.git_blame)
+					.on_toggle(Message::SetGitBlame)
+					.size(27.5)
+					.into()
+			),
+			labeled(
+				"Mark done by commits:",
+				toggler(settings.mark_done_by_commits)
+					.on_toggle(Message::SetMarkDoneByCommits)
+					.size(27.5)
+					.into()
+			),
+			text(
+				"Commits reference tasks with 'pt-<task id>' or a 'Fixes: <task name>' line, \
+				 tasks get marked done once such a commit is on the default branch."
+			)
+			.size(SMALL_TEXT_SIZE),
+			text("Globs are comma separated, press enter to apply them.").size(SMALL_TEXT_SIZE),
+		]
+		.spacing(SPACING_AMOUNT)
+		.into()
+	}
+}
+
+fn parse_globs(globs: &str) -> Vec<String> {
+	globs
+		.split(',')
+		.map(str::trim)
+		.filter(|glob| !glob.is_empty())
+		.map(str::to_string)
+		.collect()
+}
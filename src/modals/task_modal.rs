@@ -1,22 +1,38 @@
 use crate::{
 	components::{
-		delete_task_button, due_date_button, edit_needed_time_button, horizontal_scrollable,
-		start_task_timer_button, task_description, task_description_editor, task_tag_list,
+		add_task_comment_button, cancel_editing_task_comment_button, copy_to_clipboard_button,
+		delete_task_button, delete_task_comment_button, due_date_button, due_time_button,
+		edit_needed_time_button, edit_task_comment_button, file_dropzone, horizontal_scrollable,
+		mark_task_done_button, open_attachment_button, open_in_code_editor_button,
+		open_issue_button, plan_task_for_today_button, promote_source_code_todo_button,
+		remove_task_attachment_button, remove_task_reminder_button, save_task_comment_button,
+		start_task_timer_button, task_comment_body, task_description, task_description_editor,
+		task_priority_dropdown_button, task_tag_list, time_block_button,
 		toggle_view_edit_task_description_button, vertical_scrollable, ICON_BUTTON_WIDTH,
 		SCROLLBAR_WIDTH,
 	},
-	core::SerializableDateConversion,
+	core::{
+		attachment_filepath, is_linked_source_code_todo_removed, is_source_code_todo_promoted,
+		linked_source_code_todo, source_code_todo_location, source_code_todo_origin, time_ago_str,
+		SerializableDateConversion,
+	},
+	icons::{icon_to_text, Bootstrap},
 	project_tracker,
 	styles::{
-		card_style, markdown_background_container_style, text_input_style_borderless,
-		unindent_text, BOLD_FONT, HEADING_TEXT_SIZE, LARGE_SPACING_AMOUNT, LARGE_TEXT_SIZE,
-		PADDING_AMOUNT, SMALL_PADDING_AMOUNT, SPACING_AMOUNT,
+		card_style, grey_text_style, markdown_background_container_style, rounded_container_style,
+		text_input_style_borderless, text_input_style_default, unindent_text, BOLD_FONT,
+		HEADING_TEXT_SIZE, LARGE_SPACING_AMOUNT, LARGE_TEXT_SIZE, PADDING_AMOUNT,
+		SMALL_HORIZONTAL_PADDING, SMALL_PADDING_AMOUNT, SMALL_SPACING_AMOUNT, SMALL_TEXT_SIZE,
+		SPACING_AMOUNT,
 	},
-	OptionalPreference, ProjectTrackerApp,
+	DateFormatting, OptionalPreference, Preferences, ProjectTrackerApp,
 };
+use chrono::{Local, Timelike, Utc};
 use iced::{
 	alignment::{Horizontal, Vertical},
-	widget::{column, container, row, stack, text, text_editor, text_input, Row, Space},
+	widget::{
+		column, container, image, row, stack, text, text_editor, text_input, Column, Row, Space,
+	},
 	Element,
 	Length::Fill,
 	Padding,
@@ -24,7 +40,8 @@ use iced::{
 use iced_aw::card;
 use project_tracker_core::{
 	duration_str, duration_to_minutes, parse_duration_from_str, Database, DatabaseMessage,
-	ProjectId, SerializableDate, TaskId,
+	ProjectId, SerializableDate, SerializableTime, TaskComment, TaskCommentId, TaskId, TaskOrigin,
+	TaskPriority, TaskReminder, TaskType, TimeBlock,
 };
 use std::sync::LazyLock;
 use std::time::Duration;
@@ -32,6 +49,9 @@ use tracing::error;
 
 static TASK_NAME_INPUT_ID: LazyLock<text_input::Id> = LazyLock::new(text_input::Id::unique);
 static EDIT_NEEDED_TIME_INPUT_ID: LazyLock<text_input::Id> = LazyLock::new(text_input::Id::unique);
+static EDIT_DUE_TIME_INPUT_ID: LazyLock<text_input::Id> = LazyLock::new(text_input::Id::unique);
+
+const ATTACHMENT_THUMBNAIL_SIZE: f32 = 64.0;
 
 #[derive(Debug, Clone)]
 pub enum Message {
@@ -44,6 +64,18 @@ pub enum Message {
 	StopEditingDueDate,
 	ChangeDueDate(SerializableDate),
 
+	EditDueTime,
+	StopEditingDueTime,
+	ChangeDueTimeInput(String),
+	ChangeDueTime,
+	ClearDueTime,
+
+	AddTimeBlock,
+
+	ChangeNewReminderInput(String),
+	AddReminder,
+	RemoveReminder(TaskReminder),
+
 	EditNeededTime,
 	StopEditingNeededTime,
 	ClearTaskNeededTime,
@@ -51,6 +83,24 @@ pub enum Message {
 	ChangeNeededTime,
 	InvalidNeededTimeInput,
 
+	OpenPriorityDropdown,
+	ClosePriorityDropdown,
+	ChangePriority(TaskPriority),
+
+	PromoteSourceCodeTodo,
+
+	HoveringFiles(bool),
+
+	ChangeNewCommentAction(text_editor::Action),
+	UnindentNewComment,
+	AddComment,
+	EditComment(TaskCommentId),
+	EditCommentAction(text_editor::Action),
+	UnindentEditedComment,
+	SaveEditedComment,
+	StopEditingComment,
+	DeleteComment(TaskCommentId),
+
 	DeleteTask,
 }
 
@@ -83,6 +133,12 @@ pub struct Modal {
 	new_description: Option<text_editor::Content>,
 	edit_due_date: bool,
 	new_needed_time_minutes: Option<String>,
+	new_due_time: Option<String>,
+	new_reminder: String,
+	show_priority_dropdown: bool,
+	hovering_files: bool,
+	new_comment: text_editor::Content,
+	edited_comment: Option<(TaskCommentId, text_editor::Content)>,
 }
 
 impl Modal {
@@ -97,6 +153,12 @@ impl Modal {
 				new_description: None,
 				edit_due_date: false,
 				new_needed_time_minutes: None,
+				new_due_time: None,
+				new_reminder: String::new(),
+				show_priority_dropdown: false,
+				hovering_files: false,
+				new_comment: text_editor::Content::new(),
+				edited_comment: None,
 			},
 			text_input::focus(TASK_NAME_INPUT_ID.clone()),
 		)
@@ -124,7 +186,12 @@ impl Modal {
 		}
 	}
 
-	pub fn update<'a>(&'a mut self, message: Message, database: Option<&'a Database>) -> Action {
+	pub fn update<'a>(
+		&'a mut self,
+		message: Message,
+		database: Option<&'a Database>,
+		preferences: &Option<Preferences>,
+	) -> Action {
 		match message {
 			Message::EditDescription => {
 				self.new_description = database.as_ref().and_then(|db| {
@@ -185,6 +252,100 @@ impl Modal {
 				.into()
 			}
 
+			Message::EditDueTime => {
+				let previous_due_time = database.as_ref().and_then(|db| {
+					db.get_task(&self.project_id, &self.task_id)
+						.and_then(|task| task.due_time)
+				});
+				self.new_due_time = Some(
+					previous_due_time
+						.map(|due_time| due_time.to_string())
+						.unwrap_or("12:00".to_string()),
+				);
+				text_input::focus(EDIT_DUE_TIME_INPUT_ID.clone()).into()
+			}
+			Message::StopEditingDueTime => {
+				self.new_due_time = None;
+				Action::None
+			}
+			Message::ChangeDueTimeInput(new_due_time) => {
+				self.new_due_time = Some(new_due_time);
+				Action::None
+			}
+			Message::ChangeDueTime => match self
+				.new_due_time
+				.as_ref()
+				.and_then(|new_due_time| SerializableTime::parse(new_due_time))
+			{
+				Some(new_due_time) => {
+					self.new_due_time = None;
+					DatabaseMessage::ChangeTaskDueTime {
+						project_id: self.project_id,
+						task_id: self.task_id,
+						new_due_time: Some(new_due_time),
+					}
+					.into()
+				}
+				None => Action::None,
+			},
+			Message::ClearDueTime => {
+				self.new_due_time = None;
+				DatabaseMessage::ChangeTaskDueTime {
+					project_id: self.project_id,
+					task_id: self.task_id,
+					new_due_time: None,
+				}
+				.into()
+			}
+
+			Message::AddTimeBlock => {
+				let now = Local::now();
+				let task = database.and_then(|db| db.get_task(&self.project_id, &self.task_id));
+				// block the next full hour of the due date, unless the task is due at a specific time
+				let date = task
+					.and_then(|task| task.due_date)
+					.unwrap_or(now.date_naive().into());
+				let start = task
+					.and_then(|task| task.due_time)
+					.unwrap_or(SerializableTime {
+						hour: (now.hour() + 1).min(23),
+						minute: 0,
+					});
+				DatabaseMessage::ChangeTaskTimeBlock {
+					project_id: self.project_id,
+					task_id: self.task_id,
+					new_time_block: Some(TimeBlock {
+						date,
+						start,
+						duration_minutes: None,
+					}),
+				}
+				.into()
+			}
+
+			Message::ChangeNewReminderInput(new_reminder) => {
+				self.new_reminder = new_reminder;
+				Action::None
+			}
+			Message::AddReminder => match TaskReminder::parse(&self.new_reminder, &Local) {
+				Some(reminder) => {
+					self.new_reminder.clear();
+					DatabaseMessage::AddTaskReminder {
+						project_id: self.project_id,
+						task_id: self.task_id,
+						reminder,
+					}
+					.into()
+				}
+				None => Action::None,
+			},
+			Message::RemoveReminder(reminder) => DatabaseMessage::RemoveTaskReminder {
+				project_id: self.project_id,
+				task_id: self.task_id,
+				reminder,
+			}
+			.into(),
+
 			Message::EditNeededTime => {
 				let previous_task_needed_minutes = database.as_ref().and_then(|db| {
 					db.get_task(&self.project_id, &self.task_id)
@@ -230,6 +391,120 @@ impl Modal {
 				.into()
 			}
 			Message::InvalidNeededTimeInput => Action::None,
+			Message::OpenPriorityDropdown => {
+				self.show_priority_dropdown = true;
+				Action::None
+			}
+			Message::ClosePriorityDropdown => {
+				self.show_priority_dropdown = false;
+				Action::None
+			}
+			Message::ChangePriority(new_priority) => {
+				self.show_priority_dropdown = false;
+				DatabaseMessage::ChangeTaskPriority {
+					project_id: self.project_id,
+					task_id: self.task_id,
+					new_priority,
+				}
+				.into()
+			}
+			Message::PromoteSourceCodeTodo => {
+				let project = database.and_then(|db| db.get_project(&self.project_id));
+				match project.zip(project.and_then(|project| project.get_task(&self.task_id))) {
+					Some((project, source_code_todo)) => DatabaseMessage::PromoteSourceCodeTodo {
+						project_id: self.project_id,
+						source_code_todo_id: self.task_id,
+						task_id: TaskId::generate(),
+						origin: source_code_todo_origin(project, source_code_todo),
+					}
+					.into(),
+					None => Action::None,
+				}
+			}
+			Message::HoveringFiles(hovering) => {
+				self.hovering_files = hovering;
+				Action::None
+			}
+			Message::ChangeNewCommentAction(action) => {
+				self.new_comment.perform(action);
+				Action::None
+			}
+			Message::UnindentNewComment => {
+				unindent_text(&mut self.new_comment);
+				Action::None
+			}
+			Message::AddComment => {
+				let body = self.new_comment.text();
+				if body.trim().is_empty() {
+					return Action::None;
+				}
+				self.new_comment = text_editor::Content::new();
+				let author = preferences.comment_author();
+				let author = if author.trim().is_empty() {
+					"Anonymous".to_string()
+				} else {
+					author
+				};
+				DatabaseMessage::AddTaskComment {
+					project_id: self.project_id,
+					task_id: self.task_id,
+					comment: TaskComment::new(author, body.trim_end().to_string()),
+				}
+				.into()
+			}
+			Message::EditComment(comment_id) => {
+				self.edited_comment = database
+					.and_then(|db| db.get_task(&self.project_id, &self.task_id))
+					.and_then(|task| {
+						task.comments
+							.iter()
+							.find(|comment| comment.id == comment_id)
+					})
+					.map(|comment| (comment_id, text_editor::Content::with_text(&comment.body)));
+				Action::None
+			}
+			Message::EditCommentAction(action) => {
+				if let Some((_, edited_comment)) = &mut self.edited_comment {
+					edited_comment.perform(action);
+				}
+				Action::None
+			}
+			Message::UnindentEditedComment => {
+				if let Some((_, edited_comment)) = &mut self.edited_comment {
+					unindent_text(edited_comment);
+				}
+				Action::None
+			}
+			Message::SaveEditedComment => match self.edited_comment.take() {
+				Some((comment_id, edited_comment)) => DatabaseMessage::EditTaskComment {
+					project_id: self.project_id,
+					task_id: self.task_id,
+					comment_id,
+					new_body: edited_comment.text().trim_end().to_string(),
+					edited: Utc::now(),
+				}
+				.into(),
+				None => Action::None,
+			},
+			Message::StopEditingComment => {
+				self.edited_comment = None;
+				Action::None
+			}
+			Message::DeleteComment(comment_id) => {
+				if self
+					.edited_comment
+					.as_ref()
+					.is_some_and(|(edited_comment_id, _)| *edited_comment_id == comment_id)
+				{
+					self.edited_comment = None;
+				}
+				DatabaseMessage::DeleteTaskComment {
+					project_id: self.project_id,
+					task_id: self.task_id,
+					comment_id,
+				}
+				.into()
+			}
 			Message::DeleteTask => DatabaseMessage::DeleteTask {
 				project_id: self.project_id,
 				task_id: self.task_id,
@@ -246,8 +521,8 @@ impl Modal {
 				.ok()
 				.and_then(|db| db.get_project(&self.project_id))
 			{
-				Some(project) => match project.get_task(&self.task_id) {
-					Some(task) => {
+				Some(project) => match project.get_task_and_type(&self.task_id) {
+					Some((task, task_type)) => {
 						let task_tags_list: Vec<Element<project_tracker::Message>> =
 							task_tag_list(project, &task.tags, |task_tag_id| {
 								DatabaseMessage::ToggleTaskTag {
@@ -305,6 +580,200 @@ impl Modal {
 							.into(),
 						);
 
+						let due_time_view: Option<Element<'a, project_tracker::Message>> =
+							task.due_date.map(|_| {
+								due_time_button(
+									task.due_time,
+									&self.new_due_time,
+									Message::EditDueTime.into(),
+									|input| Message::ChangeDueTimeInput(input).into(),
+									Message::ChangeDueTime.into(),
+									Message::StopEditingDueTime.into(),
+									Message::ClearDueTime.into(),
+									EDIT_DUE_TIME_INPUT_ID.clone(),
+								)
+							});
+
+						let date_formatting = app.preferences.date_formatting();
+						let reminders_view =
+							Column::with_children(task.reminders.iter().map(|reminder| {
+								row![
+									container(text(reminder_str(reminder, date_formatting)))
+										.padding(SMALL_HORIZONTAL_PADDING)
+										.style(rounded_container_style),
+									remove_task_reminder_button(
+										Message::RemoveReminder(*reminder).into()
+									),
+								]
+								.align_y(Vertical::Center)
+								.into()
+							}))
+							.push(
+								text_input(
+									"Add reminder: 15min (before due) or 2025-03-14 09:30",
+									&self.new_reminder,
+								)
+								.on_input(|input| Message::ChangeNewReminderInput(input).into())
+								.on_submit(Message::AddReminder.into())
+								.style(text_input_style_default),
+							)
+							.spacing(SMALL_SPACING_AMOUNT);
+
+						let commit_reference_view = (project.source_code_directory.is_some()
+							&& !matches!(task_type, TaskType::SourceCodeTodo))
+						.then(|| {
+							let commit_reference = self.task_id.commit_reference();
+							row![
+								text(format!("Reference in commits with '{commit_reference}'")),
+								copy_to_clipboard_button(commit_reference),
+							]
+							.spacing(SPACING_AMOUNT)
+							.align_y(Vertical::Center)
+						});
+
+						let linked_commits_view = (!task.linked_commits.is_empty()).then(|| {
+							let linked_commits_list =
+								Column::with_children(task.linked_commits.iter().map(|commit| {
+									row![
+										container(text(commit.short_hash().to_string()))
+											.padding(SMALL_HORIZONTAL_PADDING)
+											.style(rounded_container_style),
+										text(commit.summary.clone()).width(Fill),
+										text(format!(
+											"{} by {}",
+											time_ago_str(commit.date),
+											commit.author
+										))
+										.size(SMALL_TEXT_SIZE)
+										.style(grey_text_style),
+									]
+									.spacing(SPACING_AMOUNT)
+									.align_y(Vertical::Center)
+									.into()
+								}))
+								.spacing(SMALL_SPACING_AMOUNT);
+							column![text("Commits:"), linked_commits_list]
+								.spacing(SMALL_SPACING_AMOUNT)
+						});
+
+						let attachments_directory = app.attachments_directory();
+						let attachments_list =
+							Column::with_children(task.attachments.iter().map(|attachment| {
+								let filepath =
+									attachments_directory.as_ref().map(|attachments_directory| {
+										attachment_filepath(attachments_directory, attachment)
+									});
+								let preview: Element<'a, project_tracker::Message> = match &filepath
+								{
+									Some(filepath) if attachment.is_image() => {
+										image(image::Handle::from_path(filepath))
+											.width(ATTACHMENT_THUMBNAIL_SIZE)
+											.height(ATTACHMENT_THUMBNAIL_SIZE)
+											.into()
+									}
+									_ => icon_to_text(Bootstrap::FileEarmark)
+										.size(LARGE_TEXT_SIZE)
+										.into(),
+								};
+								row![
+									open_attachment_button(
+										preview,
+										attachment.name.clone(),
+										filepath
+									),
+									text(size_str(attachment.size_bytes))
+										.size(SMALL_TEXT_SIZE)
+										.style(grey_text_style),
+									remove_task_attachment_button(
+										self.project_id,
+										self.task_id,
+										attachment.clone()
+									),
+								]
+								.spacing(SPACING_AMOUNT)
+								.align_y(Vertical::Center)
+								.into()
+							}))
+							.spacing(SMALL_SPACING_AMOUNT);
+
+						let comments_list =
+							Column::with_children(task.comments.iter().map(|comment| {
+								let edited_str = if comment.edited.is_some() {
+									" (edited)"
+								} else {
+									""
+								};
+								let header = row![
+									text(comment.author.clone()).font(BOLD_FONT),
+									text(format!("{}{edited_str}", time_ago_str(comment.created)))
+										.size(SMALL_TEXT_SIZE)
+										.style(grey_text_style),
+									Space::new(Fill, 0.0),
+									edit_task_comment_button(comment.id),
+									delete_task_comment_button(comment.id),
+								]
+								.spacing(SPACING_AMOUNT)
+								.align_y(Vertical::Center);
+
+								let body: Element<'a, project_tracker::Message> = match &self
+									.edited_comment
+								{
+									Some((edited_comment_id, edited_comment))
+										if *edited_comment_id == comment.id =>
+									{
+										column![
+											task_description_editor(
+												edited_comment,
+												|action| Message::EditCommentAction(action).into(),
+												Some(Message::StopEditingComment.into()),
+												Message::UnindentEditedComment.into(),
+											),
+											row![
+												Space::new(Fill, 0.0),
+												cancel_editing_task_comment_button(),
+												save_task_comment_button(),
+											]
+											.spacing(SPACING_AMOUNT),
+										]
+										.spacing(SMALL_SPACING_AMOUNT)
+										.into()
+									}
+									_ => task_comment_body(
+										self.project_id,
+										self.task_id,
+										comment.id,
+										app.task_description_markdown_storage
+											.get_from_comment(comment),
+										app,
+									),
+								};
+
+								container(column![header, body].spacing(SMALL_SPACING_AMOUNT))
+									.padding(SMALL_PADDING_AMOUNT)
+									.style(markdown_background_container_style)
+									.into()
+							}))
+							.spacing(SPACING_AMOUNT);
+
+						let new_comment_view: Column<'a, project_tracker::Message> = column![
+							task_description_editor(
+								&self.new_comment,
+								|action| Message::ChangeNewCommentAction(action).into(),
+								None,
+								Message::UnindentNewComment.into(),
+							),
+							row![
+								Space::new(Fill, 0.0),
+								add_task_comment_button(!self.new_comment.text().trim().is_empty()),
+							],
+						]
+						.spacing(SMALL_SPACING_AMOUNT);
+
+						let git_view = Column::new()
+							.push_maybe(commit_reference_view)
+							.push_maybe(linked_commits_view)
+							.spacing(SPACING_AMOUNT);
+
 						let viewing_description = self.new_description.is_none();
 						let description_hover_button: Element<'a, project_tracker::Message> =
 							container(toggle_view_edit_task_description_button(
@@ -374,14 +843,91 @@ impl Modal {
 									.style(markdown_background_container_style),
 								description_hover_button
 							],
+							Space::new(0.0, SPACING_AMOUNT),
+							text("Comments:"),
+							comments_list,
+							new_comment_view,
 							Space::new(0.0, LARGE_SPACING_AMOUNT),
-							row![
-								needed_time_view,
-								due_date_view,
-								Space::new(Fill, 0.0),
-								delete_task_button(),
-							]
-							.spacing(SPACING_AMOUNT)
+							Row::new()
+								.push(needed_time_view)
+								.push(due_date_view)
+								.push_maybe(due_time_view)
+								.push(task_priority_dropdown_button(
+									self.show_priority_dropdown,
+									task.priority
+								))
+								.push(plan_task_for_today_button(
+									self.project_id,
+									self.task_id,
+									task.plan.is_some_and(|plan| {
+										plan.date == Local::now().date_naive().into()
+									})
+								))
+								.push(time_block_button(
+									self.project_id,
+									self.task_id,
+									task.time_block,
+									task.time_block_minutes(),
+									app.preferences.date_formatting(),
+									Message::AddTimeBlock.into()
+								))
+								.push_maybe((task.completed_pomodoros > 0).then(|| {
+									container(text(format!(
+										"{} pomodoros",
+										task.completed_pomodoros
+									)))
+									.padding(SMALL_HORIZONTAL_PADDING)
+									.style(rounded_container_style)
+								}))
+								.push_maybe(
+									task.origin
+										.as_ref()
+										.and_then(TaskOrigin::url)
+										.map(|url| open_issue_button(url.to_string()))
+								)
+								.push_maybe(matches!(task_type, TaskType::SourceCodeTodo).then(
+									|| {
+										promote_source_code_todo_button(
+											is_source_code_todo_promoted(project, task),
+										)
+									}
+								))
+								.push_maybe(
+									linked_source_code_todo(project, task)
+										.zip(app.preferences.code_editor())
+										.map(|(source_code_todo, code_editor)| {
+											open_in_code_editor_button(
+												source_code_todo_location(source_code_todo)
+													.to_string(),
+												code_editor,
+											)
+										})
+								)
+								.push(Space::new(Fill, 0.0))
+								.push(delete_task_button())
+								.spacing(SPACING_AMOUNT),
+							Column::new().push_maybe(
+								(matches!(task_type, TaskType::Todo)
+									&& is_linked_source_code_todo_removed(project, task))
+								.then(|| {
+									row![
+										text("The todo comment of this task was removed from the code."),
+										Space::new(Fill, 0.0),
+										mark_task_done_button(self.project_id, self.task_id),
+									]
+									.spacing(SPACING_AMOUNT)
+									.align_y(Vertical::Center)
+								})
+							),
+							Space::new(0.0, SPACING_AMOUNT),
+							text("Reminders:"),
+							reminders_view,
+							Space::new(0.0, SPACING_AMOUNT),
+							text("Attachments:"),
+							attachments_list,
+							file_dropzone("Drop files here to attach them", self.hovering_files),
+							Space::new(0.0, SPACING_AMOUNT),
+							git_view,
 						]))
 						.padding(Padding::default().bottom(SCROLLBAR_WIDTH + SMALL_PADDING_AMOUNT))
 						.into()
@@ -404,3 +950,30 @@ impl Modal {
 		.into()
 	}
 }
+
+fn size_str(size_bytes: u64) -> String {
+	const KB: u64 = 1024;
+	const MB: u64 = KB * 1024;
+	match size_bytes {
+		0..KB => format!("{size_bytes} B"),
+		KB..MB => format!("{} KB", size_bytes / KB),
+		_ => format!("{:.1} MB", size_bytes as f64 / MB as f64),
+	}
+}
+
+fn reminder_str(reminder: &TaskReminder, date_formatting: DateFormatting) -> String {
+	match reminder {
+		TaskReminder::At(date_time) => {
+			let local_date_time = date_time.with_timezone(&Local);
+			format!(
+				"{} {}",
+				date_formatting.format(&local_date_time.date_naive().into()),
+				SerializableTime::from(local_date_time.time())
+			)
+		}
+		TaskReminder::BeforeDue { minutes } => format!(
+			"{} before due",
+			duration_str(Duration::from_secs(*minutes as u64 * 60))
+		),
+	}
+}
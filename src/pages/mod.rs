@@ -9,13 +9,16 @@ pub mod project_page;
 pub use project_page::CachedTaskList;
 
 pub mod stopwatch_page;
-pub use stopwatch_page::format_stopwatch_duration;
+pub use stopwatch_page::{desktop_notification, format_stopwatch_duration};
 
 pub mod overview_page;
 
+pub mod today_page;
+
 #[derive(Debug)]
 pub struct Page {
 	pub overview_page: Option<overview_page::Page>,
+	pub today_page: Option<today_page::Page>,
 	pub stopwatch_page: stopwatch_page::Page,
 	pub project_page: Option<project_page::Page>,
 }
@@ -25,7 +28,9 @@ pub enum Message {
 	StopwatchPage(stopwatch_page::Message),
 	ProjectPage(project_page::Message),
 	OverviewPage(overview_page::Message),
+	TodayPage(today_page::Message),
 	OpenOverview,
+	OpenToday,
 	OpenProjectPage(ProjectId),
 	OpenStopwatch,
 }
@@ -44,7 +49,9 @@ pub enum Action {
 	Actions(Vec<Action>),
 	Task(iced::Task<Message>),
 	DatabaseMessage(DatabaseMessage),
+	DatabaseMessages(Vec<DatabaseMessage>),
 	OpenManageTaskTagsModal(ProjectId),
+	OpenSourceCodeTodoSettingsModal(ProjectId),
 	ConfirmDeleteProject {
 		project_id: ProjectId,
 		project_name: String,
@@ -73,6 +80,7 @@ impl Page {
 	pub fn new(database: Option<&Database>) -> Self {
 		Self {
 			overview_page: Some(overview_page::Page::new(database)),
+			today_page: None,
 			stopwatch_page: stopwatch_page::Page::default(),
 			project_page: None,
 		}
@@ -98,6 +106,7 @@ impl Page {
 				match ref_preferences.selected_content_page() {
 					SerializedContentPage::Overview => self.open_overview(database, preferences),
 					SerializedContentPage::Stopwatch => self.open_stopwatch(preferences),
+					SerializedContentPage::Today => self.open_today(database, preferences),
 					SerializedContentPage::Project(project_id) => {
 						let project_id_to_open = match &self.project_page {
 							Some(project_page) => project_page.project_id,
@@ -117,12 +126,16 @@ impl Page {
 		self.overview_page.is_some()
 	}
 
+	pub fn is_today_page_opened(&self) -> bool {
+		self.today_page.is_some()
+	}
+
 	pub fn is_project_page_opened(&self) -> bool {
 		self.project_page.is_some()
 	}
 
 	pub fn is_stopwatch_page_opened(&self) -> bool {
-		self.overview_page.is_none() && self.project_page.is_none()
+		self.overview_page.is_none() && self.today_page.is_none() && self.project_page.is_none()
 	}
 
 	pub fn subscription(&self) -> Subscription<Message> {
@@ -161,16 +174,22 @@ impl Page {
 				));
 				Action::Actions(actions)
 			}
-			Message::OverviewPage(message) => {
-				if let Some(overview_page) = &mut self.overview_page {
-					overview_page.update(message, database, preferences);
-				}
-				Action::None
-			}
+			Message::OverviewPage(message) => match &mut self.overview_page {
+				Some(overview_page) => overview_page.update(message, database, preferences),
+				None => Action::None,
+			},
+			Message::TodayPage(message) => match &mut self.today_page {
+				Some(today_page) => today_page.update(message, database, preferences),
+				None => Action::None,
+			},
 			Message::OpenOverview => {
 				self.open_overview(database, preferences);
 				Action::None
 			}
+			Message::OpenToday => {
+				self.open_today(database, preferences);
+				Action::None
+			}
 			Message::OpenProjectPage(project_id) => {
 				self.open_project_page(project_id, database, preferences);
 				Action::None
@@ -188,12 +207,22 @@ impl Page {
 		preferences: &mut Option<Preferences>,
 	) {
 		self.project_page = None;
+		self.today_page = None;
 		self.overview_page = Some(overview_page::Page::new(database));
 		if let Some(preferences) = preferences {
 			preferences.set_selected_content_page(SerializedContentPage::Overview);
 		}
 	}
 
+	fn open_today(&mut self, database: Option<&Database>, preferences: &mut Option<Preferences>) {
+		self.project_page = None;
+		self.overview_page = None;
+		self.today_page = Some(today_page::Page::new(database));
+		if let Some(preferences) = preferences {
+			preferences.set_selected_content_page(SerializedContentPage::Today);
+		}
+	}
+
 	fn open_project_page(
 		&mut self,
 		project_id: ProjectId,
@@ -201,6 +230,7 @@ impl Page {
 		preferences: &mut Option<Preferences>,
 	) {
 		self.overview_page = None;
+		self.today_page = None;
 		let open_project_info = database.as_ref().and_then(|database| {
 			database
 				.get_project(&project_id)
@@ -218,6 +248,7 @@ impl Page {
 			None => {
 				// database is not loaded yet -> dont override saved selected content page yet
 				self.overview_page = None;
+				self.today_page = None;
 				self.project_page = None;
 			}
 		}
@@ -225,6 +256,7 @@ impl Page {
 
 	fn open_stopwatch(&mut self, preferences: &mut Option<Preferences>) {
 		self.overview_page = None;
+		self.today_page = None;
 		self.project_page = None;
 		if let Some(preferences) = preferences {
 			preferences.set_selected_content_page(SerializedContentPage::Stopwatch);
@@ -236,7 +268,10 @@ impl Page {
 			Some(project_page) => project_page.view(app),
 			None => match &self.overview_page {
 				Some(overview_page) => overview_page.view(app),
-				None => self.stopwatch_page.view(app),
+				None => match &self.today_page {
+					Some(today_page) => today_page.view(app),
+					None => self.stopwatch_page.view(app),
+				},
 			},
 		}
 	}
@@ -1,24 +1,37 @@
 use crate::{
 	components::{
-		calendar_navigation_button, calendar_today_button, calendar_view_button,
-		horizontal_seperator, on_input, open_project_button, task_widget, vertical_seperator,
+		calendar_day_overflow_button, calendar_navigation_button, calendar_task_button,
+		calendar_today_button, calendar_view_button, horizontal_seperator, on_input,
+		open_project_button, show_unscheduled_tasks_button, task_widget, vertical_scrollable,
+		vertical_scrollable_no_padding, vertical_seperator,
 	},
 	core::IcedColorConversion,
-	pages,
+	pages::{self, TaskDropzone},
 	preferences::{FirstWeekday, SerializedOverviewPage},
 	project_tracker,
-	styles::{PADDING_AMOUNT, SPACING_AMOUNT},
+	styles::{
+		dropzone_container_style, grey_text_style, rounded_container_style,
+		time_block_container_style, tracked_session_container_style, GREY, LARGE_SPACING_AMOUNT,
+		PADDING_AMOUNT, SMALL_HORIZONTAL_PADDING, SMALL_PADDING_AMOUNT, SMALL_SPACING_AMOUNT,
+		SMALL_TEXT_SIZE, SPACING_AMOUNT,
+	},
 	OptionalPreference, Preferences, ProjectTrackerApp,
 };
-use chrono::{DateTime, Datelike, Days, Duration, Local, NaiveDate, Utc, Weekday};
+use chrono::{
+	DateTime, Datelike, Days, Duration, Local, Months, NaiveDate, Timelike, Utc, Weekday,
+};
 use iced::{
 	alignment::{Horizontal, Vertical},
-	widget::{column, container, row, text, Column, Row, Space},
+	mouse,
+	widget::{column, container, mouse_area, row, text, Column, Row, Space, Stack},
 	Element,
-	Length::Fill,
+	Length::{Fill, Fixed},
+};
+use iced_aw::{quad::Quad, style::colors::RED};
+use project_tracker_core::{
+	duration_str, round_duration_to_minutes, Database, DatabaseMessage, ProjectId,
+	SerializableDate, SerializableTime, TaskId, TaskType, TimeBlock, TrackedSession,
 };
-use iced_aw::style::colors::RED;
-use project_tracker_core::{Database, ProjectId, SerializableDate, TaskId, TaskType};
 use serde::{Deserialize, Serialize};
 use std::{
 	collections::{BTreeMap, HashMap},
@@ -29,15 +42,66 @@ use tracing::error;
 #[derive(Debug, Clone)]
 pub struct Page {
 	tasks: BTreeMap<SerializableDate, HashMap<ProjectId, Vec<TaskId>>>,
+	/// todo tasks without a due date, they can be dragged onto calendar days
+	unscheduled_tasks: HashMap<ProjectId, Vec<TaskId>>,
+	show_unscheduled_tasks: bool,
+	/// tasks with a time block on that date
+	time_blocks: BTreeMap<SerializableDate, Vec<(ProjectId, TaskId)>>,
+	/// tracked sessions by their local start date, shown next to the time blocks
+	tracked_sessions: BTreeMap<SerializableDate, Vec<(ProjectId, TrackedSession)>>,
+	time_block_drag: Option<TimeBlockDrag>,
 	cache_time: SystemTime,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeBlockDragMode {
+	Move,
+	Resize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TimeBlockDrag {
+	project_id: ProjectId,
+	task_id: TaskId,
+	mode: TimeBlockDragMode,
+	original: TimeBlock,
+	original_start_minute: usize,
+	/// minute of the day the block got grabbed at, 'None' until the cursor moves
+	grab_minute: Option<f32>,
+	day: NaiveDate,
+	start_minute: usize,
+	duration_minutes: usize,
+}
+
 #[derive(Debug, Clone)]
 pub enum Message {
 	RefreshCachedTaskList,
 	GoForward,
 	GoBackward,
 	GoToToday,
+	ShowUnscheduledTasks(bool),
+	StartTimeBlockDrag {
+		project_id: ProjectId,
+		task_id: TaskId,
+		mode: TimeBlockDragMode,
+	},
+	DragTimeBlock {
+		day: NaiveDate,
+		minute: f32,
+	},
+	StopTimeBlockDrag,
+}
+
+const UNSCHEDULED_TASKS_TRAY_WIDTH: f32 = 300.0;
+const HOUR_HEIGHT: f32 = 48.0;
+const HOUR_LABEL_WIDTH: f32 = 45.0;
+const TRACKED_SESSION_WIDTH: f32 = 6.0;
+const TIME_BLOCK_RESIZE_HANDLE_HEIGHT: f32 = 6.0;
+const TIME_BLOCK_SNAP_MINUTES: usize = 15;
+const MINUTES_PER_DAY: usize = 24 * 60;
+
+pub fn calendar_day_dropzone_id(day: NaiveDate) -> container::Id {
+	container::Id::new(format!("calendar_day_dropzone_{day}"))
 }
 
 impl From<Message> for project_tracker::Message {
@@ -50,18 +114,45 @@ impl Page {
 	pub fn new(database: Option<&Database>) -> Self {
 		let mut tasks: BTreeMap<SerializableDate, HashMap<ProjectId, Vec<TaskId>>> =
 			BTreeMap::new();
+		let mut unscheduled_tasks: HashMap<ProjectId, Vec<TaskId>> = HashMap::new();
+		let mut time_blocks: BTreeMap<SerializableDate, Vec<(ProjectId, TaskId)>> = BTreeMap::new();
+		let mut tracked_sessions: BTreeMap<SerializableDate, Vec<(ProjectId, TrackedSession)>> =
+			BTreeMap::new();
 
 		if let Some(database) = database {
 			for (project_id, project) in database.projects().iter() {
 				for (task_id, task, task_type) in project.iter() {
-					if let Some(due_date) = &task.due_date {
-						if matches!(task_type, TaskType::Todo | TaskType::SourceCodeTodo) {
-							tasks
-								.entry(*due_date)
-								.or_default()
-								.entry(project_id)
-								.or_default()
-								.push(task_id);
+					if let Some(time_block) = &task.time_block {
+						time_blocks
+							.entry(time_block.date)
+							.or_default()
+							.push((project_id, task_id));
+					}
+					for session in task.tracked_sessions.iter() {
+						let start_date = session.start.with_timezone(&Local).date_naive();
+						tracked_sessions
+							.entry(start_date.into())
+							.or_default()
+							.push((project_id, *session));
+					}
+
+					if !matches!(task_type, TaskType::Todo | TaskType::SourceCodeTodo) {
+						continue;
+					}
+					match &task.due_date {
+						Some(due_date) => tasks
+							.entry(*due_date)
+							.or_default()
+							.entry(project_id)
+							.or_default()
+							.push(task_id),
+						None => {
+							if matches!(task_type, TaskType::Todo) {
+								unscheduled_tasks
+									.entry(project_id)
+									.or_default()
+									.push(task_id);
+							}
 						}
 					}
 				}
@@ -70,23 +161,107 @@ impl Page {
 
 		Self {
 			tasks,
+			unscheduled_tasks,
+			show_unscheduled_tasks: false,
+			time_blocks,
+			tracked_sessions,
+			time_block_drag: None,
 			cache_time: SystemTime::now(),
 		}
 	}
 
+	pub fn refresh_cached_task_list(&mut self, database: Option<&Database>) {
+		if let Some(database_ref) = database {
+			let cache_date_time: DateTime<Utc> = self.cache_time.into();
+			if cache_date_time < *database_ref.last_changed_time() {
+				let show_unscheduled_tasks = self.show_unscheduled_tasks;
+				let time_block_drag = self.time_block_drag;
+				*self = Self::new(database);
+				self.show_unscheduled_tasks = show_unscheduled_tasks;
+				self.time_block_drag = time_block_drag;
+			}
+		}
+	}
+
+	/// days of the current calendar view that tasks can be dropped onto
+	pub fn calendar_dropzone_days(&self, preferences: &Option<Preferences>) -> Vec<NaiveDate> {
+		let calendar_view = Self::calendar_view(preferences);
+		let days = calendar_view
+			.days(preferences.first_day_of_week())
+			.into_iter()
+			.map(|(_week_day, day)| day);
+
+		match calendar_view {
+			// the agenda only shows days with due tasks
+			CalendarView::Agenda { .. } => days
+				.filter(|day| self.tasks.contains_key(&(*day).into()))
+				.collect(),
+			_ => days.collect(),
+		}
+	}
+
+	fn calendar_view(preferences: &Option<Preferences>) -> CalendarView {
+		match preferences.serialized_overview_page() {
+			SerializedOverviewPage::Calendar { view } => *view,
+			_ => CalendarView::default(),
+		}
+	}
+
 	pub fn update(
 		&mut self,
 		message: Message,
 		database: Option<&Database>,
 		preferences: &mut Option<Preferences>,
-	) {
+	) -> pages::Action {
 		match message {
-			Message::RefreshCachedTaskList => {
-				if let Some(database_ref) = database {
-					let cache_date_time: DateTime<Utc> = self.cache_time.into();
-					if cache_date_time < *database_ref.last_changed_time() {
-						*self = Self::new(database);
-					}
+			Message::RefreshCachedTaskList => self.refresh_cached_task_list(database),
+			Message::ShowUnscheduledTasks(show) => self.show_unscheduled_tasks = show,
+			Message::StartTimeBlockDrag {
+				project_id,
+				task_id,
+				mode,
+			} => {
+				self.time_block_drag = database
+					.and_then(|db| db.get_task(&project_id, &task_id))
+					.and_then(|task| {
+						let time_block = task.time_block?;
+						let start_minute =
+							(time_block.start.hour * 60 + time_block.start.minute) as usize;
+						Some(TimeBlockDrag {
+							project_id,
+							task_id,
+							mode,
+							original: time_block,
+							original_start_minute: start_minute,
+							grab_minute: None,
+							day: time_block.date.naive_date()?,
+							start_minute,
+							duration_minutes: task.time_block_minutes()?,
+						})
+					});
+			}
+			Message::DragTimeBlock { day, minute } => {
+				if let Some(time_block_drag) = &mut self.time_block_drag {
+					time_block_drag.drag_to(day, minute);
+				}
+			}
+			Message::StopTimeBlockDrag => {
+				if let Some(time_block_drag) = self.time_block_drag.take() {
+					let new_time_block = time_block_drag.time_block();
+					// clicking a block without moving it opens its task
+					return if new_time_block == time_block_drag.original {
+						pages::Action::OpenTaskModal {
+							project_id: time_block_drag.project_id,
+							task_id: time_block_drag.task_id,
+						}
+					} else {
+						DatabaseMessage::ChangeTaskTimeBlock {
+							project_id: time_block_drag.project_id,
+							task_id: time_block_drag.task_id,
+							new_time_block: Some(new_time_block),
+						}
+						.into()
+					};
 				}
 			}
 			Message::GoForward => {
@@ -123,6 +298,8 @@ impl Page {
 						let view = match view {
 							CalendarView::Week { .. } => CalendarView::current_week(),
 							CalendarView::ThreeDays { .. } => CalendarView::current_three_days(),
+							CalendarView::Month { .. } => CalendarView::current_month(),
+							CalendarView::Agenda { .. } => CalendarView::current_agenda(),
 						};
 						preferences.set_serialized_overview_page(
 							SerializedOverviewPage::Calendar { view },
@@ -131,20 +308,85 @@ impl Page {
 				}
 			}
 		}
+		pages::Action::None
 	}
 
 	pub fn view<'a>(&'a self, app: &'a ProjectTrackerApp) -> Element<'a, project_tracker::Message> {
 		let today = Local::now().date_naive();
 		let first_week_day = app.preferences.first_day_of_week();
-		let calendar_view = match app.preferences.serialized_overview_page() {
-			SerializedOverviewPage::Calendar { view } => *view,
-			_ => CalendarView::default(),
-		};
+		let calendar_view = Self::calendar_view(&app.preferences);
 		let week_days = calendar_view.days(first_week_day);
 
 		let current_first_date = match calendar_view {
 			CalendarView::Week { week_day } => week_day,
-			CalendarView::ThreeDays { first_date } => first_date,
+			CalendarView::ThreeDays { first_date } | CalendarView::Agenda { first_date } => {
+				first_date
+			}
+			CalendarView::Month { month_day } => month_day,
+		};
+
+		let range_label = match calendar_view {
+			// the month grid also shows days of the previous and next month
+			CalendarView::Month { month_day } => {
+				Self::view_range_label(&[(month_day.weekday(), month_day)])
+			}
+			_ => Self::view_range_label(&week_days),
+		};
+
+		let calendar: Element<project_tracker::Message> = match calendar_view {
+			CalendarView::Week { .. } | CalendarView::ThreeDays { .. } => column![
+				row![
+					Space::new(HOUR_LABEL_WIDTH, 0.0),
+					Row::with_children(week_days.iter().enumerate().map(|(i, (week_day, day))| {
+						Row::new()
+							.push_maybe(if i == 0 {
+								Some(vertical_seperator())
+							} else {
+								None
+							})
+							.push(Self::day_view(
+								*week_day,
+								*day,
+								*day == today,
+								self.tasks.get(&(*day).into()),
+								app,
+							))
+							.push(vertical_seperator())
+							.into()
+					}))
+					.width(Fill)
+				]
+				.height(Fill),
+				self.time_grid_view(&week_days, app),
+			]
+			.spacing(SPACING_AMOUNT)
+			.into(),
+			CalendarView::Month { month_day } => {
+				self.month_view(month_day.month(), &week_days, today, app)
+			}
+			CalendarView::Agenda { .. } => self.agenda_view(&week_days, today, app),
+		};
+
+		let unscheduled_task_count: usize = self.unscheduled_tasks.values().map(Vec::len).sum();
+
+		let unscheduled_tasks_tray = if self.show_unscheduled_tasks {
+			Some(
+				column![
+					text("Unscheduled"),
+					horizontal_seperator(),
+					if self.unscheduled_tasks.is_empty() {
+						Element::new(text("Every todo task has a due date.").style(grey_text_style))
+					} else {
+						vertical_scrollable(Self::tasks_view(&self.unscheduled_tasks, app))
+							.height(Fill)
+							.into()
+					},
+				]
+				.spacing(SPACING_AMOUNT)
+				.width(Fixed(UNSCHEDULED_TASKS_TRAY_WIDTH)),
+			)
+		} else {
+			None
 		};
 
 		on_input(
@@ -156,8 +398,13 @@ impl Page {
 						calendar_navigation_button(true)
 					],
 					Space::new(SPACING_AMOUNT, 0.0),
-					text(Self::view_range_label(&week_days)),
+					text(range_label),
 					Space::new(Fill, 0.0),
+					show_unscheduled_tasks_button(
+						self.show_unscheduled_tasks,
+						unscheduled_task_count
+					),
+					Space::new(SPACING_AMOUNT, 0.0),
 					calendar_view_button(
 						CalendarView::Week {
 							week_day: current_first_date
@@ -172,30 +419,29 @@ impl Page {
 						},
 						matches!(calendar_view, CalendarView::ThreeDays { .. }),
 						false,
+						false
+					),
+					calendar_view_button(
+						CalendarView::Month {
+							month_day: current_first_date
+						},
+						matches!(calendar_view, CalendarView::Month { .. }),
+						false,
+						false
+					),
+					calendar_view_button(
+						CalendarView::Agenda {
+							first_date: current_first_date
+						},
+						matches!(calendar_view, CalendarView::Agenda { .. }),
+						false,
 						true
 					)
 				]
 				.align_y(Vertical::Center),
-				Row::with_children(week_days.into_iter().enumerate().map(
-					|(i, (week_day, day))| {
-						Row::new()
-							.push_maybe(if i == 0 {
-								Some(vertical_seperator())
-							} else {
-								None
-							})
-							.push(Self::day_view(
-								week_day,
-								day,
-								day == today,
-								self.tasks.get(&day.into()),
-								app,
-							))
-							.push(vertical_seperator())
-							.into()
-					}
-				))
-				.width(Fill)
+				row![calendar]
+					.push_maybe(unscheduled_tasks_tray)
+					.spacing(SPACING_AMOUNT),
 			]
 			.spacing(SPACING_AMOUNT)
 			.padding(PADDING_AMOUNT)
@@ -215,55 +461,12 @@ impl Page {
 		app: &'a ProjectTrackerApp,
 	) -> Element<'a, project_tracker::Message> {
 		let tasks: Element<project_tracker::Message> = match tasks {
-			Some(tasks) => Column::with_children(tasks.iter().map(
-				|(project_id, task_ids)| -> Element<project_tracker::Message> {
-					let task_widgets = task_ids.iter().map(|task_id| {
-						match app
-							.database
-							.ok()
-							.and_then(|db| db.get_project_task_type(project_id, task_id))
-						{
-							Some((project, task, task_type)) => task_widget(
-								task,
-								*task_id,
-								app.task_ui_id_map.get_dropzone_id_mut(*task_id),
-								task_type,
-								*project_id,
-								project,
-								app.preferences.code_editor(),
-								false,
-								true,
-								false,
-								false,
-								false,
-								true,
-							),
-							None => text("<invalid project or task id>").into(),
-						}
-					});
-					match app.database.ok().and_then(|db| db.get_project(project_id)) {
-						Some(project) => column![
-							open_project_button(
-								*project_id,
-								&project.name,
-								project.color.to_iced_color()
-							),
-							Column::with_children(task_widgets).spacing(SPACING_AMOUNT)
-						]
-						.width(Fill)
-						.padding(PADDING_AMOUNT)
-						.spacing(SPACING_AMOUNT)
-						.into(),
-						None => text("<invalid project or task id>").into(),
-					}
-				},
-			))
-			.width(Fill)
-			.spacing(SPACING_AMOUNT)
-			.into(),
+			Some(tasks) => Self::tasks_view(tasks, app),
 			None => Space::new(0, 0).into(),
 		};
 
+		let highlight = Self::is_calendar_day_hovered(day, app);
+
 		container(
 			column![
 				text!("{week_day:?}"),
@@ -274,6 +477,448 @@ impl Page {
 			.align_x(Horizontal::Center),
 		)
 		.center_x(Fill)
+		.height(Fill)
+		.id(calendar_day_dropzone_id(day))
+		.style(move |t| dropzone_container_style(t, highlight))
+		.into()
+	}
+
+	fn time_grid_view<'a>(
+		&'a self,
+		days: &[(Weekday, NaiveDate)],
+		app: &'a ProjectTrackerApp,
+	) -> Element<'a, project_tracker::Message> {
+		let hour_labels = Column::with_children((0..24).map(|hour| {
+			text!("{hour:02}:00")
+				.size(SMALL_TEXT_SIZE)
+				.style(grey_text_style)
+				.height(Fixed(HOUR_HEIGHT))
+				.into()
+		}))
+		.width(Fixed(HOUR_LABEL_WIDTH));
+
+		let day_grids = Row::with_children(days.iter().enumerate().map(|(i, (_, day))| {
+			Row::new()
+				.push_maybe(if i == 0 {
+					Some(time_grid_seperator())
+				} else {
+					None
+				})
+				.push(self.time_grid_day_view(*day, app))
+				.push(time_grid_seperator())
+				.into()
+		}))
+		.width(Fill);
+
+		// releasing outside of the grid would leave the dragged block stuck to the cursor
+		mouse_area(vertical_scrollable_no_padding(row![hour_labels, day_grids]).height(Fill))
+			.on_release(Message::StopTimeBlockDrag.into())
+			.into()
+	}
+
+	fn time_grid_day_view<'a>(
+		&'a self,
+		day: NaiveDate,
+		app: &'a ProjectTrackerApp,
+	) -> Element<'a, project_tracker::Message> {
+		let date: SerializableDate = day.into();
+		let database = app.database.ok();
+
+		let hour_lines =
+			Column::with_children((0..24).map(|_| {
+				column![horizontal_seperator(), Space::new(0.0, HOUR_HEIGHT - 1.0)].into()
+			}));
+
+		let mut layers: Vec<Element<project_tracker::Message>> = vec![hour_lines.into()];
+
+		for (project_id, session) in self.tracked_sessions.get(&date).into_iter().flatten() {
+			let start = session.start.with_timezone(&Local);
+			let start_minute = (start.hour() * 60 + start.minute()) as f32;
+			// sessions running past midnight get cut off
+			let duration_minutes = ((session.end - session.start).num_seconds() as f32 / 60.0)
+				.min(MINUTES_PER_DAY as f32 - start_minute);
+			let color = database
+				.and_then(|db| db.get_project(project_id))
+				.map(|project| project.color.to_iced_color())
+				.unwrap_or(GREY);
+
+			layers.push(
+				row![
+					Space::new(Fill, 0.0),
+					column![
+						Space::new(0.0, minutes_to_height(start_minute)),
+						container(Space::new(0.0, 0.0))
+							.width(Fixed(TRACKED_SESSION_WIDTH))
+							.height(Fixed(minutes_to_height(duration_minutes)))
+							.style(move |t| tracked_session_container_style(t, color)),
+					]
+				]
+				.into(),
+			);
+		}
+
+		let dragged_task_id = self
+			.time_block_drag
+			.map(|time_block_drag| time_block_drag.task_id);
+
+		let time_blocks = self
+			.time_blocks
+			.get(&date)
+			.into_iter()
+			.flatten()
+			.filter(|(_project_id, task_id)| Some(*task_id) != dragged_task_id)
+			.filter_map(|(project_id, task_id)| {
+				let task = database?.get_task(project_id, task_id)?;
+				let start = task.time_block?.start;
+				Some((
+					*project_id,
+					*task_id,
+					(start.hour * 60 + start.minute) as usize,
+					task.time_block_minutes()?,
+					false,
+				))
+			})
+			.chain(
+				self.time_block_drag
+					.filter(|time_block_drag| time_block_drag.day == day)
+					.map(|time_block_drag| {
+						(
+							time_block_drag.project_id,
+							time_block_drag.task_id,
+							time_block_drag.start_minute,
+							time_block_drag.duration_minutes,
+							true,
+						)
+					}),
+			);
+
+		for (project_id, task_id, start_minute, duration_minutes, dragging) in time_blocks {
+			layers.push(Self::time_block_view(
+				project_id,
+				task_id,
+				start_minute,
+				duration_minutes,
+				dragging,
+				app,
+			));
+		}
+
+		let day_grid = mouse_area(
+			Stack::with_children(layers)
+				.width(Fill)
+				.height(Fixed(HOUR_HEIGHT * 24.0)),
+		);
+
+		if self.time_block_drag.is_some() {
+			day_grid
+				.on_move(move |position| {
+					Message::DragTimeBlock {
+						day,
+						minute: position.y / HOUR_HEIGHT * 60.0,
+					}
+					.into()
+				})
+				.into()
+		} else {
+			day_grid.into()
+		}
+	}
+
+	fn time_block_view<'a>(
+		project_id: ProjectId,
+		task_id: TaskId,
+		start_minute: usize,
+		duration_minutes: usize,
+		dragging: bool,
+		app: &'a ProjectTrackerApp,
+	) -> Element<'a, project_tracker::Message> {
+		let Some((project, task, _task_type)) = app
+			.database
+			.ok()
+			.and_then(|db| db.get_project_task_type(&project_id, &task_id))
+		else {
+			return Space::new(0.0, 0.0).into();
+		};
+		let color = project.color.to_iced_color();
+
+		let tracked_time = task
+			.time_block_range(&Local)
+			.and_then(|(start, end)| task.tracked_time_between(start, end).to_std().ok())
+			.unwrap_or_default();
+		let planned_time = std::time::Duration::from_secs(duration_minutes as u64 * 60);
+
+		let resize_handle = mouse_area(Space::new(Fill, TIME_BLOCK_RESIZE_HANDLE_HEIGHT))
+			.on_press(
+				Message::StartTimeBlockDrag {
+					project_id,
+					task_id,
+					mode: TimeBlockDragMode::Resize,
+				}
+				.into(),
+			)
+			.interaction(mouse::Interaction::ResizingVertically);
+
+		let time_block = mouse_area(
+			container(column![
+				text(&task.name).size(SMALL_TEXT_SIZE),
+				text!(
+					"{} of {} tracked",
+					duration_str(round_duration_to_minutes(tracked_time)),
+					duration_str(planned_time)
+				)
+				.size(SMALL_TEXT_SIZE)
+				.style(grey_text_style),
+				Space::new(0.0, Fill),
+				resize_handle,
+			])
+			.padding(SMALL_HORIZONTAL_PADDING)
+			.width(Fill)
+			.height(Fixed(minutes_to_height(duration_minutes as f32)))
+			.clip(true)
+			.style(move |t| time_block_container_style(t, color, dragging)),
+		)
+		.on_press(
+			Message::StartTimeBlockDrag {
+				project_id,
+				task_id,
+				mode: TimeBlockDragMode::Move,
+			}
+			.into(),
+		)
+		.interaction(if dragging {
+			mouse::Interaction::Grabbing
+		} else {
+			mouse::Interaction::Grab
+		});
+
+		// leave room for the tracked sessions on the right
+		row![
+			column![
+				Space::new(0.0, minutes_to_height(start_minute as f32)),
+				time_block
+			],
+			Space::new(TRACKED_SESSION_WIDTH + f32::from(SMALL_SPACING_AMOUNT), 0.0),
+		]
+		.into()
+	}
+
+	fn month_view<'a>(
+		&'a self,
+		month: u32,
+		days: &[(Weekday, NaiveDate)],
+		today: NaiveDate,
+		app: &'a ProjectTrackerApp,
+	) -> Element<'a, project_tracker::Message> {
+		let week_day_labels = Row::with_children(days.iter().take(7).map(|(week_day, _)| {
+			text!("{week_day:?}")
+				.width(Fill)
+				.align_x(Horizontal::Center)
+				.into()
+		}))
+		.spacing(SMALL_SPACING_AMOUNT);
+
+		let weeks = Column::with_children(days.chunks(7).map(|week| {
+			Row::with_children(week.iter().map(|(_, day)| {
+				self.month_day_view(*day, day.month() == month, *day == today, app)
+			}))
+			.spacing(SMALL_SPACING_AMOUNT)
+			.height(Fill)
+			.into()
+		}))
+		.spacing(SMALL_SPACING_AMOUNT)
+		.height(Fill);
+
+		column![week_day_labels, weeks]
+			.spacing(SMALL_SPACING_AMOUNT)
+			.into()
+	}
+
+	fn month_day_view<'a>(
+		&'a self,
+		day: NaiveDate,
+		in_month: bool,
+		today: bool,
+		app: &'a ProjectTrackerApp,
+	) -> Element<'a, project_tracker::Message> {
+		const MAX_SHOWN_TASKS: usize = 3;
+
+		let tasks = self.tasks.get(&day.into());
+		let task_count: usize = tasks
+			.map(|tasks| tasks.values().map(Vec::len).sum())
+			.unwrap_or(0);
+
+		let task_buttons = tasks
+			.into_iter()
+			.flat_map(|tasks| {
+				tasks.iter().flat_map(|(project_id, task_ids)| {
+					task_ids.iter().map(move |task_id| (*project_id, *task_id))
+				})
+			})
+			.take(MAX_SHOWN_TASKS)
+			.map(|(project_id, task_id)| {
+				match app
+					.database
+					.ok()
+					.and_then(|db| db.get_task(&project_id, &task_id))
+				{
+					Some(task) => calendar_task_button(project_id, task_id, &task.name).into(),
+					None => text("<invalid project or task id>").into(),
+				}
+			});
+
+		let day_view = container(
+			column![
+				row![
+					text!("{}", day.day()).color_maybe(if today {
+						Some(RED)
+					} else if in_month {
+						None
+					} else {
+						Some(GREY)
+					}),
+					Space::new(Fill, 0.0),
+				]
+				.push_maybe(if task_count > 0 {
+					Some(
+						text(task_count.to_string())
+							.size(SMALL_TEXT_SIZE)
+							.style(grey_text_style),
+					)
+				} else {
+					None
+				}),
+				Column::with_children(task_buttons),
+			]
+			.push_maybe(if task_count > MAX_SHOWN_TASKS {
+				Some(calendar_day_overflow_button(
+					day,
+					task_count - MAX_SHOWN_TASKS,
+				))
+			} else {
+				None
+			})
+			.spacing(SMALL_SPACING_AMOUNT),
+		)
+		.width(Fill)
+		.height(Fill)
+		.padding(SMALL_PADDING_AMOUNT)
+		.style(rounded_container_style);
+
+		let highlight = Self::is_calendar_day_hovered(day, app);
+
+		container(day_view)
+			.width(Fill)
+			.height(Fill)
+			.id(calendar_day_dropzone_id(day))
+			.style(move |t| dropzone_container_style(t, highlight))
+			.into()
+	}
+
+	fn is_calendar_day_hovered(day: NaiveDate, app: &ProjectTrackerApp) -> bool {
+		matches!(
+			app.sidebar_page.task_dropzone_hovered,
+			Some(TaskDropzone::CalendarDay(hovered_day)) if hovered_day == day
+		)
+	}
+
+	fn agenda_view<'a>(
+		&'a self,
+		days: &[(Weekday, NaiveDate)],
+		today: NaiveDate,
+		app: &'a ProjectTrackerApp,
+	) -> Element<'a, project_tracker::Message> {
+		let day_views: Vec<Element<project_tracker::Message>> = days
+			.iter()
+			.filter_map(|(week_day, day)| {
+				self.tasks.get(&(*day).into()).map(|tasks| {
+					let highlight = Self::is_calendar_day_hovered(*day, app);
+
+					container(
+						column![
+							text!(
+								"{week_day:?}, {}",
+								app.preferences.date_formatting().format(&(*day).into())
+							)
+							.color_maybe(if *day == today { Some(RED) } else { None }),
+							horizontal_seperator(),
+							Self::tasks_view(tasks, app),
+						]
+						.spacing(SPACING_AMOUNT),
+					)
+					.id(calendar_day_dropzone_id(*day))
+					.style(move |t| dropzone_container_style(t, highlight))
+					.into()
+				})
+			})
+			.collect();
+
+		if day_views.is_empty() {
+			container(
+				text!(
+					"No tasks are due in these {} weeks.",
+					CalendarView::AGENDA_WEEKS
+				)
+				.style(grey_text_style),
+			)
+			.center(Fill)
+			.into()
+		} else {
+			vertical_scrollable(Column::with_children(day_views).spacing(LARGE_SPACING_AMOUNT))
+				.height(Fill)
+				.into()
+		}
+	}
+
+	fn tasks_view<'a>(
+		tasks: &'a HashMap<ProjectId, Vec<TaskId>>,
+		app: &'a ProjectTrackerApp,
+	) -> Element<'a, project_tracker::Message> {
+		Column::with_children(tasks.iter().map(
+			|(project_id, task_ids)| -> Element<project_tracker::Message> {
+				let task_widgets = task_ids.iter().map(|task_id| {
+					match app
+						.database
+						.ok()
+						.and_then(|db| db.get_project_task_type(project_id, task_id))
+					{
+						Some((project, task, task_type)) => task_widget(
+							task,
+							*task_id,
+							app.task_ui_id_map.get_dropzone_id_mut(*task_id),
+							task_type,
+							*project_id,
+							project,
+							app.preferences.code_editor(),
+							app.dragged_task == Some(*task_id),
+							app.just_minimal_dragging,
+							true,
+							false,
+							false,
+							false,
+							true,
+						),
+						None => text("<invalid project or task id>").into(),
+					}
+				});
+				match app.database.ok().and_then(|db| db.get_project(project_id)) {
+					Some(project) => column![
+						open_project_button(
+							*project_id,
+							&project.name,
+							project.color.to_iced_color()
+						),
+						Column::with_children(task_widgets).spacing(SPACING_AMOUNT)
+					]
+					.width(Fill)
+					.padding(PADDING_AMOUNT)
+					.spacing(SPACING_AMOUNT)
+					.into(),
+					None => text("<invalid project or task id>").into(),
+				}
+			},
+		))
+		.width(Fill)
+		.spacing(SPACING_AMOUNT)
 		.into()
 	}
 
@@ -329,12 +974,69 @@ impl Page {
 	}
 }
 
+fn minutes_to_height(minutes: f32) -> f32 {
+	minutes / 60.0 * HOUR_HEIGHT
+}
+
+fn snap_minutes(minutes: f32) -> usize {
+	(minutes.max(0.0) / TIME_BLOCK_SNAP_MINUTES as f32).round() as usize * TIME_BLOCK_SNAP_MINUTES
+}
+
+/// vertical seperator with a fixed height, since the time grid is inside of a scrollable
+fn time_grid_seperator() -> Quad {
+	Quad {
+		height: Fixed(HOUR_HEIGHT * 24.0),
+		..vertical_seperator()
+	}
+}
+
+impl TimeBlockDrag {
+	fn drag_to(&mut self, day: NaiveDate, minute: f32) {
+		let grab_minute = *self.grab_minute.get_or_insert(minute);
+		match self.mode {
+			TimeBlockDragMode::Move => {
+				let start_minute = self.original_start_minute as f32 + minute - grab_minute;
+				let latest_start_minute = MINUTES_PER_DAY.saturating_sub(self.duration_minutes);
+				self.start_minute = snap_minutes(start_minute).min(latest_start_minute);
+				self.day = day;
+			}
+			TimeBlockDragMode::Resize => {
+				if day == self.day {
+					let end_minute = snap_minutes(minute).min(MINUTES_PER_DAY);
+					self.duration_minutes = end_minute
+						.saturating_sub(self.start_minute)
+						.max(TIME_BLOCK_SNAP_MINUTES);
+				}
+			}
+		}
+	}
+
+	fn time_block(&self) -> TimeBlock {
+		TimeBlock {
+			date: self.day.into(),
+			start: SerializableTime {
+				hour: (self.start_minute / 60) as u32,
+				minute: (self.start_minute % 60) as u32,
+			},
+			duration_minutes: match self.mode {
+				// moving keeps following the needed time of the task
+				TimeBlockDragMode::Move => self.original.duration_minutes,
+				TimeBlockDragMode::Resize => Some(self.duration_minutes),
+			},
+		}
+	}
+}
+
 /// `ThreeDays`: 3 days, initially pervious, current and next day
 /// `Week`: Mon to Sun / Sun to Sat
+/// `Month`: full weeks covering the month of `month_day`
+/// `Agenda`: the next `AGENDA_WEEKS` weeks, starting at `first_date`
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum CalendarView {
 	ThreeDays { first_date: NaiveDate },
 	Week { week_day: NaiveDate },
+	Month { month_day: NaiveDate },
+	Agenda { first_date: NaiveDate },
 }
 
 impl Default for CalendarView {
@@ -344,6 +1046,8 @@ impl Default for CalendarView {
 }
 
 impl CalendarView {
+	pub const AGENDA_WEEKS: u64 = 4;
+
 	pub fn current_week() -> Self {
 		Self::Week {
 			week_day: Local::now().date_naive(),
@@ -357,13 +1061,31 @@ impl CalendarView {
 		}
 	}
 
+	pub fn current_month() -> Self {
+		Self::Month {
+			month_day: Local::now().date_naive(),
+		}
+	}
+
+	pub fn current_agenda() -> Self {
+		Self::Agenda {
+			first_date: Local::now().date_naive(),
+		}
+	}
+
 	pub fn label(&self) -> &'static str {
 		match self {
 			Self::Week { .. } => "Week",
 			Self::ThreeDays { .. } => "3 Days",
+			Self::Month { .. } => "Month",
+			Self::Agenda { .. } => "Agenda",
 		}
 	}
 
+	fn first_day_of_month(month_day: NaiveDate) -> NaiveDate {
+		month_day.with_day(1).unwrap_or(month_day)
+	}
+
 	pub fn go_forward(self, first_week_day: FirstWeekday) -> Self {
 		match self {
 			Self::Week { week_day } => Self::Week {
@@ -376,6 +1098,19 @@ impl CalendarView {
 			Self::ThreeDays { first_date } => Self::ThreeDays {
 				first_date: first_date.succ_opt().unwrap_or(first_date),
 			},
+			Self::Month { month_day } => {
+				let first_day = Self::first_day_of_month(month_day);
+				Self::Month {
+					month_day: first_day
+						.checked_add_months(Months::new(1))
+						.unwrap_or(first_day),
+				}
+			}
+			Self::Agenda { first_date } => Self::Agenda {
+				first_date: first_date
+					.checked_add_days(Days::new(Self::AGENDA_WEEKS * 7))
+					.unwrap_or(first_date),
+			},
 		}
 	}
 
@@ -391,6 +1126,19 @@ impl CalendarView {
 			Self::ThreeDays { first_date } => Self::ThreeDays {
 				first_date: first_date.pred_opt().unwrap_or(first_date),
 			},
+			Self::Month { month_day } => {
+				let first_day = Self::first_day_of_month(month_day);
+				Self::Month {
+					month_day: first_day
+						.checked_sub_months(Months::new(1))
+						.unwrap_or(first_day),
+				}
+			}
+			Self::Agenda { first_date } => Self::Agenda {
+				first_date: first_date
+					.checked_sub_days(Days::new(Self::AGENDA_WEEKS * 7))
+					.unwrap_or(first_date),
+			},
 		}
 	}
 
@@ -401,6 +1149,20 @@ impl CalendarView {
 				(week.first_day(), 7)
 			}
 			Self::ThreeDays { first_date } => (*first_date, 3),
+			Self::Month { month_day } => {
+				let first_day = Self::first_day_of_month(*month_day);
+				let last_day = first_day
+					.checked_add_months(Months::new(1))
+					.and_then(|next_month| next_month.pred_opt())
+					.unwrap_or(first_day);
+				let first_grid_day = first_day.week(first_week_day.as_week_day()).first_day();
+				let last_grid_day = last_day.week(first_week_day.as_week_day()).last_day();
+				(
+					first_grid_day,
+					(last_grid_day - first_grid_day).num_days() + 1,
+				)
+			}
+			Self::Agenda { first_date } => (*first_date, Self::AGENDA_WEEKS as i64 * 7),
 		};
 		(0..num_days)
 			.map(|i| {
@@ -417,7 +1179,11 @@ impl CalendarView {
 mod tests {
 	use chrono::{NaiveDate, Weekday};
 
-	use crate::{pages::overview_page::CalendarView, preferences::FirstWeekday};
+	use crate::{
+		pages::overview_page::{CalendarView, TimeBlockDrag, TimeBlockDragMode},
+		preferences::FirstWeekday,
+	};
+	use project_tracker_core::{ProjectId, SerializableTime, TaskId, TimeBlock};
 
 	#[test]
 	fn test_week_days() {
@@ -458,4 +1224,102 @@ mod tests {
 			]
 		);
 	}
+
+	#[test]
+	fn test_month_days() {
+		let month_day = NaiveDate::from_ymd_opt(2025, 2, 14).unwrap();
+		let days = CalendarView::Month { month_day }.days(FirstWeekday::Monday);
+
+		// february 2025: sat 1st to fri 28th
+		assert_eq!(days.len(), 35);
+		assert_eq!(
+			days.first(),
+			Some(&(Weekday::Mon, NaiveDate::from_ymd_opt(2025, 1, 27).unwrap()))
+		);
+		assert_eq!(
+			days.last(),
+			Some(&(Weekday::Sun, NaiveDate::from_ymd_opt(2025, 3, 2).unwrap()))
+		);
+
+		assert_eq!(
+			CalendarView::Month { month_day }.go_forward(FirstWeekday::Monday),
+			CalendarView::Month {
+				month_day: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+			}
+		);
+		assert_eq!(
+			CalendarView::Month { month_day }.go_backward(FirstWeekday::Monday),
+			CalendarView::Month {
+				month_day: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+			}
+		);
+	}
+
+	#[test]
+	fn test_agenda_days() {
+		let first_date = NaiveDate::from_ymd_opt(2025, 1, 30).unwrap();
+		let agenda = CalendarView::Agenda { first_date };
+		let days = agenda.days(FirstWeekday::Monday);
+
+		assert_eq!(days.len(), CalendarView::AGENDA_WEEKS as usize * 7);
+		assert_eq!(days.first(), Some(&(Weekday::Thu, first_date)));
+		assert_eq!(
+			agenda.go_forward(FirstWeekday::Monday),
+			CalendarView::Agenda {
+				first_date: NaiveDate::from_ymd_opt(2025, 2, 27).unwrap()
+			}
+		);
+	}
+
+	#[test]
+	fn test_time_block_drag() {
+		let day = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+		let next_day = day.succ_opt().unwrap();
+		let original = TimeBlock {
+			date: day.into(),
+			start: SerializableTime { hour: 9, minute: 0 },
+			duration_minutes: None,
+		};
+		let time_block_drag = TimeBlockDrag {
+			project_id: ProjectId::generate(),
+			task_id: TaskId::generate(),
+			mode: TimeBlockDragMode::Move,
+			original,
+			original_start_minute: 9 * 60,
+			grab_minute: None,
+			day,
+			start_minute: 9 * 60,
+			duration_minutes: 60,
+		};
+
+		// moving snaps to quarter hours and keeps the grab offset
+		let mut moving = time_block_drag;
+		moving.drag_to(day, 9.0 * 60.0 + 20.0);
+		moving.drag_to(next_day, 11.0 * 60.0 + 27.0);
+		assert_eq!(
+			moving.time_block(),
+			TimeBlock {
+				date: next_day.into(),
+				start: SerializableTime {
+					hour: 11,
+					minute: 0
+				},
+				duration_minutes: None,
+			}
+		);
+
+		// blocks cant be moved past the end of the day
+		moving.drag_to(day, 24.0 * 60.0);
+		assert_eq!(moving.start_minute, 23 * 60);
+
+		let mut resizing = TimeBlockDrag {
+			mode: TimeBlockDragMode::Resize,
+			..time_block_drag
+		};
+		resizing.drag_to(day, 10.0 * 60.0);
+		resizing.drag_to(day, 11.0 * 60.0 + 35.0);
+		assert_eq!(resizing.time_block().duration_minutes, Some(150));
+		resizing.drag_to(day, 8.0 * 60.0);
+		assert_eq!(resizing.time_block().duration_minutes, Some(15));
+	}
 }
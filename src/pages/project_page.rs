@@ -1,13 +1,19 @@
 use crate::{
 	components::{
-		cancel_search_tasks_button, color_palette, completion_bar, edit_color_palette_button,
-		horizontal_scrollable, loading_screen, on_input, open_create_task_modal_button,
-		project_context_menu_button, search_tasks_button, sort_dropdown_button, task_list,
-		task_tag_button, ScalarAnimation, HORIZONTAL_SCROLLABLE_PADDING,
+		cancel_search_tasks_button, clear_task_selection_button, color_palette, completion_bar,
+		delete_selected_tasks_button, due_date_button, edit_color_palette_button,
+		horizontal_scrollable, loading_screen, move_selected_tasks_dropdown_button, on_input,
+		open_create_task_modal_button, project_context_menu_button, search_tasks_button,
+		set_selected_tasks_done_button, set_selected_tasks_todo_button, sort_dropdown_button,
+		task_list, task_tag_button, ScalarAnimation, HORIZONTAL_SCROLLABLE_PADDING,
 		LARGE_LOADING_SPINNER_SIZE,
 	},
-	core::{import_source_code_todos, IcedColorConversion, SortModeUI},
+	core::{
+		import_source_code_todos, merge_source_code_todos, IcedColorConversion,
+		SerializableDateConversion, SortModeUI,
+	},
 	icons::{icon_to_char, Bootstrap, BOOTSTRAP_FONT},
+	integrations::scan_git_log,
 	pages,
 	project_tracker::{self, ProjectTrackerApp},
 	styles::{
@@ -28,11 +34,12 @@ use iced::{
 };
 use iced_aw::{drop_down, DropDown};
 use project_tracker_core::{
-	Database, DatabaseMessage, OrderedHashMap, Project, ProjectId, SerializableColor, SortMode,
-	Task, TaskId, TaskTagId, TaskType,
+	Database, DatabaseMessage, OrderedHashMap, Project, ProjectId, ScannedGitCommit,
+	SerializableColor, SerializableDate, SortMode, SourceCodeTodoSettings, Task, TaskId, TaskTagId,
+	TaskType,
 };
 use std::{collections::HashSet, path::PathBuf, sync::LazyLock, time::SystemTime};
-use tracing::error;
+use tracing::{debug, error};
 
 static PROJECT_NAME_TEXT_INPUT_ID: LazyLock<text_input::Id> = LazyLock::new(text_input::Id::unique);
 static SEARCH_TASKS_TEXT_INPUT_ID: LazyLock<text_input::Id> = LazyLock::new(text_input::Id::unique);
@@ -48,6 +55,7 @@ pub enum Message {
 	ShowContextMenu,
 	HideContextMenu,
 	OpenManageTaskTagsModal,
+	OpenSourceCodeTodoSettingsModal,
 
 	OpenSearchTasks,
 	CloseSearchTasks,
@@ -59,9 +67,13 @@ pub enum Message {
 		source_code_todos: OrderedHashMap<TaskId, Task>,
 	},
 	ReimportSourceCodeTodos,
+	GitCommitsScanned(Vec<ScannedGitCommit>),
 	ImportSourceCodeTodosDialogCanceled,
 
 	ShowSourceCodeTodos(bool),
+	OpenSourceCodeTodoAuthorDropdown,
+	CloseSourceCodeTodoAuthorDropdown,
+	SetSourceCodeTodoAuthorFilter(Option<String>),
 
 	ShowDoneTasks(bool),
 
@@ -75,6 +87,21 @@ pub enum Message {
 	ConfirmDeleteProject,
 
 	AnimateProgressbar,
+
+	ToggleTaskSelection(TaskId),
+	SelectTaskRange(TaskId),
+	ClearTaskSelection,
+	SetSelectedTasksDone,
+	SetSelectedTasksTodo,
+	DeleteSelectedTasks,
+	OpenMoveSelectedTasksDropdown,
+	CloseMoveSelectedTasksDropdown,
+	MoveSelectedTasks(ProjectId),
+	EditSelectedTasksDueDate,
+	StopEditingSelectedTasksDueDate,
+	SetSelectedTasksDueDate(Option<SerializableDate>),
+	AddTaskTagToSelectedTasks(TaskTagId),
+	RemoveTaskTagFromSelectedTasks(TaskTagId),
 }
 
 impl From<Message> for pages::Message {
@@ -111,6 +138,7 @@ impl CachedTaskList {
 		project: &Project,
 		task_tag_filter: &HashSet<TaskTagId>,
 		search_filter: &Option<String>,
+		source_code_todo_author_filter: &Option<String>,
 		sort_unspecified_tasks_at_bottom: bool,
 	) -> Self {
 		let mut todo_list = Vec::new();
@@ -202,6 +230,16 @@ impl CachedTaskList {
 			}
 		}
 
+		if let Some(author) = source_code_todo_author_filter {
+			source_code_todo_list.retain(|task_id| {
+				project
+					.source_code_todos
+					.get(task_id)
+					.and_then(|task| task.source_code_blame.as_ref())
+					.is_some_and(|blame| blame.author == *author)
+			});
+		}
+
 		Self::new(todo_list, done_list, source_code_todo_list)
 	}
 }
@@ -212,6 +250,8 @@ pub struct Page {
 	pub cached_task_list: CachedTaskList,
 	show_done_tasks: bool,
 	show_source_code_todos: bool,
+	source_code_todo_author_filter: Option<String>,
+	show_source_code_todo_author_dropdown: bool,
 	show_color_picker: bool,
 	pub filter_task_tags: HashSet<TaskTagId>,
 	search_tasks_filter: Option<String>,
@@ -220,6 +260,10 @@ pub struct Page {
 	show_context_menu: bool,
 	show_sort_mode_dropdown: bool,
 	pub importing_source_code_todos: bool,
+	selected_tasks: HashSet<TaskId>,
+	task_selection_anchor: Option<TaskId>,
+	show_move_selected_tasks_dropdown: bool,
+	edit_selected_tasks_due_date: bool,
 }
 
 impl Page {
@@ -232,6 +276,7 @@ impl Page {
 			project,
 			&HashSet::new(),
 			&None,
+			&None,
 			preferences.sort_unspecified_tasks_at_bottom(),
 		);
 
@@ -240,6 +285,8 @@ impl Page {
 			cached_task_list,
 			show_done_tasks: false,
 			show_source_code_todos: true,
+			source_code_todo_author_filter: None,
+			show_source_code_todo_author_dropdown: false,
 			show_color_picker: false,
 			filter_task_tags: HashSet::new(),
 			search_tasks_filter: None,
@@ -248,6 +295,10 @@ impl Page {
 			show_context_menu: false,
 			show_sort_mode_dropdown: false,
 			importing_source_code_todos: false,
+			selected_tasks: HashSet::new(),
+			task_selection_anchor: None,
+			show_move_selected_tasks_dropdown: false,
+			edit_selected_tasks_due_date: false,
 		}
 	}
 
@@ -260,6 +311,54 @@ impl Page {
 				.unwrap_or(false)
 	}
 
+	pub fn has_selected_tasks(&self) -> bool {
+		!self.selected_tasks.is_empty()
+	}
+
+	/// visible tasks in the same order as they are displayed in the task list
+	fn visible_task_ids(&self) -> Vec<TaskId> {
+		let mut task_ids = self.cached_task_list.todo.clone();
+		if self.show_source_code_todos {
+			task_ids.extend(self.cached_task_list.source_code_todo.iter().copied());
+		}
+		if self.show_done_tasks {
+			task_ids.extend(self.cached_task_list.done.iter().copied());
+		}
+		task_ids
+	}
+
+	/// selected tasks in the order they are stored in the project
+	fn selected_tasks_of<'a>(
+		&'a self,
+		project: &'a Project,
+	) -> impl Iterator<Item = (TaskId, &'a Task, TaskType)> + 'a {
+		project
+			.iter()
+			.filter(move |(task_id, _task, _task_type)| self.selected_tasks.contains(task_id))
+	}
+
+	fn clear_task_selection(&mut self) {
+		self.selected_tasks.clear();
+		self.task_selection_anchor = None;
+		self.show_move_selected_tasks_dropdown = false;
+		self.edit_selected_tasks_due_date = false;
+	}
+
+	fn selected_tasks_action(
+		&self,
+		database: Option<&Database>,
+		f: impl Fn(TaskId, &Task, TaskType) -> Option<DatabaseMessage>,
+	) -> pages::Action {
+		match database.and_then(|db| db.get_project(&self.project_id)) {
+			Some(project) => pages::Action::DatabaseMessages(
+				self.selected_tasks_of(project)
+					.filter_map(|(task_id, task, task_type)| f(task_id, task, task_type))
+					.collect(),
+			),
+			None => pages::Action::None,
+		}
+	}
+
 	pub fn update(
 		&mut self,
 		message: Message,
@@ -298,6 +397,10 @@ impl Page {
 				self.show_context_menu = false;
 				pages::Action::OpenManageTaskTagsModal(self.project_id)
 			}
+			Message::OpenSourceCodeTodoSettingsModal => {
+				self.show_context_menu = false;
+				pages::Action::OpenSourceCodeTodoSettingsModal(self.project_id)
+			}
 
 			Message::OpenSearchTasks => {
 				self.search_tasks_filter = Some(String::new());
@@ -324,8 +427,12 @@ impl Page {
 			Message::ImportSourceCodeTodosDialog => {
 				self.show_context_menu = false;
 				self.importing_source_code_todos = true;
+				let settings = database
+					.and_then(|db| db.get_project(&self.project_id))
+					.map(|project| project.source_code_todo_settings.clone())
+					.unwrap_or_default();
 				iced::Task::perform(
-					Self::pick_todo_source_code_folder_dialog(),
+					Self::pick_todo_source_code_folder_dialog(settings),
 					|source_code_todos| match source_code_todos {
 						Some((source_code_directory, source_code_todos)) => {
 							Message::ImportSourceCodeTodos {
@@ -348,24 +455,83 @@ impl Page {
 				source_code_todos,
 			} => {
 				self.importing_source_code_todos = false;
-				DatabaseMessage::ImportSourceCodeTodos {
-					project_id: self.project_id,
-					source_code_directory,
-					source_code_todo_tasks: source_code_todos,
+				// keep the ids of todos that were already imported from this directory
+				let source_code_todos = match database
+					.and_then(|db| db.get_project(&self.project_id))
+					.filter(|project| {
+						project.source_code_directory.as_ref() == Some(&source_code_directory)
+					}) {
+					Some(project) => merge_source_code_todos(
+						&project.source_code_todos,
+						source_code_todos,
+						|_| true,
+					),
+					None => source_code_todos,
+				};
+				let scan_git_log_directory = source_code_directory.clone();
+				pages::Action::Actions(vec![
+					DatabaseMessage::ImportSourceCodeTodos {
+						project_id: self.project_id,
+						source_code_directory,
+						source_code_todo_tasks: source_code_todos,
+					}
+					.into(),
+					iced::Task::perform(
+						async move { scan_git_log(&scan_git_log_directory) },
+						|result| match result {
+							Ok(commits) => Message::GitCommitsScanned(commits).into(),
+							Err(e) => {
+								debug!("no git commits to link: {e}");
+								Message::GitCommitsScanned(Vec::new()).into()
+							}
+						},
+					)
+					.into(),
+				])
+			}
+			Message::GitCommitsScanned(commits) => {
+				let links_changed = database
+					.and_then(|db| db.get_project(&self.project_id))
+					.is_some_and(|project| {
+						let mut linked_project = project.clone();
+						linked_project.link_git_commits(
+							&commits,
+							project.source_code_todo_settings.mark_done_by_commits,
+						);
+						linked_project != *project
+					});
+				if links_changed {
+					DatabaseMessage::LinkGitCommits {
+						project_id: self.project_id,
+						commits,
+					}
+					.into()
+				} else {
+					pages::Action::None
 				}
-				.into()
 			}
 			Message::ReimportSourceCodeTodos => {
 				match database
 					.and_then(|db| db.get_project(&self.project_id))
-					.and_then(|project| project.source_code_directory.clone())
-				{
-					Some(source_code_directory) => {
+					.and_then(|project| {
+						project
+							.source_code_directory
+							.clone()
+							.map(|source_code_directory| {
+								(
+									source_code_directory,
+									project.source_code_todo_settings.clone(),
+								)
+							})
+					}) {
+					Some((source_code_directory, settings)) => {
 						self.importing_source_code_todos = true;
 						let source_code_directory_clone = source_code_directory.clone();
 
 						iced::Task::perform(
-							async move { import_source_code_todos(source_code_directory_clone) },
+							async move {
+								import_source_code_todos(source_code_directory_clone, &settings)
+							},
 							move |source_code_todos| {
 								Message::ImportSourceCodeTodos {
 									source_code_directory: source_code_directory.clone(),
@@ -386,6 +552,22 @@ impl Page {
 				self.show_source_code_todos = show;
 				pages::Action::None
 			}
+			Message::OpenSourceCodeTodoAuthorDropdown => {
+				self.show_source_code_todo_author_dropdown = true;
+				pages::Action::None
+			}
+			Message::CloseSourceCodeTodoAuthorDropdown => {
+				self.show_source_code_todo_author_dropdown = false;
+				pages::Action::None
+			}
+			Message::SetSourceCodeTodoAuthorFilter(author) => {
+				self.show_source_code_todo_author_dropdown = false;
+				self.source_code_todo_author_filter = author;
+				if let Some(database) = database {
+					self.generate_cached_task_list(database, preferences);
+				}
+				pages::Action::None
+			}
 
 			Message::ShowDoneTasks(show) => {
 				self.show_done_tasks = show;
@@ -452,6 +634,131 @@ impl Page {
 				self.progressbar_animation.update();
 				pages::Action::None
 			}
+
+			Message::ToggleTaskSelection(task_id) => {
+				if self.selected_tasks.contains(&task_id) {
+					self.selected_tasks.remove(&task_id);
+				} else {
+					self.selected_tasks.insert(task_id);
+				}
+				self.task_selection_anchor = Some(task_id);
+				pages::Action::None
+			}
+			Message::SelectTaskRange(task_id) => {
+				let visible_task_ids = self.visible_task_ids();
+				let anchor_index = self.task_selection_anchor.and_then(|anchor| {
+					visible_task_ids
+						.iter()
+						.position(|visible_task_id| *visible_task_id == anchor)
+				});
+				let task_index = visible_task_ids
+					.iter()
+					.position(|visible_task_id| *visible_task_id == task_id);
+
+				match (anchor_index, task_index) {
+					(Some(anchor_index), Some(task_index)) => {
+						let range = anchor_index.min(task_index)..=anchor_index.max(task_index);
+						self.selected_tasks
+							.extend(visible_task_ids[range].iter().copied());
+					}
+					_ => {
+						self.selected_tasks.insert(task_id);
+						self.task_selection_anchor = Some(task_id);
+					}
+				}
+				pages::Action::None
+			}
+			Message::ClearTaskSelection => {
+				self.clear_task_selection();
+				pages::Action::None
+			}
+			Message::SetSelectedTasksDone => {
+				self.selected_tasks_action(database, |task_id, _task, task_type| {
+					matches!(task_type, TaskType::Todo).then_some(DatabaseMessage::SetTaskDone {
+						project_id: self.project_id,
+						task_id,
+					})
+				})
+			}
+			Message::SetSelectedTasksTodo => {
+				self.selected_tasks_action(database, |task_id, _task, task_type| {
+					task_type.is_done().then_some(DatabaseMessage::SetTaskTodo {
+						project_id: self.project_id,
+						task_id,
+					})
+				})
+			}
+			Message::DeleteSelectedTasks => {
+				let action = self.selected_tasks_action(database, |task_id, _task, _task_type| {
+					Some(DatabaseMessage::DeleteTask {
+						project_id: self.project_id,
+						task_id,
+					})
+				});
+				self.clear_task_selection();
+				action
+			}
+			Message::OpenMoveSelectedTasksDropdown => {
+				self.show_move_selected_tasks_dropdown = true;
+				pages::Action::None
+			}
+			Message::CloseMoveSelectedTasksDropdown => {
+				self.show_move_selected_tasks_dropdown = false;
+				pages::Action::None
+			}
+			Message::MoveSelectedTasks(dst_project_id) => {
+				let action = if dst_project_id == self.project_id {
+					pages::Action::None
+				} else {
+					self.selected_tasks_action(database, |task_id, _task, _task_type| {
+						Some(DatabaseMessage::MoveTask {
+							task_id,
+							src_project_id: self.project_id,
+							dst_project_id,
+						})
+					})
+				};
+				self.clear_task_selection();
+				action
+			}
+			Message::EditSelectedTasksDueDate => {
+				self.edit_selected_tasks_due_date = true;
+				pages::Action::None
+			}
+			Message::StopEditingSelectedTasksDueDate => {
+				self.edit_selected_tasks_due_date = false;
+				pages::Action::None
+			}
+			Message::SetSelectedTasksDueDate(new_due_date) => {
+				self.edit_selected_tasks_due_date = false;
+				self.selected_tasks_action(database, |task_id, task, _task_type| {
+					(task.due_date != new_due_date).then_some(DatabaseMessage::ChangeTaskDueDate {
+						project_id: self.project_id,
+						task_id,
+						new_due_date,
+					})
+				})
+			}
+			Message::AddTaskTagToSelectedTasks(task_tag_id) => {
+				self.selected_tasks_action(database, |task_id, task, _task_type| {
+					(!task.tags.contains(&task_tag_id)).then_some(DatabaseMessage::ToggleTaskTag {
+						project_id: self.project_id,
+						task_id,
+						task_tag_id,
+					})
+				})
+			}
+			Message::RemoveTaskTagFromSelectedTasks(task_tag_id) => {
+				self.selected_tasks_action(database, |task_id, task, _task_type| {
+					task.tags
+						.contains(&task_tag_id)
+						.then_some(DatabaseMessage::ToggleTaskTag {
+							project_id: self.project_id,
+							task_id,
+							task_tag_id,
+						})
+				})
+			}
 		};
 
 		if let Some(database_ref) = database {
@@ -482,9 +789,13 @@ impl Page {
 		match &app.database {
 			DatabaseState::Loaded(database) => {
 				match database.get_project(&self.project_id) {
-					Some(project) => column![
-						self.project_details_view(project),
-						task_list(
+					Some(project) => column![self.project_details_view(project)]
+						.push_maybe(if self.selected_tasks.is_empty() {
+							None
+						} else {
+							Some(self.selected_tasks_view(database, project, app))
+						})
+						.push(task_list(
 							self.project_id,
 							project,
 							&self.cached_task_list,
@@ -493,15 +804,17 @@ impl Page {
 							app.dragged_task,
 							app.just_minimal_dragging,
 							app.sidebar_page.task_dropzone_hovered,
+							&self.selected_tasks,
 							self.show_done_tasks,
 							self.show_source_code_todos,
-							self.importing_source_code_todos
-						),
-					]
-					// .spacing(SPACING_AMOUNT) this is not needed since every task in the list has a SPACING_AMOUNT height dropzone
-					.width(Fill)
-					.height(Fill)
-					.into(),
+							self.source_code_todo_author_filter.as_deref(),
+							self.show_source_code_todo_author_dropdown,
+							self.importing_source_code_todos,
+						))
+						// .spacing(SPACING_AMOUNT) this is not needed since every task in the list has a SPACING_AMOUNT height dropzone
+						.width(Fill)
+						.height(Fill)
+						.into(),
 					None => {
 						error!("invalid project_id inside project_page: doesnt exist in database!");
 						text("<Invalid ProjectId>").into()
@@ -645,6 +958,82 @@ impl Page {
 		.into()
 	}
 
+	fn selected_tasks_view<'a>(
+		&'a self,
+		database: &'a Database,
+		project: &'a Project,
+		app: &'a ProjectTrackerApp,
+	) -> Element<'a, project_tracker::Message> {
+		let selected_tasks: Vec<&Task> = self
+			.selected_tasks_of(project)
+			.map(|(_task_id, task, _task_type)| task)
+			.collect();
+
+		let task_tags_list: Vec<Element<project_tracker::Message>> = project
+			.task_tags
+			.iter()
+			.map(|(tag_id, tag)| {
+				let all_selected_tasks_tagged = selected_tasks
+					.iter()
+					.all(|task| task.tags.contains(&tag_id));
+				task_tag_button(tag, all_selected_tasks_tagged)
+					.on_press(if all_selected_tasks_tagged {
+						Message::RemoveTaskTagFromSelectedTasks(tag_id).into()
+					} else {
+						Message::AddTaskTagToSelectedTasks(tag_id).into()
+					})
+					.into()
+			})
+			.collect();
+
+		let due_date_view = due_date_button(
+			self.edit_selected_tasks_due_date,
+			&None,
+			app.preferences.date_formatting(),
+			Message::EditSelectedTasksDueDate.into(),
+			Message::StopEditingSelectedTasksDueDate.into(),
+			|date| {
+				Message::SetSelectedTasksDueDate(Some(SerializableDate::from_iced_date(date)))
+					.into()
+			},
+			Message::SetSelectedTasksDueDate(None).into(),
+		);
+
+		column![
+			row![
+				text(format!("{} selected", selected_tasks.len())),
+				Space::new(Fill, 0.0),
+				set_selected_tasks_done_button(),
+				set_selected_tasks_todo_button(),
+				due_date_view,
+				move_selected_tasks_dropdown_button(
+					self.show_move_selected_tasks_dropdown,
+					self.project_id,
+					database
+				),
+				delete_selected_tasks_button(selected_tasks.len()),
+				clear_task_selection_button(),
+			]
+			.spacing(SPACING_AMOUNT)
+			.align_y(Alignment::Center),
+			row![
+				container("Tags:").padding(HORIZONTAL_SCROLLABLE_PADDING),
+				horizontal_scrollable(Row::with_children(task_tags_list).spacing(SPACING_AMOUNT))
+					.width(Fill),
+			]
+			.spacing(SPACING_AMOUNT)
+			.align_y(Alignment::Center),
+		]
+		.spacing(SPACING_AMOUNT)
+		.padding(Padding {
+			top: PADDING_AMOUNT,
+			bottom: 0.0,
+			left: PADDING_AMOUNT,
+			right: PADDING_AMOUNT,
+		})
+		.into()
+	}
+
 	fn start_progressbar_animation(&mut self, start_percentage: f32, target_percentage: f32) {
 		self.progressbar_animation =
 			ScalarAnimation::start(start_percentage, target_percentage, 0.125);
@@ -656,10 +1045,14 @@ impl Page {
 		preferences: &Option<Preferences>,
 	) {
 		if let Some(project) = database.get_project(&self.project_id) {
+			self.selected_tasks
+				.retain(|task_id| project.get_task(task_id).is_some());
+
 			self.cached_task_list = CachedTaskList::generate(
 				project,
 				&self.filter_task_tags,
 				&self.search_tasks_filter,
+				&self.source_code_todo_author_filter,
 				preferences.sort_unspecified_tasks_at_bottom(),
 			);
 
@@ -675,8 +1068,9 @@ impl Page {
 		}
 	}
 
-	async fn pick_todo_source_code_folder_dialog() -> Option<(PathBuf, OrderedHashMap<TaskId, Task>)>
-	{
+	async fn pick_todo_source_code_folder_dialog(
+		settings: SourceCodeTodoSettings,
+	) -> Option<(PathBuf, OrderedHashMap<TaskId, Task>)> {
 		let file_dialog_result = rfd::AsyncFileDialog::new()
 			.set_title("Import Todos from source code folder")
 			.pick_folder()
@@ -684,7 +1078,10 @@ impl Page {
 
 		file_dialog_result.map(|folder_handle| {
 			let pathbuf = folder_handle.path().to_path_buf();
-			(pathbuf.clone(), import_source_code_todos(pathbuf))
+			(
+				pathbuf.clone(),
+				import_source_code_todos(pathbuf, &settings),
+			)
 		})
 	}
 }
@@ -1,6 +1,6 @@
 use crate::components::{
 	create_new_project_button, custom_project_preview, loading_screen, overview_button,
-	project_preview, settings_button, stopwatch_button, toggle_sidebar_button,
+	project_preview, settings_button, stopwatch_button, today_button, toggle_sidebar_button,
 	LARGE_LOADING_SPINNER_SIZE,
 };
 use crate::core::{IcedColorConversion, ProjectUiIdMap, TaskUiIdMap};
@@ -12,12 +12,13 @@ use crate::{
 		horizontal_seperator, in_between_dropzone, on_input, vertical_scrollable,
 		COLOR_PALETTE_BLACK, COLOR_PALETTE_WHITE,
 	},
-	pages::stopwatch_page,
+	pages::{overview_page::calendar_day_dropzone_id, stopwatch_page},
 	project_tracker::{self, ProjectTrackerApp},
 	styles::{
 		text_input_style_default, MINIMAL_DRAG_DISTANCE, PADDING_AMOUNT, SMALL_SPACING_AMOUNT,
 	},
 };
+use chrono::NaiveDate;
 use iced::widget::Space;
 use iced::{
 	advanced::widget::Id,
@@ -65,6 +66,7 @@ pub enum Message {
 		project_id: ProjectId,
 		task_id: TaskId,
 		zones: Vec<(Id, Rectangle)>,
+		calendar_days: Vec<NaiveDate>,
 	},
 	HandleTaskZones {
 		project_id: ProjectId,
@@ -76,6 +78,8 @@ pub enum Message {
 		task_id: TaskId,
 		task_is_todo: bool,
 		filtering_tasks: bool,
+		/// days of the overview calendar that tasks can be dropped onto
+		calendar_days: Vec<NaiveDate>,
 		point: Point,
 		rect: Rectangle,
 	},
@@ -292,6 +296,13 @@ impl Page {
 							task_id,
 						}
 						.into(),
+
+						TaskDropzone::CalendarDay(day) => DatabaseMessage::ChangeTaskDueDate {
+							project_id,
+							task_id,
+							new_due_date: Some(day.into()),
+						}
+						.into(),
 					}
 				}
 				None => Action::None,
@@ -300,7 +311,11 @@ impl Page {
 				self.task_dropzone_hovered = None;
 				Action::None
 			}
-			Message::HandleProjectZonesForTasks { zones, .. } => {
+			Message::HandleProjectZonesForTasks {
+				zones,
+				calendar_days,
+				..
+			} => {
 				self.task_dropzone_hovered = None;
 				if let Some(projects) = database.as_ref().map(|db| db.projects()) {
 					for (id, _bounds) in zones.iter() {
@@ -318,6 +333,12 @@ impl Page {
 						if *id == STOPWATCH_TASK_DROPZONE_ID.clone().into() {
 							self.task_dropzone_hovered = Some(TaskDropzone::Stopwatch);
 						}
+						for day in calendar_days.iter() {
+							if *id == calendar_day_dropzone_id(*day).into() {
+								self.task_dropzone_hovered = Some(TaskDropzone::CalendarDay(*day));
+								break;
+							}
+						}
 					}
 				}
 				Action::None
@@ -326,8 +347,10 @@ impl Page {
 				zones, project_id, ..
 			} => {
 				if !zones.is_empty()
-					&& !matches!(self.task_dropzone_hovered, Some(TaskDropzone::Project(_)))
-				{
+					&& !matches!(
+						self.task_dropzone_hovered,
+						Some(TaskDropzone::Project(_) | TaskDropzone::CalendarDay(_))
+					) {
 					self.task_dropzone_hovered = None;
 					let is_hovered = |target_id| {
 						for (id, _bounds) in zones.iter() {
@@ -361,16 +384,26 @@ impl Page {
 				task_id,
 				task_is_todo,
 				filtering_tasks,
+				calendar_days,
 				rect,
 				point,
 			} => {
 				let project_options =
-					Self::project_dropzones_for_tasks_options(database, project_id, project_ui_ids);
+					Self::project_dropzones_for_tasks_options(database, project_id, project_ui_ids)
+						.map(|mut options| {
+							options.extend(
+								calendar_days
+									.iter()
+									.map(|day| calendar_day_dropzone_id(*day).into()),
+							);
+							options
+						});
 				let mut commands = vec![zones_on_point(
 					move |zones| Message::HandleProjectZonesForTasks {
 						project_id,
 						task_id,
 						zones,
+						calendar_days: calendar_days.clone(),
 					},
 					point,
 					project_options,
@@ -606,6 +639,7 @@ impl Page {
 				]
 				.align_y(Alignment::Center)
 				.spacing(SMALL_SPACING_AMOUNT),
+				today_button(app.content_page.is_today_page_opened()),
 				horizontal_seperator(),
 			]
 			.spacing(SPACING_AMOUNT)
@@ -775,4 +809,5 @@ pub enum TaskDropzone {
 	Task(TaskId),
 	EndOfTodoTaskList,
 	Stopwatch,
+	CalendarDay(NaiveDate),
 }
@@ -2,9 +2,9 @@ use crate::{
 	components::{
 		complete_task_timer_button, days_left_widget, horizontal_scrollable, loading_screen,
 		open_project_button, open_task_by_name_link_button, pause_timer_button,
-		resume_timer_button, stop_timer_button, take_break_button, task_description,
-		track_time_button, StopwatchClock, HORIZONTAL_SCROLLABLE_PADDING,
-		LARGE_LOADING_SPINNER_SIZE,
+		resume_timer_button, skip_pomodoro_phase_button, start_pomodoro_button, stop_timer_button,
+		take_break_button, task_description, track_time_button, StopwatchClock,
+		HORIZONTAL_SCROLLABLE_PADDING, LARGE_LOADING_SPINNER_SIZE,
 	},
 	core::IcedColorConversion,
 	pages, project_tracker,
@@ -22,7 +22,10 @@ use iced::{
 	Length::{self, Fill, Fixed},
 	Padding, Subscription,
 };
-use project_tracker_core::{Database, DatabaseMessage, Project, ProjectId, Task, TaskId, TaskType};
+use project_tracker_core::{
+	Database, DatabaseMessage, Project, ProjectId, Task, TaskId, TaskType, TrackedSession,
+};
+use serde::{Deserialize, Serialize};
 use std::time::{Duration, Instant};
 use tracing::error;
 
@@ -50,6 +53,33 @@ pub enum Page {
 		clock: StopwatchClock,
 		break_over_notification_sent: bool,
 	},
+	Pomodoro {
+		elapsed_time: Duration,
+		last_update: Instant,
+		paused: bool,
+		phase: PomodoroPhase,
+		completed_work_sessions: usize,
+		/// time spend and completed pomodoros get logged to this task
+		task: Option<(ProjectId, TaskId)>,
+		clock: StopwatchClock,
+	},
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PomodoroPhase {
+	Work,
+	ShortBreak,
+	LongBreak,
+}
+
+impl PomodoroPhase {
+	pub fn label(&self) -> &'static str {
+		match self {
+			Self::Work => "Focus",
+			Self::ShortBreak => "Short break",
+			Self::LongBreak => "Long break",
+		}
+	}
 }
 
 #[derive(Clone, Debug)]
@@ -60,6 +90,8 @@ pub enum Message {
 		task_id: TaskId,
 	},
 	TakeBreak(usize), // minutes
+	StartPomodoro(Option<(ProjectId, TaskId)>),
+	SkipPomodoroPhase,
 	StartupAgain(StopwatchProgress),
 	Stop,
 	Pause,
@@ -152,13 +184,32 @@ impl Page {
 					pages::Action::None,
 				)
 			}
+			StopwatchProgress::Pomodoro {
+				elapsed_time_seconds,
+				paused,
+				phase,
+				completed_work_sessions,
+				task,
+			} => {
+				let page = Page::Pomodoro {
+					elapsed_time: Duration::from_secs(elapsed_time_seconds),
+					last_update: Instant::now(),
+					paused,
+					phase,
+					completed_work_sessions,
+					task,
+					clock: StopwatchClock::new(0.0, 0.0, None),
+				};
+				let action = page.pomodoro_task_time_spend_action(true, database);
+				(page, action)
+			}
 		}
 	}
 
 	pub fn subscription(&self, opened: bool) -> Subscription<Message> {
 		let redraw_subscription = match self {
 			Page::Idle => Subscription::none(),
-			Page::StopTaskTime { .. } | Page::TakingBreak { .. } => {
+			Page::StopTaskTime { .. } | Page::TakingBreak { .. } | Page::Pomodoro { .. } => {
 				if opened {
 					window::frames().map(|_| Message::Update)
 				} else {
@@ -188,6 +239,12 @@ impl Page {
 				task_id: stopped_task_id,
 				..
 			} if *stopped_project_id == project_id && *stopped_task_id == task_id => !*paused,
+			Page::Pomodoro {
+				paused,
+				phase: PomodoroPhase::Work,
+				task: Some((stopped_project_id, stopped_task_id)),
+				..
+			} if *stopped_project_id == project_id && *stopped_task_id == task_id => !*paused,
 			_ => false,
 		}
 	}
@@ -220,6 +277,25 @@ impl Page {
 		})
 	}
 
+	/// the tracked session is measured by the client,
+	/// so that every replica of the database records the same session
+	fn stop_task_time_spend_action(
+		project_id: ProjectId,
+		task_id: TaskId,
+		database: Option<&Database>,
+	) -> pages::Action {
+		let tracked_session = database
+			.and_then(|db| db.get_task(&project_id, &task_id))
+			.and_then(|task| task.time_spend.as_ref()?.tracking_duration())
+			.map(TrackedSession::ending_now);
+		DatabaseMessage::StopTaskTimeSpend {
+			project_id,
+			task_id,
+			tracked_session,
+		}
+		.into()
+	}
+
 	pub fn update(
 		&mut self,
 		message: Message,
@@ -276,18 +352,51 @@ impl Page {
 				self.set_stopwatch_progress(preferences);
 				pages::Action::None
 			}
+			Message::StartPomodoro(task) => {
+				let stop_action = self.stop(database, preferences);
+				let pomodoro_settings = preferences.pomodoro_settings();
+				*self = Page::Pomodoro {
+					elapsed_time: Duration::from_secs(0),
+					last_update: Instant::now(),
+					paused: false,
+					phase: PomodoroPhase::Work,
+					completed_work_sessions: 0,
+					task,
+					clock: StopwatchClock::new(
+						0.0,
+						pomodoro_settings.phase_minutes(PomodoroPhase::Work) as f32 * 60.0,
+						None,
+					),
+				};
+				self.set_stopwatch_progress(preferences);
+				pages::Action::Actions(vec![
+					stop_action,
+					pages::Action::OpenStopwatch,
+					self.pomodoro_task_time_spend_action(true, database),
+				])
+			}
+			Message::SkipPomodoroPhase => {
+				let action = self.advance_pomodoro_phase(false, database, preferences);
+				self.set_stopwatch_progress(preferences);
+				action
+			}
 			Message::StartupAgain(progress) => {
 				let (new_self, action) = Self::startup_again(progress, database);
 				*self = new_self;
 				action
 			}
-			Message::Stop => self.stop(preferences),
+			Message::Stop => self.stop(database, preferences),
 			Message::Resume => match self {
 				Page::TrackTime { paused, .. } | Page::TakingBreak { paused, .. } => {
 					*paused = false;
 					self.set_stopwatch_progress(preferences);
 					pages::Action::None
 				}
+				Page::Pomodoro { paused, .. } => {
+					*paused = false;
+					self.set_stopwatch_progress(preferences);
+					self.pomodoro_task_time_spend_action(true, database)
+				}
 				Page::StopTaskTime {
 					project_id,
 					task_id,
@@ -313,6 +422,14 @@ impl Page {
 					self.set_stopwatch_progress(preferences);
 					pages::Action::None
 				}
+				Page::Pomodoro { .. } => {
+					let action = self.pomodoro_task_time_spend_action(false, database);
+					if let Page::Pomodoro { paused, .. } = self {
+						*paused = true;
+					}
+					self.set_stopwatch_progress(preferences);
+					action
+				}
 				Page::StopTaskTime {
 					project_id,
 					task_id,
@@ -324,11 +441,7 @@ impl Page {
 					let task_id = *task_id;
 					self.set_stopwatch_progress(preferences);
 
-					DatabaseMessage::StopTaskTimeSpend {
-						project_id,
-						task_id,
-					}
-					.into()
+					Self::stop_task_time_spend_action(project_id, task_id, database)
 				}
 				Page::Idle => pages::Action::None,
 			},
@@ -355,16 +468,20 @@ impl Page {
 								.into()
 							} else {
 								// pause
-								DatabaseMessage::StopTaskTimeSpend {
-									project_id: *project_id,
-									task_id: *task_id,
-								}
-								.into()
+								Self::stop_task_time_spend_action(*project_id, *task_id, database)
 							};
 							*paused = !*paused;
 							self.set_stopwatch_progress(preferences);
 							action
 						}
+						Page::Pomodoro { paused, .. } => {
+							let message = if *paused {
+								Message::Resume
+							} else {
+								Message::Pause
+							};
+							self.update(message, database, preferences, opened)
+						}
 						Page::Idle => {
 							self.update(Message::StartTrackingTime, database, preferences, opened)
 						}
@@ -379,6 +496,10 @@ impl Page {
 						project_id,
 						task_id,
 						..
+					}
+					| Page::Pomodoro {
+						task: Some((project_id, task_id)),
+						..
 					} => DatabaseMessage::SetTaskDone {
 						project_id: *project_id,
 						task_id: *task_id,
@@ -386,9 +507,11 @@ impl Page {
 					.into(),
 					_ => pages::Action::None,
 				};
-				pages::Action::Actions(vec![set_task_done_action, self.stop(preferences)])
+				pages::Action::Actions(vec![set_task_done_action, self.stop(database, preferences)])
 			}
 			Message::Update => {
+				let mut action = pages::Action::None;
+
 				// advance time
 				match self {
 					// stop_task_time stores the start time to get the elapsed time
@@ -403,6 +526,12 @@ impl Page {
 						last_update,
 						paused,
 						..
+					}
+					| Page::Pomodoro {
+						elapsed_time,
+						last_update,
+						paused,
+						..
 					} => {
 						if !*paused {
 							*elapsed_time += Instant::now().duration_since(*last_update);
@@ -460,7 +589,7 @@ impl Page {
 												*finished_notification_sent = true;
 
 												if preferences.play_timer_notification_sound() {
-													timer_notification(
+													desktop_notification(
 														format!(
 															"{} min. timer finished!",
 															needed_minutes
@@ -496,30 +625,44 @@ impl Page {
 							*break_over_notification_sent = true;
 
 							if preferences.play_timer_notification_sound() {
-								timer_notification(
+								desktop_notification(
 									format!("{break_duration_minutes} min. break is over!"),
 									"".to_string(),
 								);
 							}
 						}
 					}
+					Page::Pomodoro {
+						elapsed_time,
+						phase,
+						clock,
+						..
+					} => {
+						let timer_seconds = elapsed_time.as_secs_f32();
+						let needed_seconds =
+							preferences.pomodoro_settings().phase_minutes(*phase) as f32 * 60.0;
+
+						if timer_seconds >= needed_seconds {
+							action = self.advance_pomodoro_phase(true, database, preferences);
+						} else {
+							clock.set_percentage(timer_seconds / needed_seconds);
+							clock.set_seconds_left(needed_seconds - timer_seconds);
+						}
+					}
 					_ => {}
 				}
 
 				self.set_stopwatch_progress(preferences);
 
-				pages::Action::None
+				action
 			}
 			Message::SaveTaskTimeSpendBeforeClosing => match self {
 				Page::StopTaskTime {
 					project_id,
 					task_id,
 					..
-				} => DatabaseMessage::StopTaskTimeSpend {
-					project_id: *project_id,
-					task_id: *task_id,
-				}
-				.into(),
+				} => Self::stop_task_time_spend_action(*project_id, *task_id, database),
+				Page::Pomodoro { .. } => self.pomodoro_task_time_spend_action(false, database),
 				_ => pages::Action::None,
 			},
 		}
@@ -544,13 +687,20 @@ impl Page {
 				.align_x(Alignment::Center)
 				.spacing(LARGE_SPACING_AMOUNT);
 
+				let pomodoro = column![
+					text("or focus in pomodoros:").size(45),
+					start_pomodoro_button(None),
+				]
+				.align_x(Alignment::Center)
+				.spacing(LARGE_SPACING_AMOUNT);
+
 				let page_view: Element<project_tracker::Message> = if size.width > size.height * 2.0
 				{
-					row![track_time, take_break]
+					row![track_time, take_break, pomodoro]
 						.spacing(LARGE_SPACING_AMOUNT * 3)
 						.into()
 				} else {
-					column![track_time, take_break,]
+					column![track_time, take_break, pomodoro]
 						.align_x(Alignment::Center)
 						.spacing(LARGE_SPACING_AMOUNT)
 						.into()
@@ -627,6 +777,88 @@ impl Page {
 			})
 			.into(),
 
+			Page::Pomodoro {
+				paused,
+				phase,
+				completed_work_sessions,
+				task,
+				clock,
+				..
+			} => {
+				let pomodoro_settings = app.preferences.pomodoro_settings();
+				let task_refs = task.and_then(|(project_id, task_id)| {
+					app.database
+						.ok()
+						.and_then(|db| db.get_project(&project_id))
+						.and_then(|project| {
+							project
+								.get_task(&task_id)
+								.map(|task| (project_id, project, task_id, task))
+						})
+				});
+
+				responsive(move |size| -> Element<project_tracker::Message> {
+					let clock = canvas(clock)
+						.width(Length::Fixed(225.0))
+						.height(Length::Fixed(225.0));
+
+					let phase_info = column![
+						text(format!(
+							"{} min. {}",
+							pomodoro_settings.phase_minutes(*phase),
+							phase.label().to_lowercase()
+						))
+						.size(45),
+						text(format!(
+							"{completed_work_sessions} pomodoros completed{}",
+							match task_refs {
+								Some((_, _, _, task)) =>
+									format!(" ({} on this task)", task.completed_pomodoros),
+								None => String::new(),
+							}
+						)),
+					]
+					.align_x(Alignment::Center)
+					.spacing(SPACING_AMOUNT);
+
+					let controls = row![
+						if *paused {
+							resume_timer_button()
+						} else {
+							pause_timer_button()
+						},
+						stop_timer_button(),
+						skip_pomodoro_phase_button(),
+					]
+					.push_maybe(task_refs.map(|_| complete_task_timer_button()))
+					.spacing(LARGE_SPACING_AMOUNT);
+
+					let info_and_controls = Column::new()
+						.push_maybe(task_refs.and_then(|(project_id, project, task_id, task)| {
+							task_info(Some(task), task_id, Some((project_id, project)), app)
+						}))
+						.push(phase_info)
+						.push(controls)
+						.align_x(Alignment::Center)
+						.spacing(LARGE_SPACING_AMOUNT);
+
+					let page_view: Element<project_tracker::Message> = if size.width > size.height {
+						row![clock, info_and_controls.width(Fill)]
+							.spacing(LARGE_SPACING_AMOUNT)
+							.align_y(Vertical::Center)
+							.into()
+					} else {
+						column![clock, info_and_controls]
+							.spacing(LARGE_SPACING_AMOUNT)
+							.align_x(Horizontal::Center)
+							.into()
+					};
+
+					container(page_view).center(Fill).into()
+				})
+				.into()
+			}
+
 			Page::StopTaskTime {
 				project_id,
 				task_id,
@@ -678,7 +910,8 @@ impl Page {
 							pause_timer_button()
 						},
 						stop_timer_button(),
-						complete_task_timer_button()
+						complete_task_timer_button(),
+						start_pomodoro_button(Some((*project_id, *task_id))),
 					]
 					.spacing(LARGE_SPACING_AMOUNT);
 
@@ -729,17 +962,18 @@ impl Page {
 		.into()
 	}
 
-	fn stop(&mut self, preferences: &mut Option<Preferences>) -> pages::Action {
+	fn stop(
+		&mut self,
+		database: Option<&Database>,
+		preferences: &mut Option<Preferences>,
+	) -> pages::Action {
 		let action = match self {
 			Page::StopTaskTime {
 				project_id,
 				task_id,
 				..
-			} => DatabaseMessage::StopTaskTimeSpend {
-				project_id: *project_id,
-				task_id: *task_id,
-			}
-			.into(),
+			} => Self::stop_task_time_spend_action(*project_id, *task_id, database),
+			Page::Pomodoro { .. } => self.pomodoro_task_time_spend_action(false, database),
 			_ => pages::Action::None,
 		};
 		*self = Page::Idle;
@@ -783,14 +1017,127 @@ impl Page {
 					break_duration_minutes: *break_duration_minutes,
 					break_over_notification_sent: *break_over_notification_sent,
 				}),
+				Page::Pomodoro {
+					elapsed_time,
+					paused,
+					phase,
+					completed_work_sessions,
+					task,
+					..
+				} => Some(StopwatchProgress::Pomodoro {
+					elapsed_time_seconds: elapsed_time.as_secs(),
+					paused: *paused,
+					phase: *phase,
+					completed_work_sessions: *completed_work_sessions,
+					task: *task,
+				}),
 			};
 			preferences.set_stopwatch_progress(progress);
 		}
 	}
+
+	/// 'phase_finished' is false when the current phase got skipped
+	fn advance_pomodoro_phase(
+		&mut self,
+		phase_finished: bool,
+		database: Option<&Database>,
+		preferences: &Option<Preferences>,
+	) -> pages::Action {
+		let stop_time_spend_action = self.pomodoro_task_time_spend_action(false, database);
+		let pomodoro_settings = preferences.pomodoro_settings();
+
+		let Page::Pomodoro {
+			elapsed_time,
+			last_update,
+			paused,
+			phase,
+			completed_work_sessions,
+			task,
+			clock,
+		} = self
+		else {
+			return pages::Action::None;
+		};
+
+		let mut actions = vec![stop_time_spend_action];
+		let finished_phase = *phase;
+
+		*phase = match finished_phase {
+			PomodoroPhase::Work if phase_finished => {
+				*completed_work_sessions += 1;
+				if let Some((project_id, task_id)) = task {
+					actions.push(
+						DatabaseMessage::AddTaskPomodoro {
+							project_id: *project_id,
+							task_id: *task_id,
+						}
+						.into(),
+					);
+				}
+				pomodoro_settings.next_phase(finished_phase, *completed_work_sessions)
+			}
+			PomodoroPhase::Work => PomodoroPhase::ShortBreak,
+			PomodoroPhase::ShortBreak | PomodoroPhase::LongBreak => PomodoroPhase::Work,
+		};
+
+		let phase_minutes = pomodoro_settings.phase_minutes(*phase);
+		*paused = !pomodoro_settings.auto_start(*phase);
+		*elapsed_time = Duration::from_secs(0);
+		*last_update = Instant::now();
+		*clock = StopwatchClock::new(0.0, phase_minutes as f32 * 60.0, None);
+
+		if phase_finished && preferences.play_timer_notification_sound() {
+			desktop_notification(
+				match finished_phase {
+					PomodoroPhase::Work => format!(
+						"{} min. pomodoro finished!",
+						pomodoro_settings.phase_minutes(finished_phase)
+					),
+					PomodoroPhase::ShortBreak | PomodoroPhase::LongBreak => {
+						format!("{} is over!", finished_phase.label())
+					}
+				},
+				format!(
+					"Next up: {phase_minutes} min. {}",
+					phase.label().to_lowercase()
+				),
+			);
+		}
+
+		actions.push(self.pomodoro_task_time_spend_action(true, database));
+		pages::Action::Actions(actions)
+	}
+
+	/// starts or stops the time spend of the pomodoro task while focusing on it
+	fn pomodoro_task_time_spend_action(
+		&self,
+		start: bool,
+		database: Option<&Database>,
+	) -> pages::Action {
+		match self {
+			Page::Pomodoro {
+				paused: false,
+				phase: PomodoroPhase::Work,
+				task: Some((project_id, task_id)),
+				..
+			} => {
+				if start {
+					DatabaseMessage::StartTaskTimeSpend {
+						project_id: *project_id,
+						task_id: *task_id,
+					}
+					.into()
+				} else {
+					Self::stop_task_time_spend_action(*project_id, *task_id, database)
+				}
+			}
+			_ => pages::Action::None,
+		}
+	}
 }
 
 #[cfg(target_os = "windows")]
-fn timer_notification(summary: String, body: String) {
+pub fn desktop_notification(summary: String, body: String) {
 	use std::path::Path;
 
 	use winrt_notification::{Duration, IconCrop, Sound, Toast};
@@ -810,12 +1157,12 @@ fn timer_notification(summary: String, body: String) {
 		.show();
 
 	if let Err(e) = notification_result {
-		error!("failed to show timer notification: {e}");
+		error!("failed to show notification: {e}");
 	}
 }
 
 #[cfg(not(target_os = "windows"))]
-fn timer_notification(summary: String, body: String) {
+pub fn desktop_notification(summary: String, body: String) {
 	use notify_rust::{Notification, Timeout};
 	use std::{io::Cursor, thread};
 
@@ -854,7 +1201,7 @@ fn timer_notification(summary: String, body: String) {
 	#[cfg(target_os = "linux")]
 	thread::spawn(|| match notification_result {
 		Ok(notification_handle) => notification_handle.on_close(|| {}),
-		Err(e) => error!("failed to show timer notification: {e}"),
+		Err(e) => error!("failed to show notification: {e}"),
 	});
 }
 
@@ -0,0 +1,348 @@
+use crate::{
+	components::{
+		carry_over_day_plan_button, duration_text, edit_needed_time_button, horizontal_seperator,
+		move_planned_task_down_button, move_planned_task_up_button, open_project_button,
+		plan_task_button, task_widget, unplan_task_button, vertical_scrollable,
+	},
+	core::IcedColorConversion,
+	pages, project_tracker,
+	styles::{
+		danger_text_style, grey_text_style, PADDING_AMOUNT, SMALL_SPACING_AMOUNT, SPACING_AMOUNT,
+		TITLE_TEXT_SIZE,
+	},
+	DateFormatting, OptionalPreference, Preferences, ProjectTrackerApp,
+};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use iced::{
+	alignment::Vertical,
+	widget::{column, row, text, text_input, Column, Space},
+	Element,
+	Length::Fill,
+};
+use project_tracker_core::{
+	duration_str, duration_to_minutes, parse_duration_from_str, Database, DatabaseMessage,
+	ProjectId, SerializableDate, TaskId,
+};
+use std::{sync::LazyLock, time::Duration, time::SystemTime};
+
+static EDIT_AVAILABLE_TIME_INPUT_ID: LazyLock<text_input::Id> =
+	LazyLock::new(text_input::Id::unique);
+
+#[derive(Debug, Clone)]
+pub struct Page {
+	date: NaiveDate,
+	planned_tasks: Vec<(ProjectId, TaskId)>,
+	/// unplanned tasks that are due today or earlier and tasks left over in past plans
+	suggested_tasks: Vec<(ProjectId, TaskId)>,
+	new_available_time: Option<String>,
+	cache_time: SystemTime,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+	RefreshCachedTaskList,
+	PlanTask {
+		project_id: ProjectId,
+		task_id: TaskId,
+	},
+	UnplanTask {
+		project_id: ProjectId,
+		task_id: TaskId,
+	},
+	MoveTaskUp(usize),
+	MoveTaskDown(usize),
+	CarryOverToTomorrow,
+	EditAvailableTime,
+	StopEditingAvailableTime,
+	ChangeAvailableTimeInput(String),
+	ChangeAvailableTime,
+}
+
+impl From<Message> for project_tracker::Message {
+	fn from(value: Message) -> Self {
+		pages::Message::TodayPage(value).into()
+	}
+}
+
+impl Page {
+	pub fn new(database: Option<&Database>) -> Self {
+		let date = Local::now().date_naive();
+		let today: SerializableDate = date.into();
+
+		let mut planned_tasks = Vec::new();
+		let mut suggested_tasks = Vec::new();
+
+		if let Some(database) = database {
+			planned_tasks = database.day_plan(today);
+
+			for (project_id, project) in database.projects().iter() {
+				for (task_id, task, task_type) in project.iter() {
+					if task_type.is_done() {
+						continue;
+					}
+					let suggested = match &task.plan {
+						Some(plan) => plan.date < today,
+						None => task.due_date.is_some_and(|due_date| due_date <= today),
+					};
+					if suggested {
+						suggested_tasks.push((project_id, task_id));
+					}
+				}
+			}
+		}
+
+		Self {
+			date,
+			planned_tasks,
+			suggested_tasks,
+			new_available_time: None,
+			cache_time: SystemTime::now(),
+		}
+	}
+
+	pub fn update(
+		&mut self,
+		message: Message,
+		database: Option<&Database>,
+		preferences: &mut Option<Preferences>,
+	) -> pages::Action {
+		let today: SerializableDate = self.date.into();
+
+		match message {
+			Message::RefreshCachedTaskList => {
+				self.refresh_cached_task_list(database);
+				pages::Action::None
+			}
+			Message::PlanTask {
+				project_id,
+				task_id,
+			} => DatabaseMessage::PlanTask {
+				project_id,
+				task_id,
+				date: today,
+			}
+			.into(),
+			Message::UnplanTask {
+				project_id,
+				task_id,
+			} => DatabaseMessage::UnplanTask {
+				project_id,
+				task_id,
+			}
+			.into(),
+			Message::MoveTaskUp(index) => self.swap_planned_tasks(index.saturating_sub(1), index),
+			Message::MoveTaskDown(index) => self.swap_planned_tasks(index, index + 1),
+			Message::CarryOverToTomorrow => match self.date.succ_opt() {
+				Some(tomorrow) => DatabaseMessage::CarryOverDayPlan {
+					from: today,
+					to: tomorrow.into(),
+				}
+				.into(),
+				None => pages::Action::None,
+			},
+			Message::EditAvailableTime => {
+				self.new_available_time = Some(duration_str(Duration::from_secs(
+					preferences.available_minutes_per_day() as u64 * 60,
+				)));
+				text_input::focus(EDIT_AVAILABLE_TIME_INPUT_ID.clone()).into()
+			}
+			Message::StopEditingAvailableTime => {
+				self.new_available_time = None;
+				pages::Action::None
+			}
+			Message::ChangeAvailableTimeInput(new_available_time) => {
+				self.new_available_time = Some(new_available_time);
+				pages::Action::None
+			}
+			Message::ChangeAvailableTime => {
+				if let Some(available_minutes) = self
+					.new_available_time
+					.as_ref()
+					.and_then(|new_available_time| parse_duration_from_str(new_available_time))
+					.map(duration_to_minutes)
+				{
+					if let Some(preferences) = preferences {
+						preferences.set_available_minutes_per_day(available_minutes);
+					}
+					self.new_available_time = None;
+				}
+				pages::Action::None
+			}
+		}
+	}
+
+	pub fn refresh_cached_task_list(&mut self, database: Option<&Database>) {
+		if let Some(database_ref) = database {
+			let cache_date_time: DateTime<Utc> = self.cache_time.into();
+			if cache_date_time < *database_ref.last_changed_time()
+				|| self.date != Local::now().date_naive()
+			{
+				let new_available_time = self.new_available_time.take();
+				*self = Self::new(database);
+				self.new_available_time = new_available_time;
+			}
+		}
+	}
+
+	fn swap_planned_tasks(&self, index_a: usize, index_b: usize) -> pages::Action {
+		if index_a == index_b || index_b >= self.planned_tasks.len() {
+			return pages::Action::None;
+		}
+		let mut reordered_tasks = self.planned_tasks.clone();
+		reordered_tasks.swap(index_a, index_b);
+		DatabaseMessage::ReorderDayPlan {
+			date: self.date.into(),
+			tasks: reordered_tasks,
+		}
+		.into()
+	}
+
+	pub fn view<'a>(&'a self, app: &'a ProjectTrackerApp) -> Element<'a, project_tracker::Message> {
+		let database = app.database.ok();
+
+		let mut planned_minutes = 0;
+		let mut unfinished_task_count = 0;
+		for (project_id, task_id) in self.planned_tasks.iter() {
+			if let Some((_project, task, task_type)) =
+				database.and_then(|db| db.get_project_task_type(project_id, task_id))
+			{
+				if !task_type.is_done() {
+					unfinished_task_count += 1;
+					planned_minutes += task.needed_time_minutes.unwrap_or(0);
+				}
+			}
+		}
+		let available_minutes = app.preferences.available_minutes_per_day();
+
+		let available_time_view: Element<'a, project_tracker::Message> = edit_needed_time_button(
+			Some(available_minutes),
+			&self.new_available_time,
+			Message::EditAvailableTime.into(),
+			|input| Message::ChangeAvailableTimeInput(input).into(),
+			Some(Message::ChangeAvailableTime.into()),
+			Message::StopEditingAvailableTime.into(),
+			Message::StopEditingAvailableTime.into(),
+			EDIT_AVAILABLE_TIME_INPUT_ID.clone(),
+		);
+
+		let overplanned = planned_minutes > available_minutes;
+		let planned_time_text = duration_text(Duration::from_secs(planned_minutes as u64 * 60))
+			.style(move |theme| {
+				if overplanned {
+					danger_text_style(theme)
+				} else {
+					grey_text_style(theme)
+				}
+			});
+
+		let planned_tasks_list = Column::with_children(self.planned_tasks.iter().enumerate().map(
+			|(i, (project_id, task_id))| {
+				row![
+					task_view(*project_id, *task_id, app),
+					row![
+						move_planned_task_up_button(i),
+						move_planned_task_down_button(i, self.planned_tasks.len()),
+						unplan_task_button(*project_id, *task_id),
+					]
+				]
+				.spacing(SPACING_AMOUNT)
+				.align_y(Vertical::Center)
+				.into()
+			},
+		))
+		.spacing(SPACING_AMOUNT);
+
+		let suggested_tasks_list =
+			Column::with_children(self.suggested_tasks.iter().map(|(project_id, task_id)| {
+				row![
+					task_view(*project_id, *task_id, app),
+					plan_task_button(*project_id, *task_id)
+				]
+				.spacing(SPACING_AMOUNT)
+				.align_y(Vertical::Center)
+				.into()
+			}))
+			.spacing(SPACING_AMOUNT);
+
+		vertical_scrollable(
+			column![
+				row![
+					text("Today").size(TITLE_TEXT_SIZE),
+					text(date_str(self.date, app.preferences.date_formatting()))
+						.style(grey_text_style),
+					Space::new(Fill, 0.0),
+					planned_time_text,
+					text("of").style(grey_text_style),
+					available_time_view,
+					carry_over_day_plan_button(unfinished_task_count),
+				]
+				.spacing(SMALL_SPACING_AMOUNT)
+				.align_y(Vertical::Center),
+				horizontal_seperator(),
+				if self.planned_tasks.is_empty() {
+					Element::new(
+						text("Nothing planned yet, pull tasks into today with the plan button.")
+							.style(grey_text_style),
+					)
+				} else {
+					planned_tasks_list.into()
+				},
+			]
+			.push_maybe(if self.suggested_tasks.is_empty() {
+				None
+			} else {
+				Some(
+					column![
+						text("Due or left over").style(grey_text_style),
+						horizontal_seperator(),
+						suggested_tasks_list,
+					]
+					.spacing(SPACING_AMOUNT),
+				)
+			})
+			.spacing(SPACING_AMOUNT)
+			.padding(PADDING_AMOUNT),
+		)
+		.into()
+	}
+}
+
+fn task_view(
+	project_id: ProjectId,
+	task_id: TaskId,
+	app: &ProjectTrackerApp,
+) -> Element<'_, project_tracker::Message> {
+	match app
+		.database
+		.ok()
+		.and_then(|db| db.get_project_task_type(&project_id, &task_id))
+	{
+		Some((project, task, task_type)) => column![
+			open_project_button(project_id, &project.name, project.color.to_iced_color()),
+			task_widget(
+				task,
+				task_id,
+				app.task_ui_id_map.get_dropzone_id_mut(task_id),
+				task_type,
+				project_id,
+				project,
+				app.preferences.code_editor(),
+				false,
+				true,
+				false,
+				false,
+				false,
+				true,
+				false,
+			)
+		]
+		.spacing(SMALL_SPACING_AMOUNT)
+		.width(Fill)
+		.into(),
+		None => text("<invalid project or task id>").into(),
+	}
+}
+
+fn date_str(date: NaiveDate, date_formatting: DateFormatting) -> String {
+	date_formatting.format(&date.into())
+}
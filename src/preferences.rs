@@ -3,6 +3,7 @@ use crate::icons::Bootstrap;
 use crate::integrations::CodeEditor;
 use crate::pages::overview_page::CalendarView;
 use crate::pages::sidebar_page;
+use crate::pages::stopwatch_page::PomodoroPhase;
 use crate::project_tracker::AppFlags;
 use crate::synchronization::Synchronization;
 use crate::{
@@ -12,7 +13,7 @@ use crate::{
 	},
 	modals::error_msg_modal,
 	project_tracker::Message,
-	styles::SPACING_AMOUNT,
+	styles::{on_number_input, text_input_style_default, SPACING_AMOUNT},
 	theme_mode::ThemeMode,
 };
 use crate::{ProjectId, SerializableDate, TaskId};
@@ -20,9 +21,9 @@ use chrono::Weekday;
 use iced::widget::text;
 use iced::{
 	alignment::Horizontal,
-	widget::{column, container, row, toggler, Row},
+	widget::{column, container, row, text_input, toggler, Row},
 	Alignment, Element,
-	Length::Fill,
+	Length::{Fill, Fixed},
 	Task,
 };
 use serde::{Deserialize, Serialize};
@@ -43,6 +44,15 @@ fn default_sort_unspecified_tasks_at_bottom() -> bool {
 fn default_play_timer_notification_sound() -> bool {
 	true
 }
+/// the name of the user logged into the os
+fn default_comment_author() -> String {
+	std::env::var("USER")
+		.or_else(|_| std::env::var("USERNAME"))
+		.unwrap_or_default()
+}
+fn default_available_minutes_per_day() -> usize {
+	8 * 60
+}
 fn default_sidebar_ratio() -> f32 {
 	sidebar_page::Page::DEFAULT_SPLIT_RATIO
 }
@@ -82,9 +92,18 @@ pub struct Preferences {
 	#[serde(default)]
 	stopwatch_progress: Option<StopwatchProgress>,
 
+	#[serde(default = "default_available_minutes_per_day")]
+	available_minutes_per_day: usize,
+
+	#[serde(default)]
+	pomodoro_settings: PomodoroSettings,
+
 	#[serde(default)]
 	code_editor: Option<CodeEditor>,
 
+	#[serde(default = "default_comment_author")]
+	comment_author: String,
+
 	#[serde(default)]
 	synchronization: Option<Synchronization>,
 
@@ -109,7 +128,10 @@ impl Default for Preferences {
 			selected_content_page: SerializedContentPage::default(),
 			serialized_overview_page: SerializedOverviewPage::default(),
 			stopwatch_progress: None,
+			available_minutes_per_day: default_available_minutes_per_day(),
+			pomodoro_settings: PomodoroSettings::default(),
 			code_editor: None,
+			comment_author: default_comment_author(),
 			synchronization: None,
 			last_changed_time: Instant::now(),
 			last_saved_time: Instant::now(),
@@ -135,6 +157,74 @@ pub enum StopwatchProgress {
 		break_duration_minutes: usize,
 		break_over_notification_sent: bool,
 	},
+	Pomodoro {
+		elapsed_time_seconds: u64,
+		paused: bool,
+		phase: PomodoroPhase,
+		completed_work_sessions: usize,
+		task: Option<(ProjectId, TaskId)>,
+	},
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PomodoroSettings {
+	pub work_minutes: usize,
+	pub short_break_minutes: usize,
+	pub long_break_minutes: usize,
+	pub work_sessions_before_long_break: usize,
+	pub auto_start_breaks: bool,
+	pub auto_start_work_sessions: bool,
+}
+
+impl Default for PomodoroSettings {
+	fn default() -> Self {
+		Self {
+			work_minutes: 25,
+			short_break_minutes: 5,
+			long_break_minutes: 15,
+			work_sessions_before_long_break: 4,
+			auto_start_breaks: true,
+			auto_start_work_sessions: false,
+		}
+	}
+}
+
+impl PomodoroSettings {
+	pub fn phase_minutes(&self, phase: PomodoroPhase) -> usize {
+		let minutes = match phase {
+			PomodoroPhase::Work => self.work_minutes,
+			PomodoroPhase::ShortBreak => self.short_break_minutes,
+			PomodoroPhase::LongBreak => self.long_break_minutes,
+		};
+		// an empty settings input is stored as 0
+		minutes.max(1)
+	}
+
+	/// the phase following 'phase' after 'completed_work_sessions' work sessions are done
+	pub fn next_phase(
+		&self,
+		phase: PomodoroPhase,
+		completed_work_sessions: usize,
+	) -> PomodoroPhase {
+		match phase {
+			PomodoroPhase::Work
+				if completed_work_sessions
+					.is_multiple_of(self.work_sessions_before_long_break.max(1)) =>
+			{
+				PomodoroPhase::LongBreak
+			}
+			PomodoroPhase::Work => PomodoroPhase::ShortBreak,
+			PomodoroPhase::ShortBreak | PomodoroPhase::LongBreak => PomodoroPhase::Work,
+		}
+	}
+
+	pub fn auto_start(&self, phase: PomodoroPhase) -> bool {
+		match phase {
+			PomodoroPhase::Work => self.auto_start_work_sessions,
+			PomodoroPhase::ShortBreak | PomodoroPhase::LongBreak => self.auto_start_breaks,
+		}
+	}
 }
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
@@ -142,6 +232,7 @@ pub enum SerializedContentPage {
 	#[default]
 	Overview,
 	Stopwatch,
+	Today,
 	Project(ProjectId),
 }
 
@@ -185,6 +276,8 @@ pub enum PreferenceMessage {
 	SetCreateNewTaskAtTop(bool),
 	SetSortUnspecifiedTasksAtBottom(bool),
 	SetPlayTimerNotificationSound(bool),
+	SetPomodoroSettings(PomodoroSettings),
+	SetCommentAuthor(String),
 
 	SetSynchronization(Option<Synchronization>),
 }
@@ -269,6 +362,9 @@ impl Preferences {
 	) {
 		self.modify(|pref| pref.serialized_overview_page = serialized_overview_page);
 	}
+	pub fn set_available_minutes_per_day(&mut self, available_minutes_per_day: usize) {
+		self.modify(|pref| pref.available_minutes_per_day = available_minutes_per_day);
+	}
 	pub fn stopwatch_progress(&self) -> &Option<StopwatchProgress> {
 		&self.stopwatch_progress
 	}
@@ -408,6 +504,16 @@ impl Preferences {
 				PreferenceAction::None
 			}
 
+			PreferenceMessage::SetCommentAuthor(comment_author) => {
+				self.modify(|pref| pref.comment_author = comment_author);
+				PreferenceAction::None
+			}
+
+			PreferenceMessage::SetPomodoroSettings(pomodoro_settings) => {
+				self.modify(|pref| pref.pomodoro_settings = pomodoro_settings);
+				PreferenceAction::None
+			}
+
 			PreferenceMessage::SetSynchronization(synchronization) => {
 				self.modify(|pref| pref.synchronization = synchronization);
 				PreferenceAction::None
@@ -487,6 +593,35 @@ impl Preferences {
 		}
 	}
 
+	fn pomodoro_number_input<'a>(
+		number: usize,
+		set_number: impl Fn(&mut PomodoroSettings, usize) + 'a,
+		pomodoro_settings: PomodoroSettings,
+	) -> Element<'a, Message> {
+		text_input(
+			"",
+			&if number == 0 {
+				String::new()
+			} else {
+				number.to_string()
+			},
+		)
+		.on_input(move |input| {
+			let mut new_pomodoro_settings = pomodoro_settings;
+			on_number_input(
+				input,
+				|new_number| {
+					set_number(&mut new_pomodoro_settings, new_number.unwrap_or(0));
+					PreferenceMessage::SetPomodoroSettings(new_pomodoro_settings).into()
+				},
+				PreferenceMessage::SetPomodoroSettings(pomodoro_settings).into(),
+			)
+		})
+		.width(Fixed(75.0))
+		.style(text_input_style_default)
+		.into()
+	}
+
 	fn setting_item<'a>(
 		label: impl Into<Element<'a, Message>>,
 		content: impl Into<Element<'a, Message>>,
@@ -560,6 +695,72 @@ impl Preferences {
 					})
 					.size(27.5)
 			),
+			Self::setting_item(
+				"Comment author name:",
+				text_input("Your name", &self.comment_author)
+					.on_input(|comment_author| {
+						PreferenceMessage::SetCommentAuthor(comment_author).into()
+					})
+					.width(Fixed(250.0))
+					.style(text_input_style_default)
+			),
+			horizontal_seperator_padded(),
+			Self::setting_item(
+				"Pomodoro work minutes:",
+				Self::pomodoro_number_input(
+					self.pomodoro_settings.work_minutes,
+					|settings, minutes| settings.work_minutes = minutes,
+					self.pomodoro_settings
+				)
+			),
+			Self::setting_item(
+				"Pomodoro short break minutes:",
+				Self::pomodoro_number_input(
+					self.pomodoro_settings.short_break_minutes,
+					|settings, minutes| settings.short_break_minutes = minutes,
+					self.pomodoro_settings
+				)
+			),
+			Self::setting_item(
+				"Pomodoro long break minutes:",
+				Self::pomodoro_number_input(
+					self.pomodoro_settings.long_break_minutes,
+					|settings, minutes| settings.long_break_minutes = minutes,
+					self.pomodoro_settings
+				)
+			),
+			Self::setting_item(
+				"Work sessions before a long break:",
+				Self::pomodoro_number_input(
+					self.pomodoro_settings.work_sessions_before_long_break,
+					|settings, sessions| settings.work_sessions_before_long_break = sessions,
+					self.pomodoro_settings
+				)
+			),
+			Self::setting_item(
+				"Start breaks automatically:",
+				toggler(self.pomodoro_settings.auto_start_breaks)
+					.on_toggle(|auto_start_breaks| {
+						PreferenceMessage::SetPomodoroSettings(PomodoroSettings {
+							auto_start_breaks,
+							..self.pomodoro_settings
+						})
+						.into()
+					})
+					.size(27.5)
+			),
+			Self::setting_item(
+				"Start work sessions automatically:",
+				toggler(self.pomodoro_settings.auto_start_work_sessions)
+					.on_toggle(|auto_start_work_sessions| {
+						PreferenceMessage::SetPomodoroSettings(PomodoroSettings {
+							auto_start_work_sessions,
+							..self.pomodoro_settings
+						})
+						.into()
+					})
+					.size(27.5)
+			),
 			horizontal_seperator_padded(),
 			Self::setting_item(
 				container("Preferences file location:").padding(HORIZONTAL_SCROLLABLE_PADDING),
@@ -647,6 +848,9 @@ pub trait OptionalPreference {
 	fn synchronization(&self) -> Option<&Synchronization>;
 	fn play_timer_notification_sound(&self) -> bool;
 	fn code_editor(&self) -> Option<&CodeEditor>;
+	fn comment_author(&self) -> String;
+	fn available_minutes_per_day(&self) -> usize;
+	fn pomodoro_settings(&self) -> PomodoroSettings;
 }
 
 impl OptionalPreference for Option<Preferences> {
@@ -696,7 +900,25 @@ impl OptionalPreference for Option<Preferences> {
 			None => default_play_timer_notification_sound(),
 		}
 	}
+	fn available_minutes_per_day(&self) -> usize {
+		match self {
+			Some(preferences) => preferences.available_minutes_per_day,
+			None => default_available_minutes_per_day(),
+		}
+	}
 	fn code_editor(&self) -> Option<&CodeEditor> {
 		self.as_ref().and_then(|prefs| prefs.code_editor.as_ref())
 	}
+	fn comment_author(&self) -> String {
+		match self {
+			Some(preferences) => preferences.comment_author.clone(),
+			None => default_comment_author(),
+		}
+	}
+	fn pomodoro_settings(&self) -> PomodoroSettings {
+		match self {
+			Some(preferences) => preferences.pomodoro_settings,
+			None => PomodoroSettings::default(),
+		}
+	}
 }
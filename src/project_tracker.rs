@@ -1,5 +1,6 @@
 use crate::components::{toggle_sidebar_button, Split};
 use crate::core::{export_database_as_markdown_file_dialog, TaskDescriptionMarkdownStorage};
+use crate::integrations::{export_todo_txt, export_todo_txt_file_dialog, projects_to_todo_txt};
 use crate::synchronization::{
 	DatabaseUpdateEvent, DelayedSynchronization, OnUpdateSynchronization, SynchronizationError,
 	SynchronizationMessage, SynchronizationOutput,
@@ -10,14 +11,20 @@ use crate::{
 		settings_button,
 	},
 	core::{
-		export_database_as_json_file_dialog, export_database_file_dialog, formatted_date_time,
-		import_database_file_dialog, import_json_database_file_dialog, ProjectUiIdMap, TaskUiIdMap,
+		export_database_as_icalendar_file_dialog, export_database_as_json_file_dialog,
+		export_database_file_dialog, formatted_date_time, import_database_file_dialog,
+		import_json_database_file_dialog, merge_source_code_todos, rescan_source_code_files,
+		source_code_todo_watcher_subscription, store_attachment, ProjectUiIdMap, TaskUiIdMap,
 	},
 	modals::{
-		confirm_modal, create_task_modal, error_msg_modal, manage_task_tags_modal, settings_modal,
-		task_modal, wait_closing_modal,
+		confirm_modal, create_task_modal, error_msg_modal, import_summary_modal,
+		manage_task_tags_modal, settings_modal, source_code_todo_settings_modal, task_modal,
+		wait_closing_modal,
+	},
+	pages::{
+		self, desktop_notification, overview_page, project_page, sidebar_page, stopwatch_page,
+		today_page,
 	},
-	pages::{self, overview_page, project_page, sidebar_page, stopwatch_page},
 	styles::{
 		default_background_container_style, modal_background_container_style,
 		sidebar_background_container_style, HEADING_TEXT_SIZE, LARGE_SPACING_AMOUNT,
@@ -29,7 +36,7 @@ use crate::{
 use crate::{
 	LoadPreferencesError, OptionalPreference, PreferenceAction, PreferenceMessage, Preferences,
 };
-use chrono::Utc;
+use chrono::{DateTime, Local, Utc};
 use iced::widget::pane_grid::ResizeEvent;
 use iced::{
 	alignment::{Horizontal, Vertical},
@@ -42,15 +49,20 @@ use iced::{
 	Padding, Point, Rectangle, Subscription, Task, Theme,
 };
 use project_tracker_core::{
-	Database, DatabaseMessage, LoadDatabaseError, ProjectId, SaveDatabaseError, TaskId,
+	attachment_hash, attachments_directory, duration_str, is_valid_blob_file_name,
+	missing_attachment_blobs, remove_attachment_blobs, store_attachment_blob, Attachment, Database,
+	DatabaseMessage, ICalendarTaskComponent, LoadDatabaseError, OrderedHashMap, Project, ProjectId,
+	SaveDatabaseError, TaskId, TaskReminder,
 };
 use project_tracker_server::Request;
 use std::{
-	path::PathBuf,
+	collections::HashSet,
+	io,
+	path::{Path, PathBuf},
 	sync::Arc,
 	time::{Duration, Instant, SystemTime},
 };
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 #[derive(Debug)]
 pub enum DatabaseState {
@@ -117,15 +129,19 @@ pub struct ProjectTrackerApp {
 	pub preferences: Option<Preferences>,
 	pub confirm_modal: Option<confirm_modal::Modal>,
 	pub error_msg_modal: error_msg_modal::Modal,
+	pub import_summary_modal: import_summary_modal::Modal,
 	pub wait_closing_modal: wait_closing_modal::Modal,
 	pub settings_modal: settings_modal::Modal,
 	pub manage_tags_modal: Option<manage_task_tags_modal::Modal>,
+	pub source_code_todo_settings_modal: Option<source_code_todo_settings_modal::Modal>,
 	pub create_task_modal: Option<create_task_modal::Modal>,
 	pub task_modal: Option<task_modal::Modal>,
 	pub pressed_task: Option<(ProjectId, TaskId)>,
 	pub dragged_task: Option<TaskId>,
 	pub start_dragging_point: Option<Point>,
 	pub just_minimal_dragging: bool,
+	pub keyboard_modifiers: keyboard::Modifiers,
+	pub last_task_reminder_check: DateTime<Utc>,
 	pub is_system_theme_dark: bool,
 }
 
@@ -138,27 +154,60 @@ pub enum Message {
 	TryClosing,
 	EscapePressed,
 	EnterPressed,
+	KeyboardModifiersChanged(keyboard::Modifiers),
 	CopyToClipboard(String),
 	OpenUrl(String),
 	OpenInCodeEditor(String), // file_location
 	SaveChangedFiles,
 	SyncIfChanged,
+	CheckTaskReminders,
 	OpenFolderLocation(PathBuf),
+	OpenAttachment(PathBuf),
+	HoveringFiles(bool),
+	FileDropped(PathBuf),
+	AttachmentStored {
+		project_id: ProjectId,
+		task_id: TaskId,
+		attachment: Attachment,
+		content: Arc<Vec<u8>>,
+	},
+	StoreAttachmentFailed(Arc<io::Error>),
+	RemovedOrphanedAttachments(Result<usize, Arc<io::Error>>),
+	DownloadAttachments(Vec<String>),
+	AttachmentDownloaded {
+		blob_file_name: String,
+		content: Arc<Vec<u8>>,
+	},
+	StoredDownloadedAttachment(Result<String, Arc<io::Error>>),
+	SourceCodeFilesChanged {
+		project_id: ProjectId,
+		changed_filepaths: HashSet<PathBuf>,
+	},
+	SourceCodeFilesRescanned {
+		project_id: ProjectId,
+		rescanned_filepaths: HashSet<PathBuf>,
+		source_code_todos: OrderedHashMap<TaskId, project_tracker_core::Task>,
+	},
 	SystemTheme {
 		is_dark: bool,
 	},
 	ConfirmModalMessage(confirm_modal::Message),
 	ConfirmModalConfirmed(Box<Message>),
 	ErrorMsgModalMessage(error_msg_modal::Message),
+	ImportSummaryModalMessage(import_summary_modal::Message),
 	WaitClosingModalMessage(wait_closing_modal::Message),
 	SaveDatabase,
 	DatabaseSaved(SystemTime), // begin_time since saving
 	ExportDatabase(PathBuf),
 	ExportDatabaseAsJson(PathBuf),
 	ExportDatabaseAsMarkdown(PathBuf),
+	ExportDatabaseAsICalendar(PathBuf),
+	ExportDatabaseAsTodoTxt(PathBuf),
 	ExportDatabaseDialog,
 	ExportDatabaseAsJsonDialog,
 	ExportDatabaseAsMarkdownDialog,
+	ExportDatabaseAsICalendarDialog,
+	ExportDatabaseAsTodoTxtDialog,
 	ExportDatabaseFailed(Arc<SaveDatabaseError>),
 	ExportDatabaseDialogCanceled,
 	DatabaseExported,
@@ -168,6 +217,7 @@ pub enum Message {
 	ImportDatabaseDialog,
 	ImportJsonDatabaseDialog,
 	ImportDatabaseDialogCanceled,
+	ImportProjects(Vec<Project>),
 	RequestAdminInfos,
 	SyncDatabase,
 	SyncedDatabase(Result<SynchronizationOutput, Arc<SynchronizationError>>),
@@ -177,6 +227,7 @@ pub enum Message {
 	SavePreferences,
 	LoadedPreferences(Result<Preferences, Arc<LoadPreferencesError>>),
 	DatabaseMessage(DatabaseMessage),
+	DatabaseMessages(Vec<DatabaseMessage>), // gets synchronized as one update
 	PreferenceMessage(PreferenceMessage),
 	SwitchToUpperProject, // switches to upper project when using shortcuts
 	SwitchToLowerProject, // switches to lower project when using shortcuts
@@ -214,6 +265,9 @@ pub enum Message {
 	ManageTaskTagsModalMessage(manage_task_tags_modal::Message),
 	OpenManageTaskTagsModal(ProjectId),
 	CloseManageTaskTagsModal,
+	SourceCodeTodoSettingsModalMessage(source_code_todo_settings_modal::Message),
+	OpenSourceCodeTodoSettingsModal(ProjectId),
+	CloseSourceCodeTodoSettingsModal,
 }
 
 impl ProjectTrackerApp {
@@ -225,6 +279,45 @@ impl ProjectTrackerApp {
 		self.update(error_msg_modal::Message::open_error(error))
 	}
 
+	/// the 'attachments' directory next to the database file
+	pub fn attachments_directory(&self) -> Option<PathBuf> {
+		self.flags
+			.get_database_filepath()
+			.map(|database_filepath| attachments_directory(&database_filepath))
+	}
+
+	fn remove_orphaned_attachments(&self, orphaned_blob_file_names: Vec<String>) -> Task<Message> {
+		match self.attachments_directory() {
+			Some(attachments_directory) if !orphaned_blob_file_names.is_empty() => Task::perform(
+				async move {
+					remove_attachment_blobs(&attachments_directory, orphaned_blob_file_names).await
+				},
+				|result| Message::RemovedOrphanedAttachments(result.map_err(Arc::new)),
+			),
+			_ => Task::none(),
+		}
+	}
+
+	/// requests the blobs of attachments that were added by other clients
+	fn download_missing_attachments(&self) -> Task<Message> {
+		let server_synchronization = matches!(
+			self.synchronization,
+			Some(Synchronization::ServerSynchronization(_))
+		);
+		match (self.database.ok(), self.attachments_directory()) {
+			(Some(database), Some(attachments_directory)) if server_synchronization => {
+				let blob_file_names = database.attachment_blob_file_names();
+				Task::perform(
+					async move {
+						missing_attachment_blobs(&attachments_directory, blob_file_names).await
+					},
+					Message::DownloadAttachments,
+				)
+			}
+			_ => Task::none(),
+		}
+	}
+
 	fn has_unsynched_changes(&self) -> bool {
 		match self.last_sync_finish_time {
 			Some(last_sync_time) => match &self.database {
@@ -277,15 +370,19 @@ impl ProjectTrackerApp {
 				preferences: None,
 				confirm_modal: None,
 				error_msg_modal: error_msg_modal::Modal::Closed,
+				import_summary_modal: import_summary_modal::Modal::Closed,
 				wait_closing_modal: wait_closing_modal::Modal::Closed,
 				settings_modal: settings_modal::Modal::Closed,
 				manage_tags_modal: None,
+				source_code_todo_settings_modal: None,
 				create_task_modal: None,
 				task_modal: None,
 				pressed_task: None,
 				dragged_task: None,
 				start_dragging_point: None,
 				just_minimal_dragging: true,
+				keyboard_modifiers: keyboard::Modifiers::default(),
+				last_task_reminder_check: Utc::now(),
 				is_system_theme_dark: is_system_theme_dark(),
 			},
 			Task::batch([
@@ -393,6 +490,16 @@ impl ProjectTrackerApp {
 				Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
 					Some(Message::LeftClickReleased)
 				}
+				Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+					Some(Message::KeyboardModifiersChanged(modifiers))
+				}
+				Event::Window(window::Event::FileHovered(_)) => Some(Message::HoveringFiles(true)),
+				Event::Window(window::Event::FilesHoveredLeft) => {
+					Some(Message::HoveringFiles(false))
+				}
+				Event::Window(window::Event::FileDropped(filepath)) => {
+					Some(Message::FileDropped(filepath))
+				}
 				_ => None,
 			}),
 			self.sidebar_page
@@ -408,10 +515,35 @@ impl ProjectTrackerApp {
 				.unwrap_or(Subscription::none()),
 			time::every(Duration::from_secs(1)).map(|_| Message::SaveChangedFiles),
 			time::every(Duration::from_secs(1)).map(|_| Message::SyncIfChanged),
+			time::every(Duration::from_secs(10)).map(|_| Message::CheckTaskReminders),
 			system_theme_subscription(),
+			self.source_code_todo_watcher_subscriptions(),
 		])
 	}
 
+	fn source_code_todo_watcher_subscriptions(&self) -> Subscription<Message> {
+		match self.database.ok() {
+			Some(database) => Subscription::batch(
+				database
+					.projects()
+					.iter()
+					.filter_map(|(project_id, project)| {
+						project
+							.source_code_directory
+							.clone()
+							.map(|source_code_directory| {
+								source_code_todo_watcher_subscription(
+									project_id,
+									source_code_directory,
+								)
+							})
+					})
+					.collect::<Vec<_>>(),
+			),
+			None => Subscription::none(),
+		}
+	}
+
 	pub fn update(&mut self, message: Message) -> Task<Message> {
 		let mut task = match message {
 			Message::SidebarResized { ratio } => {
@@ -449,6 +581,12 @@ impl ProjectTrackerApp {
 				if matches!(self.error_msg_modal, error_msg_modal::Modal::Open { .. }) {
 					return self.update(error_msg_modal::Message::Close.into());
 				}
+				if matches!(
+					self.import_summary_modal,
+					import_summary_modal::Modal::Open { .. }
+				) {
+					return self.update(import_summary_modal::Message::Close.into());
+				}
 				if self.confirm_modal.is_some() {
 					return self.update(confirm_modal::Message::Close.into());
 				}
@@ -458,18 +596,29 @@ impl ProjectTrackerApp {
 				if self.manage_tags_modal.is_some() {
 					return self.update(Message::CloseManageTaskTagsModal);
 				}
+				if self.source_code_todo_settings_modal.is_some() {
+					return self.update(Message::CloseSourceCodeTodoSettingsModal);
+				}
 				if self.create_task_modal.is_some() {
 					return self.update(Message::CloseCreateTaskModal);
 				}
 				if self.task_modal.is_some() {
 					return self.update(Message::CloseTaskModal);
 				}
-				if self.content_page.is_project_page_opened() {
-					self.update(project_page::Message::HideColorPicker.into())
+				if let Some(project_page) = &self.content_page.project_page {
+					if project_page.has_selected_tasks() {
+						self.update(project_page::Message::ClearTaskSelection.into())
+					} else {
+						self.update(project_page::Message::HideColorPicker.into())
+					}
 				} else {
 					self.update(stopwatch_page::Message::Stop.into())
 				}
 			}
+			Message::KeyboardModifiersChanged(modifiers) => {
+				self.keyboard_modifiers = modifiers;
+				Task::none()
+			}
 			Message::EnterPressed => {
 				if matches!(self.error_msg_modal, error_msg_modal::Modal::Open { .. }) {
 					self.error_msg_modal = error_msg_modal::Modal::Closed;
@@ -490,7 +639,7 @@ impl ProjectTrackerApp {
 			}
 			Message::OpenInCodeEditor(file_location) => {
 				if let Some(code_editor) = self.preferences.code_editor() {
-					if let Err(e) = code_editor.generate_command(&file_location).spawn() {
+					if let Err(e) = code_editor.open(&file_location) {
 						error!("failed to open source code todo in code editor\n{e}\ncode editor: {code_editor:?}, file_location: {file_location}");
 					}
 				}
@@ -522,10 +671,217 @@ impl ProjectTrackerApp {
 					Task::none()
 				}
 			}
+			Message::CheckTaskReminders => {
+				let now = Utc::now();
+				if let Some(database) = self.database.ok() {
+					for (project_id, task_id, reminder) in
+						database.task_reminders_between(self.last_task_reminder_check, now, &Local)
+					{
+						if let Some(project) = database.get_project(&project_id) {
+							if let Some(task) = project.get_task(&task_id) {
+								let summary = match reminder {
+									TaskReminder::At(_) => "Reminder".to_string(),
+									TaskReminder::BeforeDue { minutes } => format!(
+										"Due in {}",
+										duration_str(Duration::from_secs(minutes as u64 * 60))
+									),
+								};
+								desktop_notification(
+									summary,
+									format!("{} ({})", task.name, project.name),
+								);
+							}
+						}
+					}
+				}
+				self.last_task_reminder_check = now;
+				Task::none()
+			}
 			Message::OpenFolderLocation(filepath) => {
 				let _ = open::that(filepath);
 				Task::none()
 			}
+			Message::OpenAttachment(filepath) => {
+				let _ = open::that_detached(filepath);
+				Task::none()
+			}
+			Message::HoveringFiles(hovering) => {
+				self.update(task_modal::Message::HoveringFiles(hovering).into())
+			}
+			Message::FileDropped(filepath) => {
+				let attach_task = match (&self.task_modal, self.attachments_directory()) {
+					(Some(task_modal), Some(attachments_directory)) => {
+						let project_id = task_modal.project_id;
+						let task_id = task_modal.task_id;
+						Task::perform(
+							store_attachment(attachments_directory, filepath),
+							move |result| match result {
+								Ok((attachment, content)) => Message::AttachmentStored {
+									project_id,
+									task_id,
+									attachment,
+									content: Arc::new(content),
+								},
+								Err(e) => Message::StoreAttachmentFailed(Arc::new(e)),
+							},
+						)
+					}
+					_ => Task::none(),
+				};
+				Task::batch([
+					self.update(task_modal::Message::HoveringFiles(false).into()),
+					attach_task,
+				])
+			}
+			Message::AttachmentStored {
+				project_id,
+				task_id,
+				attachment,
+				content,
+			} => {
+				// upload the content first, so that the server has it before the attachment gets synced
+				if let Some(Synchronization::ServerSynchronization(server_synchronization)) =
+					&mut self.synchronization
+				{
+					server_synchronization.send_request(Request::UploadAttachment {
+						blob_file_name: attachment.blob_file_name(),
+						content: Arc::unwrap_or_clone(content),
+					});
+				}
+				self.update(
+					DatabaseMessage::AddTaskAttachment {
+						project_id,
+						task_id,
+						attachment,
+					}
+					.into(),
+				)
+			}
+			Message::StoreAttachmentFailed(error) => self.show_error(error),
+			Message::RemovedOrphanedAttachments(result) => {
+				match result {
+					Ok(removed_attachments) => {
+						info!("removed {removed_attachments} orphaned attachments")
+					}
+					Err(e) => error!("failed to remove orphaned attachments: {e}"),
+				}
+				Task::none()
+			}
+			Message::DownloadAttachments(blob_file_names) => {
+				if let Some(Synchronization::ServerSynchronization(server_synchronization)) =
+					&mut self.synchronization
+				{
+					for blob_file_name in blob_file_names {
+						info!("downloading attachment '{blob_file_name}'");
+						server_synchronization
+							.send_request(Request::DownloadAttachment { blob_file_name });
+					}
+				}
+				Task::none()
+			}
+			Message::AttachmentDownloaded {
+				blob_file_name,
+				content,
+			} => {
+				let content_matches_name = is_valid_blob_file_name(&blob_file_name)
+					&& blob_file_name.starts_with(&attachment_hash(&content));
+				match self.attachments_directory() {
+					Some(attachments_directory) if content_matches_name => Task::perform(
+						async move {
+							store_attachment_blob(&attachments_directory, &blob_file_name, &content)
+								.await
+								.map(|()| blob_file_name)
+						},
+						|result| Message::StoredDownloadedAttachment(result.map_err(Arc::new)),
+					),
+					_ => {
+						warn!("discarding downloaded attachment '{blob_file_name}'");
+						Task::none()
+					}
+				}
+			}
+			Message::StoredDownloadedAttachment(result) => {
+				match result {
+					Ok(blob_file_name) => info!("stored downloaded attachment '{blob_file_name}'"),
+					Err(e) => error!("failed to store downloaded attachment: {e}"),
+				}
+				Task::none()
+			}
+			Message::SourceCodeFilesChanged {
+				project_id,
+				changed_filepaths,
+			} => {
+				let Some((source_code_directory, settings)) = self
+					.database
+					.ok()
+					.and_then(|db| db.get_project(&project_id))
+					.and_then(|project| {
+						project
+							.source_code_directory
+							.clone()
+							.map(|source_code_directory| {
+								(
+									source_code_directory,
+									project.source_code_todo_settings.clone(),
+								)
+							})
+					})
+				else {
+					return Task::none();
+				};
+
+				Task::perform(
+					async move {
+						let source_code_todos = rescan_source_code_files(
+							&source_code_directory,
+							&settings,
+							&changed_filepaths,
+						);
+						(changed_filepaths, source_code_todos)
+					},
+					move |(rescanned_filepaths, source_code_todos)| {
+						Message::SourceCodeFilesRescanned {
+							project_id,
+							rescanned_filepaths,
+							source_code_todos,
+						}
+					},
+				)
+			}
+			Message::SourceCodeFilesRescanned {
+				project_id,
+				rescanned_filepaths,
+				source_code_todos,
+			} => {
+				let Some(project) = self
+					.database
+					.ok()
+					.and_then(|db| db.get_project(&project_id))
+				else {
+					return Task::none();
+				};
+				let Some(source_code_directory) = project.source_code_directory.clone() else {
+					return Task::none();
+				};
+
+				let merged_source_code_todos = merge_source_code_todos(
+					&project.source_code_todos,
+					source_code_todos,
+					|filepath| rescanned_filepaths.contains(Path::new(filepath)),
+				);
+				if merged_source_code_todos == project.source_code_todos {
+					return Task::none();
+				}
+
+				self.update(
+					DatabaseMessage::ImportSourceCodeTodos {
+						project_id,
+						source_code_directory,
+						source_code_todo_tasks: merged_source_code_todos,
+					}
+					.into(),
+				)
+			}
 			Message::SystemTheme { is_dark } => {
 				self.is_system_theme_dark = is_dark;
 				Task::none()
@@ -557,6 +913,10 @@ impl ProjectTrackerApp {
 				self.error_msg_modal.update(message);
 				Task::none()
 			}
+			Message::ImportSummaryModalMessage(message) => {
+				self.import_summary_modal.update(message);
+				Task::none()
+			}
 			Message::WaitClosingModalMessage(message) => self
 				.wait_closing_modal
 				.update(message)
@@ -607,6 +967,19 @@ impl ProjectTrackerApp {
 					None => Message::ExportDatabaseDialogCanceled,
 				},
 			),
+			Message::ExportDatabaseAsICalendarDialog => Task::perform(
+				export_database_as_icalendar_file_dialog(),
+				|filepath| match filepath {
+					Some(filepath) => Message::ExportDatabaseAsICalendar(filepath),
+					None => Message::ExportDatabaseDialogCanceled,
+				},
+			),
+			Message::ExportDatabaseAsTodoTxtDialog => {
+				Task::perform(export_todo_txt_file_dialog(), |filepath| match filepath {
+					Some(filepath) => Message::ExportDatabaseAsTodoTxt(filepath),
+					None => Message::ExportDatabaseDialogCanceled,
+				})
+			}
 			Message::ExportDatabaseDialogCanceled => {
 				self.exporting_database = false;
 				Task::none()
@@ -667,6 +1040,38 @@ impl ProjectTrackerApp {
 					Task::none()
 				}
 			}
+			Message::ExportDatabaseAsICalendar(filepath) => {
+				if let DatabaseState::Loaded(database) = &self.database {
+					let icalendar = database.to_icalendar(ICalendarTaskComponent::default());
+					self.exporting_database = true;
+					Task::perform(
+						Database::export_as_icalendar(filepath, icalendar),
+						|result| match result {
+							Ok(_) => Message::DatabaseExported,
+							Err(e) => Message::ExportDatabaseFailed(Arc::new(e)),
+						},
+					)
+				} else {
+					Task::none()
+				}
+			}
+			Message::ExportDatabaseAsTodoTxt(filepath) => {
+				if let DatabaseState::Loaded(database) = &self.database {
+					let todo_txt = projects_to_todo_txt(
+						database
+							.projects()
+							.iter()
+							.map(|(_project_id, project)| project),
+					);
+					self.exporting_database = true;
+					Task::perform(export_todo_txt(filepath, todo_txt), |result| match result {
+						Ok(_) => Message::DatabaseExported,
+						Err(e) => Message::ExportDatabaseFailed(Arc::new(e)),
+					})
+				} else {
+					Task::none()
+				}
+			}
 			Message::ExportDatabaseFailed(error) => {
 				self.exporting_database = false;
 				self.show_error(error)
@@ -737,6 +1142,13 @@ impl ProjectTrackerApp {
 						if let Some(synchronization) = &mut self.synchronization {
 							synchronization.preset_database_to_sync(&database);
 						}
+						let orphaned_blob_file_names = match &self.database {
+							DatabaseState::Loaded(previous_database) => database
+								.orphaned_attachment_blob_file_names(
+									previous_database.attachment_blob_file_names(),
+								),
+							_ => Vec::new(),
+						};
 						self.database = DatabaseState::Loaded(database);
 						if let Some(task_modal) = &mut self.task_modal {
 							task_modal.refresh_task_description_editor(self.database.ok());
@@ -744,7 +1156,10 @@ impl ProjectTrackerApp {
 						let action = self
 							.content_page
 							.restore_from_serialized(self.database.ok(), &mut self.preferences);
-						self.perform_content_page_action(action)
+						Task::batch([
+							self.perform_content_page_action(action),
+							self.remove_orphaned_attachments(orphaned_blob_file_names),
+						])
 					}
 					Err(error) => match error.as_ref() {
 						LoadDatabaseError::FailedToOpenFile { .. } => {
@@ -822,7 +1237,9 @@ impl ProjectTrackerApp {
 				};
 				match synchronization_result {
 					Ok(SynchronizationOutput::DatabaseLoaded(database)) => {
-						self.update(Message::LoadedDatabase(Ok(database)))
+						let loaded_database_task =
+							self.update(Message::LoadedDatabase(Ok(database)));
+						Task::batch([loaded_database_task, self.download_missing_attachments()])
 					}
 					_ => Task::none(),
 				}
@@ -864,6 +1281,9 @@ impl ProjectTrackerApp {
 						if let Some(overview_page) = &mut self.content_page.overview_page {
 							*overview_page = overview_page::Page::new(self.database.ok());
 						}
+						if let Some(today_page) = &mut self.content_page.today_page {
+							*today_page = today_page::Page::new(self.database.ok());
+						}
 						Task::batch([
 							self.update(Message::SavePreferences),
 							self.perform_content_page_action(content_page_action),
@@ -916,23 +1336,40 @@ impl ProjectTrackerApp {
 					},
 				}
 			}
-			Message::DatabaseMessage(database_message) => match &mut self.database {
-				DatabaseState::Loaded(database) => {
+			Message::ImportProjects(projects) => match self.database.ok() {
+				Some(database) => {
+					let import = database.plan_import(projects);
+					self.import_summary_modal = import_summary_modal::Modal::Open {
+						summary: import.summary,
+					};
+					self.update(DatabaseMessage::ImportProjects(import).into())
+				}
+				None => Task::none(),
+			},
+			Message::DatabaseMessage(database_message) => {
+				self.update(Message::DatabaseMessages(vec![database_message]))
+			}
+			Message::DatabaseMessages(database_messages) => match &mut self.database {
+				DatabaseState::Loaded(database) if !database_messages.is_empty() => {
 					let synchronization_task = match &mut self.synchronization {
 						Some(synchronization) => synchronization.before_database_update(
 							database,
-							DatabaseUpdateEvent::DatabaseMessage(database_message.clone()),
+							DatabaseUpdateEvent::DatabaseMessages(database_messages.clone()),
 						),
 						None => Task::none(),
 					};
 
-					database.update(database_message);
+					let blob_file_names_before_update = database.attachment_blob_file_names();
+					for database_message in database_messages {
+						database.update(database_message);
+					}
+					let orphaned_blob_file_names =
+						database.orphaned_attachment_blob_file_names(blob_file_names_before_update);
 					if let Some(overview_page) = &mut self.content_page.overview_page {
-						overview_page.update(
-							overview_page::Message::RefreshCachedTaskList,
-							Some(database),
-							&mut self.preferences,
-						);
+						overview_page.refresh_cached_task_list(Some(database));
+					}
+					if let Some(today_page) = &mut self.content_page.today_page {
+						today_page.refresh_cached_task_list(Some(database));
 					}
 					if let Some(task_modal) = &mut self.task_modal {
 						task_modal.refresh_task_description_editor(Some(database));
@@ -957,7 +1394,11 @@ impl ProjectTrackerApp {
 						_ => Task::none(),
 					};
 
-					let mut tasks = vec![project_page_task, synchronization_task];
+					let mut tasks = vec![
+						project_page_task,
+						synchronization_task,
+						self.remove_orphaned_attachments(orphaned_blob_file_names),
+					];
 					if should_save {
 						tasks.push(self.update(Message::SaveDatabase));
 					}
@@ -1076,12 +1517,20 @@ impl ProjectTrackerApp {
 			} => {
 				let is_theme_dark = self.is_theme_dark();
 
-				let filtering_tasks = self
+				// tasks can't be reordered inside the overview calendar
+				let filtering_tasks =
+					self.content_page
+						.project_page
+						.as_ref()
+						.map(project_page::Page::filtering_tasks)
+						.unwrap_or(false) || self.content_page.is_overview_page_opened();
+
+				let calendar_days = self
 					.content_page
-					.project_page
+					.overview_page
 					.as_ref()
-					.map(project_page::Page::filtering_tasks)
-					.unwrap_or(false);
+					.map(|overview_page| overview_page.calendar_dropzone_days(&self.preferences))
+					.unwrap_or_default();
 
 				self.dragged_task = Some(task_id);
 				match self.start_dragging_point {
@@ -1103,6 +1552,7 @@ impl ProjectTrackerApp {
 						task_id,
 						task_is_todo,
 						filtering_tasks,
+						calendar_days,
 						point,
 						rect,
 					},
@@ -1132,10 +1582,27 @@ impl ProjectTrackerApp {
 				self.perform_sidebar_action(action)
 			}
 			Message::LeftClickReleased => {
+				let selecting_tasks =
+					self.keyboard_modifiers.command() || self.keyboard_modifiers.shift();
 				let task = if self.just_minimal_dragging {
-					match &self.pressed_task {
+					match self.pressed_task {
+						Some((project_id, task_id))
+							if selecting_tasks
+								&& self.content_page.project_page.as_ref().is_some_and(
+									|project_page| project_page.project_id == project_id,
+								) =>
+						{
+							self.update(
+								if self.keyboard_modifiers.shift() {
+									project_page::Message::SelectTaskRange(task_id)
+								} else {
+									project_page::Message::ToggleTaskSelection(task_id)
+								}
+								.into(),
+							)
+						}
 						Some((project_id, task_id)) => {
-							let (task_modal, task) = task_modal::Modal::new(*project_id, *task_id);
+							let (task_modal, task) = task_modal::Modal::new(project_id, task_id);
 							self.task_modal = Some(task_modal);
 							task
 						}
@@ -1213,9 +1680,32 @@ impl ProjectTrackerApp {
 				self.manage_tags_modal = None;
 				Task::none()
 			}
+			Message::SourceCodeTodoSettingsModalMessage(message) => {
+				match &mut self.source_code_todo_settings_modal {
+					Some(source_code_todo_settings_modal) => {
+						match source_code_todo_settings_modal.update(message, self.database.ok()) {
+							source_code_todo_settings_modal::Action::None => Task::none(),
+							source_code_todo_settings_modal::Action::DatabaseMessage(message) => {
+								self.update(message.into())
+							}
+						}
+					}
+					None => Task::none(),
+				}
+			}
+			Message::OpenSourceCodeTodoSettingsModal(project_id) => {
+				self.source_code_todo_settings_modal = Some(
+					source_code_todo_settings_modal::Modal::new(project_id, self.database.ok()),
+				);
+				Task::none()
+			}
+			Message::CloseSourceCodeTodoSettingsModal => {
+				self.source_code_todo_settings_modal = None;
+				Task::none()
+			}
 			Message::CreateTaskModalMessage(message) => match &mut self.create_task_modal {
 				Some(create_task_modal) => {
-					match create_task_modal.update(message, &self.preferences) {
+					match create_task_modal.update(message, self.database.ok(), &self.preferences) {
 						create_task_modal::Action::None => Task::none(),
 						create_task_modal::Action::Task(task) => {
 							task.map(Message::CreateTaskModalMessage)
@@ -1230,25 +1720,33 @@ impl ProjectTrackerApp {
 							needed_time_minutes,
 							time_spend,
 							create_at_top,
+							new_task_tags,
 						} => {
 							self.create_task_modal = None;
+							let mut database_messages: Vec<DatabaseMessage> = new_task_tags
+								.into_iter()
+								.map(|(task_tag_id, task_tag)| DatabaseMessage::CreateTaskTag {
+									project_id,
+									task_tag_id,
+									task_tag,
+								})
+								.collect();
+							database_messages.push(DatabaseMessage::CreateTask {
+								project_id,
+								task_id,
+								task_name,
+								task_description,
+								task_tags,
+								due_date,
+								needed_time_minutes,
+								time_spend,
+								create_at_top,
+							});
 							Task::batch([
-								self.update(
-									DatabaseMessage::CreateTask {
-										project_id,
-										task_id,
-										task_name,
-										task_description,
-										task_tags,
-										due_date,
-										needed_time_minutes,
-										time_spend,
-										create_at_top,
-									}
-									.into(),
-								),
+								self.update(Message::DatabaseMessages(database_messages)),
 								self.update(project_page::Message::RefreshCachedTaskList.into()),
 								self.update(overview_page::Message::RefreshCachedTaskList.into()),
+								self.update(today_page::Message::RefreshCachedTaskList.into()),
 							])
 						}
 					}
@@ -1266,23 +1764,25 @@ impl ProjectTrackerApp {
 			},
 			Message::OpenCreateTaskModal(project_id) => {
 				self.create_task_modal = Some(create_task_modal::Modal::new(project_id));
-				Task::none()
+				create_task_modal::Modal::focus_quick_add_input()
 			}
 			Message::CloseCreateTaskModal => {
 				self.create_task_modal = None;
 				Task::none()
 			}
 			Message::TaskModalMessage(message) => match &mut self.task_modal {
-				Some(task_modal) => match task_modal.update(message, self.database.ok()) {
-					task_modal::Action::None => Task::none(),
-					task_modal::Action::Task(task) => task.map(Message::TaskModalMessage),
-					task_modal::Action::DatabaseMessage(message) => {
-						if let DatabaseMessage::DeleteTask { .. } = &message {
-							self.task_modal = None;
+				Some(task_modal) => {
+					match task_modal.update(message, self.database.ok(), &self.preferences) {
+						task_modal::Action::None => Task::none(),
+						task_modal::Action::Task(task) => task.map(Message::TaskModalMessage),
+						task_modal::Action::DatabaseMessage(message) => {
+							if let DatabaseMessage::DeleteTask { .. } = &message {
+								self.task_modal = None;
+							}
+							self.update(message.into())
 						}
-						self.update(message.into())
 					}
-				},
+				}
 				None => Task::none(),
 			},
 			Message::OpenTaskModal {
@@ -1341,9 +1841,29 @@ impl ProjectTrackerApp {
 
 				self.update(message.into())
 			}
+			pages::Action::DatabaseMessages(messages) => {
+				if let Some(task_modal) = &self.task_modal {
+					let task_modal_task_deleted = messages.iter().any(|message| {
+						matches!(
+							message,
+							DatabaseMessage::DeleteTask { project_id, task_id }
+								if *project_id == task_modal.project_id
+									&& *task_id == task_modal.task_id
+						)
+					});
+					if task_modal_task_deleted {
+						self.task_modal = None;
+					}
+				}
+
+				self.update(Message::DatabaseMessages(messages))
+			}
 			pages::Action::OpenManageTaskTagsModal(project_id) => {
 				self.update(Message::OpenManageTaskTagsModal(project_id))
 			}
+			pages::Action::OpenSourceCodeTodoSettingsModal(project_id) => {
+				self.update(Message::OpenSourceCodeTodoSettingsModal(project_id))
+			}
 			pages::Action::ConfirmDeleteProject {
 				project_id,
 				project_name,
@@ -1512,6 +2032,12 @@ impl ProjectTrackerApp {
 					.map(|task_modal| task_modal.view(self)),
 				Message::CloseManageTaskTagsModal,
 			))
+			.push_maybe(Self::modal(
+				self.source_code_todo_settings_modal.as_ref().map(
+					|source_code_todo_settings_modal| source_code_todo_settings_modal.view(self),
+				),
+				Message::CloseSourceCodeTodoSettingsModal,
+			))
 			.push_maybe(Self::modal(
 				self.settings_modal.view(self),
 				settings_modal::Message::Close.into(),
@@ -1527,6 +2053,10 @@ impl ProjectTrackerApp {
 				)
 				.map(|element| element.map(Message::WaitClosingModalMessage)),
 			)
+			.push_maybe(Self::modal(
+				self.import_summary_modal.view(),
+				import_summary_modal::Message::Close.into(),
+			))
 			.push_maybe(Self::modal(
 				self.error_msg_modal.view(),
 				error_msg_modal::Message::Close.into(),
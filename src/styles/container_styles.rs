@@ -125,7 +125,12 @@ pub fn project_preview_background_container_style(theme: &Theme, dragging: bool)
 	}
 }
 
-pub fn task_background_container_style(theme: &Theme, dragging: bool, drag_overlay: bool) -> Style {
+pub fn task_background_container_style(
+	theme: &Theme,
+	dragging: bool,
+	drag_overlay: bool,
+	selected: bool,
+) -> Style {
 	Style {
 		background: if dragging || drag_overlay {
 			Some(
@@ -158,7 +163,15 @@ pub fn task_background_container_style(theme: &Theme, dragging: bool, drag_overl
 		} else {
 			None
 		},
-		border: rounded(BORDER_RADIUS),
+		border: if selected {
+			Border {
+				color: link_color(theme.extended_palette().is_dark),
+				width: 1.0,
+				radius: BORDER_RADIUS.into(),
+			}
+		} else {
+			rounded(BORDER_RADIUS)
+		},
 		shadow: if dragging {
 			Shadow {
 				color: background_shadow_color(theme.extended_palette()),
@@ -246,3 +259,51 @@ pub fn markdown_code_container_style(_theme: &Theme) -> Style {
 		..Default::default()
 	}
 }
+
+pub fn markdown_block_quote_container_style(theme: &Theme) -> Style {
+	Style {
+		background: Some(
+			mix_color(
+				theme.extended_palette().background.base.color,
+				theme.extended_palette().background.strong.color,
+				0.5,
+			)
+			.into(),
+		),
+		border: rounded(BORDER_RADIUS).color(GREY).width(1.0),
+		..Default::default()
+	}
+}
+
+pub fn markdown_table_container_style(theme: &Theme) -> Style {
+	Style {
+		border: rounded(BORDER_RADIUS)
+			.color(theme.extended_palette().background.strong.color)
+			.width(1.0),
+		..Default::default()
+	}
+}
+
+pub fn time_block_container_style(_theme: &Theme, color: Color, dragging: bool) -> Style {
+	Style {
+		background: Some(
+			Color {
+				a: if dragging { 0.5 } else { 0.3 },
+				..color
+			}
+			.into(),
+		),
+		border: rounded(BORDER_RADIUS)
+			.color(color)
+			.width(if dragging { 2.0 } else { 1.0 }),
+		..Default::default()
+	}
+}
+
+pub fn tracked_session_container_style(_theme: &Theme, color: Color) -> Style {
+	Style {
+		background: Some(color.into()),
+		border: rounded(BORDER_RADIUS),
+		..Default::default()
+	}
+}
@@ -28,7 +28,7 @@ use crate::synchronization::server::{ServerSubscriptionMessage, ServerSynchroniz
 pub use server::{ServerConfig, ServerSynchronization};
 
 pub enum DatabaseUpdateEvent {
-	DatabaseMessage(DatabaseMessage),
+	DatabaseMessages(Vec<DatabaseMessage>),
 	ImportDatabase(Database),
 }
 
@@ -88,10 +88,10 @@ impl OnUpdateSynchronization for ServerSynchronization {
 		self.database_to_sync = Some(database.clone());
 
 		let request = match database_update_event {
-			DatabaseUpdateEvent::DatabaseMessage(database_message) => {
+			DatabaseUpdateEvent::DatabaseMessages(database_messages) => {
 				let database_before_update_checksum = database.checksum();
 				Request::UpdateDatabase {
-					database_messages: vec![database_message],
+					database_messages,
 					database_before_update_checksum,
 				}
 			}
@@ -245,6 +245,14 @@ impl ServerSynchronization {
 			Response::DatabaseUpToDate => Task::done(Message::SyncedDatabase(Ok(
 				SynchronizationOutput::DatabaseUpToDate,
 			))),
+			Response::AttachmentUploaded => Task::none(),
+			Response::AttachmentDownloaded {
+				blob_file_name,
+				content,
+			} => Task::done(Message::AttachmentDownloaded {
+				blob_file_name,
+				content: Arc::new(content),
+			}),
 			Response::AdminInfos(admin_infos) => {
 				self.latest_admin_infos = Some(admin_infos);
 				Task::none()